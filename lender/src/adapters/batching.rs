@@ -0,0 +1,70 @@
+use core::fmt;
+
+use crate::{Covar, Lend, Lender, Lending, higher_order::FnMutHKAOpt};
+
+/// A lender that hands the underlying lender to a closure, which consumes as
+/// many lends as it needs to produce each lend of this lender.
+///
+/// This `struct` is created by the [`batching()`](crate::Lender::batching)
+/// method on [`Lender`].
+#[derive(Clone)]
+#[must_use = "lenders are lazy and do nothing unless consumed"]
+pub struct Batching<L, F> {
+    pub(crate) lender: L,
+    pub(crate) f: Covar<F>,
+}
+
+impl<L, F> Batching<L, F> {
+    /// Returns the inner lender.
+    #[inline]
+    pub fn into_inner(self) -> L {
+        self.lender
+    }
+
+    /// Returns the inner lender and the batching function.
+    #[inline]
+    pub fn into_parts(self) -> (L, Covar<F>) {
+        (self.lender, self.f)
+    }
+}
+
+impl<L: Lender, F> Batching<L, F> {
+    #[inline]
+    pub(crate) fn new(lender: L, f: Covar<F>) -> Batching<L, F> {
+        crate::__check_lender_covariance::<L>();
+        Batching { lender, f }
+    }
+}
+
+impl<L: fmt::Debug, F> fmt::Debug for Batching<L, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Batching")
+            .field("lender", &self.lender)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'lend, L, F> Lending<'lend> for Batching<L, F>
+where
+    F: for<'all> FnMutHKAOpt<'all, &'all mut L>,
+    L: Lender,
+{
+    type Lend = <F as FnMutHKAOpt<'lend, &'lend mut L>>::B;
+}
+
+impl<L, F> Lender for Batching<L, F>
+where
+    F: for<'all> FnMutHKAOpt<'all, &'all mut L>,
+    L: Lender,
+{
+    // SAFETY: the lend is the return type of F, whose covariance
+    // has been checked at Covar construction time.
+    crate::unsafe_assume_covariance!();
+    #[inline]
+    fn next(&mut self) -> Option<Lend<'_, Self>> {
+        (self.f.as_inner_mut())(&mut self.lender)
+    }
+
+    // The closure can consume any number of lends (including none) for each
+    // lend it returns, so no bound can be derived from the underlying lender.
+}
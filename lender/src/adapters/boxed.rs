@@ -0,0 +1,112 @@
+use alloc::boxed::Box;
+use core::fmt;
+
+use crate::{CovariantLending, CovariantProof, Lend, Lender, Lending};
+
+/// A `dyn`-compatible version of [`Lender`] whose lend type is specified by
+/// a [`CovariantLending`] type `L`, as returned by [`lend!`](crate::lend!)
+/// or defined by [`covariant_lend!`](crate::covariant_lend!).
+///
+/// [`Lender`] is not `dyn`-compatible because of its generic methods. This
+/// trait is implemented by every [`Lender`] whose lend is the same as that of
+/// `L`, and it is used by [`BoxedLender`], which provides all [`Lender`]
+/// methods on top of it.
+pub trait DynLender<L: ?Sized + CovariantLending> {
+    /// See [`Lender::next`].
+    fn next(&mut self) -> Option<Lend<'_, L>>;
+
+    /// See [`Lender::size_hint`].
+    fn size_hint(&self) -> (usize, Option<usize>);
+}
+
+impl<L, T> DynLender<L> for T
+where
+    L: ?Sized + CovariantLending + for<'all> Lending<'all, Lend = Lend<'all, T>>,
+    T: Lender,
+{
+    #[inline]
+    fn next(&mut self) -> Option<Lend<'_, L>> {
+        Lender::next(self)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        Lender::size_hint(self)
+    }
+}
+
+/// A type-erased boxed [`Lender`] whose lend type is specified by a
+/// [`CovariantLending`] type `L`, as returned by [`lend!`](crate::lend!)
+/// or defined by [`covariant_lend!`](crate::covariant_lend!).
+///
+/// Lenders with different types but the same lend type can be returned as a
+/// [`BoxedLender`], at the cost of an allocation and of dynamic dispatch.
+///
+/// This `struct` is created by the [`boxed()`](crate::Lender::boxed) method on
+/// [`Lender`], or by [`BoxedLender::new`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use lender::prelude::*;
+/// use lender::BoxedLender;
+///
+/// fn windows<'a>(data: &'a mut [i32], skip: bool) -> BoxedLender<'a, lend!(&'lend mut [i32])> {
+///     if skip {
+///         lender::windows_mut(data, 2).skip(1).boxed()
+///     } else {
+///         lender::windows_mut(data, 2).boxed()
+///     }
+/// }
+///
+/// let mut data = [1, 2, 3];
+/// assert_eq!(windows(&mut data, true).next(), Some(&mut [2, 3][..]));
+/// assert_eq!(windows(&mut data, false).count(), 2);
+/// ```
+#[must_use = "lenders are lazy and do nothing unless consumed"]
+pub struct BoxedLender<'a, L: ?Sized + CovariantLending> {
+    lender: Box<dyn DynLender<L> + 'a>,
+}
+
+impl<'a, L: ?Sized + CovariantLending> BoxedLender<'a, L> {
+    /// Boxes the given lender.
+    #[inline]
+    pub fn new<T>(lender: T) -> Self
+    where
+        T: DynLender<L> + 'a,
+    {
+        crate::__check_lending_covariance::<L>();
+        Self {
+            lender: Box::new(lender),
+        }
+    }
+}
+
+impl<L: ?Sized + CovariantLending> fmt::Debug for BoxedLender<'_, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BoxedLender").finish_non_exhaustive()
+    }
+}
+
+impl<'lend, L: ?Sized + CovariantLending> Lending<'lend> for BoxedLender<'_, L> {
+    type Lend = Lend<'lend, L>;
+}
+
+impl<L: ?Sized + CovariantLending> Lender for BoxedLender<'_, L> {
+    #[inline(always)]
+    fn __check_covariance<'long: 'short, 'short>(
+        proof: CovariantProof<<Self as Lending<'long>>::Lend>,
+    ) -> CovariantProof<<Self as Lending<'short>>::Lend> {
+        L::__check_covariance(proof)
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<Lend<'_, Self>> {
+        self.lender.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.lender.size_hint()
+    }
+}
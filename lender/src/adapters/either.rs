@@ -0,0 +1,188 @@
+use core::num::NonZeroUsize;
+
+use crate::{
+    DoubleEndedLender, ExactSizeLender, FusedLender, Lend, Lender, Lending, try_trait_v2::Try,
+};
+
+/// A lender that is either of two lenders with the same lend type.
+///
+/// This makes it possible to return different lenders from the branches of
+/// a conditional without boxing them.
+///
+/// This `enum` is usually created by the
+/// [`left_lender()`](crate::Lender::left_lender) and
+/// [`right_lender()`](crate::Lender::right_lender) methods on [`Lender`] or
+/// on [`FallibleLender`](crate::FallibleLender).
+///
+/// # Examples
+///
+/// ```rust
+/// # use lender::prelude::*;
+/// fn windows(data: &mut [i32], skip: bool) -> impl Lender + for<'lend> Lending<'lend, Lend = &'lend mut [i32]> {
+///     if skip {
+///         lender::windows_mut(data, 2).skip(1).left_lender()
+///     } else {
+///         lender::windows_mut(data, 2).right_lender()
+///     }
+/// }
+///
+/// let mut data = [1, 2, 3];
+/// assert_eq!(windows(&mut data, true).next(), Some(&mut [2, 3][..]));
+/// assert_eq!(windows(&mut data, false).count(), 2);
+/// ```
+#[derive(Clone, Debug)]
+#[must_use = "lenders are lazy and do nothing unless consumed"]
+pub enum Either<L, R> {
+    /// The first lender.
+    Left(L),
+    /// The second lender.
+    Right(R),
+}
+
+/// Evaluates an expression on the lender contained in an [`Either`].
+macro_rules! either {
+    ($value:expr, $lender:pat => $expr:expr) => {
+        match $value {
+            Either::Left($lender) => $expr,
+            Either::Right($lender) => $expr,
+        }
+    };
+}
+pub(crate) use either;
+
+impl<L, R> Either<L, R> {
+    /// Returns true if this is the [`Left`](Either::Left) lender.
+    #[inline]
+    pub const fn is_left(&self) -> bool {
+        matches!(self, Either::Left(_))
+    }
+
+    /// Returns true if this is the [`Right`](Either::Right) lender.
+    #[inline]
+    pub const fn is_right(&self) -> bool {
+        matches!(self, Either::Right(_))
+    }
+}
+
+impl<'lend, L, R> Lending<'lend> for Either<L, R>
+where
+    L: Lender,
+    R: Lender + for<'all> Lending<'all, Lend = Lend<'all, L>>,
+{
+    type Lend = Lend<'lend, L>;
+}
+
+impl<L, R> Lender for Either<L, R>
+where
+    L: Lender,
+    R: Lender + for<'all> Lending<'all, Lend = Lend<'all, L>>,
+{
+    // SAFETY: the lend is that of L (and R has the same lend type)
+    crate::unsafe_assume_covariance!();
+    #[inline]
+    fn next(&mut self) -> Option<Lend<'_, Self>> {
+        either!(self, lender => lender.next())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        either!(self, lender => lender.size_hint())
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        either!(self, lender => lender.count())
+    }
+
+    #[inline]
+    fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        either!(self, lender => lender.advance_by(n))
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Lend<'_, Self>> {
+        either!(self, lender => lender.nth(n))
+    }
+
+    #[inline]
+    fn try_fold<B, F, T>(&mut self, init: B, f: F) -> T
+    where
+        Self: Sized,
+        F: FnMut(B, Lend<'_, Self>) -> T,
+        T: Try<Output = B>,
+    {
+        either!(self, lender => lender.try_fold(init, f))
+    }
+
+    #[inline]
+    fn fold<B, F>(self, init: B, f: F) -> B
+    where
+        Self: Sized,
+        F: FnMut(B, Lend<'_, Self>) -> B,
+    {
+        either!(self, lender => lender.fold(init, f))
+    }
+}
+
+impl<L, R> DoubleEndedLender for Either<L, R>
+where
+    L: DoubleEndedLender,
+    R: DoubleEndedLender + for<'all> Lending<'all, Lend = Lend<'all, L>>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Lend<'_, Self>> {
+        either!(self, lender => lender.next_back())
+    }
+
+    #[inline]
+    fn advance_back_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        either!(self, lender => lender.advance_back_by(n))
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Lend<'_, Self>> {
+        either!(self, lender => lender.nth_back(n))
+    }
+
+    #[inline]
+    fn try_rfold<B, F, T>(&mut self, init: B, f: F) -> T
+    where
+        Self: Sized,
+        F: FnMut(B, Lend<'_, Self>) -> T,
+        T: Try<Output = B>,
+    {
+        either!(self, lender => lender.try_rfold(init, f))
+    }
+
+    #[inline]
+    fn rfold<B, F>(self, init: B, f: F) -> B
+    where
+        Self: Sized,
+        F: FnMut(B, Lend<'_, Self>) -> B,
+    {
+        either!(self, lender => lender.rfold(init, f))
+    }
+}
+
+impl<L, R> ExactSizeLender for Either<L, R>
+where
+    L: ExactSizeLender,
+    R: ExactSizeLender + for<'all> Lending<'all, Lend = Lend<'all, L>>,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        either!(self, lender => lender.len())
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        either!(self, lender => lender.is_empty())
+    }
+}
+
+impl<L, R> FusedLender for Either<L, R>
+where
+    L: FusedLender,
+    R: FusedLender + for<'all> Lending<'all, Lend = Lend<'all, L>>,
+{
+}
@@ -0,0 +1,227 @@
+use crate::{ExactSizeLender, Fuse, FusedLender, Lend, Lender, Lending};
+
+/// A lender that alternates between the lends of two lenders with the same
+/// lend type, continuing with the remaining one when the other is exhausted.
+///
+/// This `struct` is created by the [`interleave()`](crate::Lender::interleave)
+/// method on [`Lender`].
+#[derive(Clone, Debug)]
+#[must_use = "lenders are lazy and do nothing unless consumed"]
+pub struct Interleave<A, B> {
+    pub(crate) a: Fuse<A>,
+    pub(crate) b: Fuse<B>,
+    // Whether the next lend is taken from `a`.
+    pub(crate) a_next: bool,
+}
+
+impl<A, B> Interleave<A, B> {
+    /// Returns the two inner lenders.
+    #[inline]
+    pub fn into_inner(self) -> (A, B) {
+        (self.a.into_inner(), self.b.into_inner())
+    }
+}
+
+impl<A: Lender, B: Lender> Interleave<A, B> {
+    #[inline]
+    pub(crate) fn new(a: A, b: B) -> Self {
+        crate::__check_lender_covariance::<A>();
+        crate::__check_lender_covariance::<B>();
+        Self {
+            a: Fuse::new(a),
+            b: Fuse::new(b),
+            a_next: true,
+        }
+    }
+}
+
+impl<'lend, A, B> Lending<'lend> for Interleave<A, B>
+where
+    A: Lender,
+    B: Lender + for<'all> Lending<'all, Lend = Lend<'all, A>>,
+{
+    type Lend = Lend<'lend, A>;
+}
+
+impl<A, B> Lender for Interleave<A, B>
+where
+    A: Lender,
+    B: Lender + for<'all> Lending<'all, Lend = Lend<'all, A>>,
+{
+    // SAFETY: the lend is that of A (and B has the same lend type)
+    crate::unsafe_assume_covariance!();
+    #[inline]
+    fn next(&mut self) -> Option<Lend<'_, Self>> {
+        let a_next = self.a_next;
+        self.a_next = !a_next;
+        if a_next {
+            match self.a.next() {
+                None => self.b.next(),
+                x => x,
+            }
+        } else {
+            match self.b.next() {
+                None => self.a.next(),
+                x => x,
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a_lower, a_upper) = self.a.size_hint();
+        let (b_lower, b_upper) = self.b.size_hint();
+        let lower = a_lower.saturating_add(b_lower);
+        let upper = match (a_upper, b_upper) {
+            (Some(x), Some(y)) => x.checked_add(y),
+            _ => None,
+        };
+        (lower, upper)
+    }
+}
+
+impl<A, B> ExactSizeLender for Interleave<A, B>
+where
+    A: ExactSizeLender,
+    B: ExactSizeLender + for<'all> Lending<'all, Lend = Lend<'all, A>>,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.a.len() + self.b.len()
+    }
+}
+
+impl<A, B> FusedLender for Interleave<A, B>
+where
+    A: Lender,
+    B: Lender + for<'all> Lending<'all, Lend = Lend<'all, A>>,
+{
+}
+
+/// A lender that alternates between the lends of two lenders with the same
+/// lend type, stopping as soon as the lender whose turn it is is exhausted.
+///
+/// This `struct` is created by the
+/// [`interleave_shortest()`](crate::Lender::interleave_shortest) method on
+/// [`Lender`].
+#[derive(Clone, Debug)]
+#[must_use = "lenders are lazy and do nothing unless consumed"]
+pub struct InterleaveShortest<A, B> {
+    pub(crate) a: A,
+    pub(crate) b: B,
+    // Whether the next lend is taken from `b`.
+    pub(crate) b_next: bool,
+}
+
+impl<A, B> InterleaveShortest<A, B> {
+    /// Returns the two inner lenders.
+    #[inline]
+    pub fn into_inner(self) -> (A, B) {
+        (self.a, self.b)
+    }
+}
+
+impl<A: Lender, B: Lender> InterleaveShortest<A, B> {
+    #[inline]
+    pub(crate) fn new(a: A, b: B) -> Self {
+        crate::__check_lender_covariance::<A>();
+        crate::__check_lender_covariance::<B>();
+        Self {
+            a,
+            b,
+            b_next: false,
+        }
+    }
+}
+
+impl<'lend, A, B> Lending<'lend> for InterleaveShortest<A, B>
+where
+    A: Lender,
+    B: Lender + for<'all> Lending<'all, Lend = Lend<'all, A>>,
+{
+    type Lend = Lend<'lend, A>;
+}
+
+impl<A, B> Lender for InterleaveShortest<A, B>
+where
+    A: Lender,
+    B: Lender + for<'all> Lending<'all, Lend = Lend<'all, A>>,
+{
+    // SAFETY: the lend is that of A (and B has the same lend type)
+    crate::unsafe_assume_covariance!();
+    #[inline]
+    fn next(&mut self) -> Option<Lend<'_, Self>> {
+        let next = if self.b_next {
+            self.b.next()
+        } else {
+            self.a.next()
+        };
+        if next.is_some() {
+            self.b_next = !self.b_next;
+        }
+        next
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a, b) = (self.a.size_hint(), self.b.size_hint());
+        if self.b_next {
+            interleave_shortest_size_hint(b, a)
+        } else {
+            interleave_shortest_size_hint(a, b)
+        }
+    }
+}
+
+impl<A, B> ExactSizeLender for InterleaveShortest<A, B>
+where
+    A: ExactSizeLender,
+    B: ExactSizeLender + for<'all> Lending<'all, Lend = Lend<'all, A>>,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        let (a, b) = (self.a.len(), self.b.len());
+        if self.b_next {
+            interleave_shortest_len(b, a)
+        } else {
+            interleave_shortest_len(a, b)
+        }
+    }
+}
+
+impl<A, B> FusedLender for InterleaveShortest<A, B>
+where
+    A: FusedLender,
+    B: FusedLender + for<'all> Lending<'all, Lend = Lend<'all, A>>,
+{
+}
+
+/// Returns the number of lends of an interleaving that stops at the first
+/// exhausted lender, given the lengths of the lender whose turn it is (`curr`)
+/// and of the other lender (`next`).
+#[inline]
+pub(crate) fn interleave_shortest_len(curr: usize, next: usize) -> usize {
+    if curr > next { 2 * next + 1 } else { 2 * curr }
+}
+
+/// Returns the size hint of an interleaving that stops at the first exhausted
+/// lender, given the size hints of the lender whose turn it is (`curr`) and of
+/// the other lender (`next`).
+#[inline]
+pub(crate) fn interleave_shortest_size_hint(
+    curr: (usize, Option<usize>),
+    next: (usize, Option<usize>),
+) -> (usize, Option<usize>) {
+    let lower = if curr.0 > next.0 {
+        next.0.saturating_mul(2).saturating_add(1)
+    } else {
+        curr.0.saturating_mul(2)
+    };
+    let upper = match (curr.1, next.1) {
+        (Some(c), Some(n)) if c > n => n.checked_mul(2).and_then(|x| x.checked_add(1)),
+        (Some(c), _) => c.checked_mul(2),
+        (None, Some(n)) => n.checked_mul(2).and_then(|x| x.checked_add(1)),
+        (None, None) => None,
+    };
+    (lower, upper)
+}
@@ -1,17 +1,21 @@
 use core::{marker::PhantomData, ops::ControlFlow};
 
+mod batching;
+pub(crate) mod boxed;
 mod chain;
 mod chunk;
 mod chunky;
 mod cloned;
 mod copied;
 mod cycle;
+pub(crate) mod either;
 mod enumerate;
 mod filter;
 mod filter_map;
 mod flatten;
 mod fuse;
 mod inspect;
+pub(crate) mod interleave;
 mod intersperse;
 mod iter;
 mod map;
@@ -24,27 +28,36 @@ mod rev;
 mod scan;
 mod skip;
 mod skip_while;
+mod split;
 mod step_by;
+#[cfg(feature = "streaming-iterator")]
+mod streaming_iter;
 mod take;
 mod take_while;
 mod zip;
 
 pub use intersperse::{Intersperse, IntersperseWith};
+#[cfg(feature = "streaming-iterator")]
+pub use streaming_iter::StreamingIter;
 pub use zip::zip;
 
 pub use self::{
+    batching::Batching,
+    boxed::BoxedLender,
     chain::Chain,
     chunk::Chunk,
     chunky::Chunky,
     cloned::Cloned,
     copied::Copied,
     cycle::Cycle,
+    either::Either,
     enumerate::Enumerate,
     filter::Filter,
     filter_map::FilterMap,
     flatten::{FlatMap, Flatten},
     fuse::Fuse,
     inspect::Inspect,
+    interleave::{Interleave, InterleaveShortest},
     iter::Iter,
     map::Map,
     map_into_iter::MapIntoIter,
@@ -56,6 +69,7 @@ pub use self::{
     scan::Scan,
     skip::Skip,
     skip_while::SkipWhile,
+    split::{Split, SplitInclusive, SplitPart},
     step_by::StepBy,
     take::Take,
     take_while::TakeWhile,
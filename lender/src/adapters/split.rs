@@ -0,0 +1,365 @@
+use core::fmt;
+
+use crate::{FusedLender, Lend, Lender, Lending, Peekable};
+
+/// A lender yielding sub-lenders ([`SplitPart`]s) of the underlying lender,
+/// separated by lends that satisfy a predicate.
+///
+/// This is the streaming analogue of [`slice::split`]: separators are not
+/// part of any sub-lender, and `n` separators delimit `n + 1` (possibly empty)
+/// sub-lenders.
+///
+/// This `struct` is created by the [`split()`](crate::Lender::split) method on
+/// [`Lender`] and by the [`split()`](crate::FallibleLender::split) method on
+/// [`FallibleLender`](crate::FallibleLender).
+///
+/// Each [`SplitPart`] borrows this lender mutably. If a part is dropped
+/// before reaching its separator, the remaining lends of the part are
+/// skipped when the next part is requested.
+#[derive(Clone)]
+#[must_use = "lenders are lazy and do nothing unless consumed"]
+pub struct Split<L, P> {
+    pub(crate) lender: L,
+    pub(crate) predicate: P,
+    // A part has been lent and its separator has not been reached yet.
+    pub(crate) in_part: bool,
+    // The underlying lender has been exhausted.
+    pub(crate) finished: bool,
+}
+
+impl<L, P> Split<L, P> {
+    /// Returns the inner lender.
+    #[inline]
+    pub fn into_inner(self) -> L {
+        self.lender
+    }
+
+    /// Returns the inner lender and the predicate.
+    #[inline]
+    pub fn into_parts(self) -> (L, P) {
+        (self.lender, self.predicate)
+    }
+}
+
+impl<L: Lender, P> Split<L, P> {
+    #[inline]
+    pub(crate) fn new(lender: L, predicate: P) -> Split<L, P> {
+        crate::__check_lender_covariance::<L>();
+        Split {
+            lender,
+            predicate,
+            in_part: false,
+            finished: false,
+        }
+    }
+}
+
+impl<L: fmt::Debug, P> fmt::Debug for Split<L, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Split")
+            .field("lender", &self.lender)
+            .field("in_part", &self.in_part)
+            .field("finished", &self.finished)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'lend, L, P> Lending<'lend> for Split<L, P>
+where
+    P: FnMut(&Lend<'_, L>) -> bool,
+    L: Lender,
+{
+    type Lend = SplitPart<'lend, Self>;
+}
+
+impl<L, P> Lender for Split<L, P>
+where
+    P: FnMut(&Lend<'_, L>) -> bool,
+    L: Lender,
+{
+    // SAFETY: the lend is a SplitPart wrapping a reference to Self
+    crate::unsafe_assume_covariance!();
+    #[inline]
+    fn next(&mut self) -> Option<Lend<'_, Self>> {
+        // Skip what is left of the previous part, if anything
+        while self.in_part {
+            match self.lender.next() {
+                Some(x) => self.in_part = !(self.predicate)(&x),
+                None => {
+                    self.in_part = false;
+                    self.finished = true;
+                }
+            }
+        }
+        if self.finished {
+            return None;
+        }
+        self.in_part = true;
+        Some(SplitPart { split: self })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            return (0, Some(0));
+        }
+        let (_, upper) = self.lender.size_hint();
+        if self.in_part {
+            // Every further part needs a separator from the underlying lender
+            (0, upper)
+        } else {
+            (1, upper.and_then(|x| x.checked_add(1)))
+        }
+    }
+}
+
+// Once the underlying lender has returned None, Split never polls it again.
+impl<L, P> FusedLender for Split<L, P>
+where
+    P: FnMut(&Lend<'_, L>) -> bool,
+    L: Lender,
+{
+}
+
+/// A lender yielding sub-lenders ([`SplitPart`]s) of the underlying lender,
+/// each terminated by a lend that satisfies a predicate.
+///
+/// This is the streaming analogue of [`slice::split_inclusive`]: the
+/// separator is the last lend of its sub-lender, and if the underlying lender
+/// ends with a separator no empty sub-lender is yielded after it.
+///
+/// This `struct` is created by the
+/// [`split_inclusive()`](crate::Lender::split_inclusive) method on [`Lender`].
+///
+/// Each [`SplitPart`] borrows this lender mutably. If a part is dropped
+/// before reaching its separator, the remaining lends of the part are
+/// skipped when the next part is requested.
+#[must_use = "lenders are lazy and do nothing unless consumed"]
+pub struct SplitInclusive<'this, L, P>
+where
+    L: Lender,
+{
+    // Peeking tells whether there is another part before lending it
+    pub(crate) lender: Peekable<'this, L>,
+    pub(crate) predicate: P,
+    // A part has been lent and its separator has not been reached yet.
+    pub(crate) in_part: bool,
+}
+
+impl<'this, L: Lender, P> SplitInclusive<'this, L, P> {
+    #[inline]
+    pub(crate) fn new(lender: L, predicate: P) -> SplitInclusive<'this, L, P> {
+        crate::__check_lender_covariance::<L>();
+        SplitInclusive {
+            lender: Peekable::new(lender),
+            predicate,
+            in_part: false,
+        }
+    }
+
+    /// Returns the inner lender.
+    #[inline]
+    pub fn into_inner(self) -> L {
+        self.lender.into_inner()
+    }
+
+    /// Returns the inner lender and the predicate.
+    #[inline]
+    pub fn into_parts(self) -> (L, P) {
+        (self.lender.into_inner(), self.predicate)
+    }
+}
+
+impl<'this, L, P> fmt::Debug for SplitInclusive<'this, L, P>
+where
+    L: Lender + fmt::Debug,
+    Lend<'this, L>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SplitInclusive")
+            .field("lender", &self.lender)
+            .field("in_part", &self.in_part)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'lend, 'this, L, P> Lending<'lend> for SplitInclusive<'this, L, P>
+where
+    P: FnMut(&Lend<'_, L>) -> bool,
+    L: Lender,
+{
+    type Lend = SplitPart<'lend, Self>;
+}
+
+impl<'this, L, P> Lender for SplitInclusive<'this, L, P>
+where
+    P: FnMut(&Lend<'_, L>) -> bool,
+    L: Lender,
+{
+    // SAFETY: the lend is a SplitPart wrapping a reference to Self
+    crate::unsafe_assume_covariance!();
+    #[inline]
+    fn next(&mut self) -> Option<Lend<'_, Self>> {
+        // Skip what is left of the previous part, if anything
+        while self.in_part {
+            match self.lender.next() {
+                Some(x) => self.in_part = !(self.predicate)(&x),
+                None => self.in_part = false,
+            }
+        }
+        self.lender.peek()?;
+        self.in_part = true;
+        Some(SplitPart { split: self })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.lender.size_hint();
+        // Every part contains at least one lend
+        if self.in_part {
+            (0, upper)
+        } else {
+            (lower.min(1), upper)
+        }
+    }
+}
+
+impl<L, P> FusedLender for SplitInclusive<'_, L, P>
+where
+    P: FnMut(&Lend<'_, L>) -> bool,
+    L: FusedLender,
+{
+}
+
+/// A sub-lender over the lends between two separators of a [`Split`] or
+/// [`SplitInclusive`] lender.
+///
+/// This `struct` is created by the [`Split`] and [`SplitInclusive`] lenders
+/// during iteration. It borrows its parent mutably and advances it as it is
+/// consumed.
+#[must_use = "lenders are lazy and do nothing unless consumed"]
+pub struct SplitPart<'s, S> {
+    pub(crate) split: &'s mut S,
+}
+
+impl<'s, S> SplitPart<'s, S> {
+    /// Returns the parent lender.
+    #[inline]
+    pub fn into_inner(self) -> &'s mut S {
+        self.split
+    }
+}
+
+impl<S: fmt::Debug> fmt::Debug for SplitPart<'_, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SplitPart")
+            .field("split", &self.split)
+            .finish()
+    }
+}
+
+impl<'lend, L, P> Lending<'lend> for SplitPart<'_, Split<L, P>>
+where
+    P: FnMut(&Lend<'_, L>) -> bool,
+    L: Lender,
+{
+    type Lend = Lend<'lend, L>;
+}
+
+impl<L, P> Lender for SplitPart<'_, Split<L, P>>
+where
+    P: FnMut(&Lend<'_, L>) -> bool,
+    L: Lender,
+{
+    // SAFETY: the lend is that of L
+    crate::unsafe_assume_covariance!();
+    #[inline]
+    fn next(&mut self) -> Option<Lend<'_, Self>> {
+        let split = &mut *self.split;
+        if !split.in_part {
+            return None;
+        }
+        match split.lender.next() {
+            Some(x) if (split.predicate)(&x) => {
+                split.in_part = false;
+                None
+            }
+            Some(x) => Some(x),
+            None => {
+                split.in_part = false;
+                split.finished = true;
+                None
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.split.in_part {
+            let (_, upper) = self.split.lender.size_hint();
+            (0, upper)
+        } else {
+            (0, Some(0))
+        }
+    }
+}
+
+impl<L, P> FusedLender for SplitPart<'_, Split<L, P>>
+where
+    P: FnMut(&Lend<'_, L>) -> bool,
+    L: Lender,
+{
+}
+
+impl<'lend, L, P> Lending<'lend> for SplitPart<'_, SplitInclusive<'_, L, P>>
+where
+    P: FnMut(&Lend<'_, L>) -> bool,
+    L: Lender,
+{
+    type Lend = Lend<'lend, L>;
+}
+
+impl<L, P> Lender for SplitPart<'_, SplitInclusive<'_, L, P>>
+where
+    P: FnMut(&Lend<'_, L>) -> bool,
+    L: Lender,
+{
+    // SAFETY: the lend is that of L
+    crate::unsafe_assume_covariance!();
+    #[inline]
+    fn next(&mut self) -> Option<Lend<'_, Self>> {
+        let split = &mut *self.split;
+        if !split.in_part {
+            return None;
+        }
+        match split.lender.next() {
+            Some(x) => {
+                if (split.predicate)(&x) {
+                    split.in_part = false;
+                }
+                Some(x)
+            }
+            None => {
+                split.in_part = false;
+                None
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.split.in_part {
+            let (lower, upper) = self.split.lender.size_hint();
+            (lower.min(1), upper)
+        } else {
+            (0, Some(0))
+        }
+    }
+}
+
+impl<L, P> FusedLender for SplitPart<'_, SplitInclusive<'_, L, P>>
+where
+    P: FnMut(&Lend<'_, L>) -> bool,
+    L: Lender,
+{
+}
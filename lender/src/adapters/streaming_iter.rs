@@ -0,0 +1,98 @@
+use aliasable::boxed::AliasableBox;
+use core::fmt;
+use maybe_dangling::MaybeDangling;
+use streaming_iterator::{DoubleEndedStreamingIterator, StreamingIterator};
+
+use crate::{DoubleEndedLender, Lender, Lending};
+
+/// [`StreamingIterator`] adapter for any [`Lender`] lending references.
+///
+/// The most recent lend is cached, so that it can be returned by
+/// [`get`](StreamingIterator::get).
+///
+/// This `struct` is created by the
+/// [`into_streaming_iter()`](crate::Lender::into_streaming_iter) method on
+/// [`Lender`], which is available only if the `streaming-iterator` feature
+/// is enabled.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct StreamingIter<'this, L, T: ?Sized + 'this> {
+    // As in Peekable, MaybeDangling marks the cached lend as possibly
+    // referencing the lender, and AliasableBox keeps it valid when the struct
+    // is moved. Field order ensures the lender drops last.
+    current: MaybeDangling<Option<&'this T>>,
+    lender: AliasableBox<L>,
+}
+
+impl<'this, L, T> StreamingIter<'this, L, T>
+where
+    L: Lender + for<'all> Lending<'all, Lend = &'all T>,
+    T: ?Sized + 'this,
+{
+    #[inline]
+    pub(crate) fn new(lender: L) -> Self {
+        Self {
+            current: MaybeDangling::new(None),
+            lender: AliasableBox::from_unique(alloc::boxed::Box::new(lender)),
+        }
+    }
+
+    /// Returns the inner lender.
+    #[inline]
+    pub fn into_inner(mut self) -> L {
+        *self.current = None;
+        *AliasableBox::into_unique(self.lender)
+    }
+}
+
+impl<L: fmt::Debug, T: ?Sized + fmt::Debug> fmt::Debug for StreamingIter<'_, L, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StreamingIter")
+            .field("current", &*self.current)
+            .field("lender", &*self.lender)
+            .finish()
+    }
+}
+
+impl<'this, L, T> StreamingIterator for StreamingIter<'this, L, T>
+where
+    L: Lender + for<'all> Lending<'all, Lend = &'all T>,
+    T: ?Sized + 'this,
+{
+    type Item = T;
+
+    #[inline]
+    fn advance(&mut self) {
+        *self.current = None;
+        // SAFETY: the lend is stored with the lifetime of the struct, but it
+        // is only exposed through `get`, which ties it to a borrow of `self`,
+        // and it is dropped before the lender is used again. The lender is
+        // boxed, so the lend stays valid when the struct is moved.
+        *self.current =
+            unsafe { core::mem::transmute::<Option<&'_ T>, Option<&'this T>>(self.lender.next()) };
+    }
+
+    #[inline]
+    fn get(&self) -> Option<&T> {
+        *self.current
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.lender.size_hint()
+    }
+}
+
+impl<'this, L, T> DoubleEndedStreamingIterator for StreamingIter<'this, L, T>
+where
+    L: DoubleEndedLender + for<'all> Lending<'all, Lend = &'all T>,
+    T: ?Sized + 'this,
+{
+    #[inline]
+    fn advance_back(&mut self) {
+        *self.current = None;
+        // SAFETY: see advance
+        *self.current = unsafe {
+            core::mem::transmute::<Option<&'_ T>, Option<&'this T>>(self.lender.next_back())
+        };
+    }
+}
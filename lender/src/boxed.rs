@@ -0,0 +1,40 @@
+//! # `dyn`-Compatible Lenders
+//!
+//! [`Lender`](crate::Lender) and [`FallibleLender`](crate::FallibleLender)
+//! are not `dyn`-compatible, because of their generic methods and of their
+//! higher-rank [`Lending`](crate::Lending) and
+//! [`FallibleLending`](crate::FallibleLending) supertraits. This module
+//! provides the `dyn`-compatible traits [`DynLender`] and
+//! [`DynFallibleLender`], which are implemented by every lender whose lend
+//! type is specified by a [`CovariantLending`](crate::CovariantLending) or
+//! [`CovariantFallibleLending`](crate::CovariantFallibleLending) type, as
+//! returned by [`lend!`](crate::lend!) and
+//! [`fallible_lend!`](crate::fallible_lend!).
+//!
+//! Usually you will not need these traits directly:
+//! [`Lender::boxed`](crate::Lender::boxed) and
+//! [`FallibleLender::boxed`](crate::FallibleLender::boxed) return a
+//! [`BoxedLender`](crate::BoxedLender) or a
+//! [`BoxedFallibleLender`](crate::BoxedFallibleLender), which are lenders
+//! themselves.
+//!
+//! The traits are not re-exported at the crate root, as their methods have
+//! the same names as those of [`Lender`](crate::Lender) and
+//! [`FallibleLender`](crate::FallibleLender), and they are implemented by
+//! every lender: having them in scope together would make method calls
+//! ambiguous.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use lender::prelude::*;
+//! use lender::boxed::DynLender;
+//!
+//! let mut data = [1, 2, 3];
+//! let mut lender: Box<dyn DynLender<lend!(&'lend mut [i32])>> =
+//!     Box::new(lender::windows_mut(&mut data, 2));
+//! assert_eq!(lender.next(), Some(&mut [1, 2][..]));
+//! ```
+
+pub use crate::adapters::boxed::DynLender;
+pub use crate::fallible_adapters::boxed::DynFallibleLender;
@@ -0,0 +1,35 @@
+use crate::{
+    Batching, Covar, FallibleLend, FallibleLender, FallibleLending, higher_order::FnMutHKAResOpt,
+};
+
+impl<L: FallibleLender, F> Batching<L, F> {
+    #[inline]
+    pub(crate) fn new_fallible(lender: L, f: Covar<F>) -> Batching<L, F> {
+        crate::__check_fallible_lender_covariance::<L>();
+        Batching { lender, f }
+    }
+}
+
+impl<'lend, L, F> FallibleLending<'lend> for Batching<L, F>
+where
+    F: for<'all> FnMutHKAResOpt<'all, &'all mut L, L::Error>,
+    L: FallibleLender,
+{
+    type Lend = <F as FnMutHKAResOpt<'lend, &'lend mut L, L::Error>>::B;
+}
+
+impl<L, F> FallibleLender for Batching<L, F>
+where
+    F: for<'all> FnMutHKAResOpt<'all, &'all mut L, L::Error>,
+    L: FallibleLender,
+{
+    type Error = L::Error;
+    // SAFETY: the lend is the return type of F, whose covariance
+    // has been checked at Covar construction time.
+    crate::unsafe_assume_covariance_fallible!();
+
+    #[inline]
+    fn next(&mut self) -> Result<Option<FallibleLend<'_, Self>>, Self::Error> {
+        (self.f.as_inner_mut())(&mut self.lender)
+    }
+}
@@ -0,0 +1,120 @@
+use alloc::boxed::Box;
+use core::fmt;
+
+use crate::{
+    CovariantFallibleLending, CovariantProof, FallibleLend, FallibleLender, FallibleLending,
+};
+
+/// A `dyn`-compatible version of [`FallibleLender`] whose lend type is
+/// specified by a [`CovariantFallibleLending`] type `L`, as returned by
+/// [`fallible_lend!`](crate::fallible_lend!) or defined by
+/// [`covariant_fallible_lend!`](crate::covariant_fallible_lend!), and whose
+/// error type is `E`.
+///
+/// This trait is implemented by every [`FallibleLender`] with the same lend
+/// and error types, and it is used by [`BoxedFallibleLender`], which provides
+/// all [`FallibleLender`] methods on top of it.
+pub trait DynFallibleLender<L: ?Sized + CovariantFallibleLending, E> {
+    /// See [`FallibleLender::next`].
+    fn next(&mut self) -> Result<Option<FallibleLend<'_, L>>, E>;
+
+    /// See [`FallibleLender::size_hint`].
+    fn size_hint(&self) -> (usize, Option<usize>);
+}
+
+impl<L, T> DynFallibleLender<L, T::Error> for T
+where
+    L: ?Sized
+        + CovariantFallibleLending
+        + for<'all> FallibleLending<'all, Lend = FallibleLend<'all, T>>,
+    T: FallibleLender,
+{
+    #[inline]
+    fn next(&mut self) -> Result<Option<FallibleLend<'_, L>>, T::Error> {
+        FallibleLender::next(self)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        FallibleLender::size_hint(self)
+    }
+}
+
+/// A type-erased boxed [`FallibleLender`] whose lend type is specified by a
+/// [`CovariantFallibleLending`] type `L`, as returned by
+/// [`fallible_lend!`](crate::fallible_lend!) or defined by
+/// [`covariant_fallible_lend!`](crate::covariant_fallible_lend!), and whose
+/// error type is `E`.
+///
+/// This `struct` is created by the [`boxed()`](crate::FallibleLender::boxed)
+/// method on [`FallibleLender`], or by [`BoxedFallibleLender::new`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use lender::prelude::*;
+/// use lender::BoxedFallibleLender;
+///
+/// fn numbers(fail: bool) -> BoxedFallibleLender<'static, fallible_lend!(i32), String> {
+///     if fail {
+///         lender::fallible_once_err::<fallible_lend!(i32), _>("failure".to_string()).boxed()
+///     } else {
+///         [1, 2].into_iter().into_lender().into_fallible().map_err(|e| match e {}).boxed()
+///     }
+/// }
+///
+/// assert_eq!(numbers(false).next(), Ok(Some(1)));
+/// assert_eq!(numbers(true).next(), Err("failure".to_string()));
+/// ```
+#[must_use = "lenders are lazy and do nothing unless consumed"]
+pub struct BoxedFallibleLender<'a, L: ?Sized + CovariantFallibleLending, E> {
+    lender: Box<dyn DynFallibleLender<L, E> + 'a>,
+}
+
+impl<'a, L: ?Sized + CovariantFallibleLending, E> BoxedFallibleLender<'a, L, E> {
+    /// Boxes the given fallible lender.
+    #[inline]
+    pub fn new<T>(lender: T) -> Self
+    where
+        T: DynFallibleLender<L, E> + 'a,
+    {
+        crate::__check_fallible_lending_covariance::<L>();
+        Self {
+            lender: Box::new(lender),
+        }
+    }
+}
+
+impl<L: ?Sized + CovariantFallibleLending, E> fmt::Debug for BoxedFallibleLender<'_, L, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BoxedFallibleLender")
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'lend, L: ?Sized + CovariantFallibleLending, E> FallibleLending<'lend>
+    for BoxedFallibleLender<'_, L, E>
+{
+    type Lend = FallibleLend<'lend, L>;
+}
+
+impl<L: ?Sized + CovariantFallibleLending, E> FallibleLender for BoxedFallibleLender<'_, L, E> {
+    type Error = E;
+
+    #[inline(always)]
+    fn __check_covariance<'long: 'short, 'short>(
+        proof: CovariantProof<<Self as FallibleLending<'long>>::Lend>,
+    ) -> CovariantProof<<Self as FallibleLending<'short>>::Lend> {
+        L::__check_covariance(proof)
+    }
+
+    #[inline]
+    fn next(&mut self) -> Result<Option<FallibleLend<'_, Self>>, Self::Error> {
+        self.lender.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.lender.size_hint()
+    }
+}
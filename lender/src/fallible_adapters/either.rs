@@ -0,0 +1,136 @@
+use core::num::NonZeroUsize;
+
+use crate::{
+    DoubleEndedFallibleLender, Either, ExactSizeFallibleLender, FallibleLend, FallibleLender,
+    FallibleLending, FusedFallibleLender, adapters::either::either, try_trait_v2::Try,
+};
+
+impl<'lend, L, R> FallibleLending<'lend> for Either<L, R>
+where
+    L: FallibleLender,
+    R: FallibleLender<Error = L::Error>
+        + for<'all> FallibleLending<'all, Lend = FallibleLend<'all, L>>,
+{
+    type Lend = FallibleLend<'lend, L>;
+}
+
+impl<L, R> FallibleLender for Either<L, R>
+where
+    L: FallibleLender,
+    R: FallibleLender<Error = L::Error>
+        + for<'all> FallibleLending<'all, Lend = FallibleLend<'all, L>>,
+{
+    type Error = L::Error;
+    // SAFETY: the lend is that of L (and R has the same lend type)
+    crate::unsafe_assume_covariance_fallible!();
+
+    #[inline]
+    fn next(&mut self) -> Result<Option<FallibleLend<'_, Self>>, Self::Error> {
+        either!(self, lender => lender.next())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        either!(self, lender => lender.size_hint())
+    }
+
+    #[inline]
+    fn count(self) -> Result<usize, Self::Error> {
+        either!(self, lender => lender.count())
+    }
+
+    #[inline]
+    fn advance_by(&mut self, n: usize) -> Result<Result<(), NonZeroUsize>, Self::Error> {
+        either!(self, lender => lender.advance_by(n))
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Result<Option<FallibleLend<'_, Self>>, Self::Error> {
+        either!(self, lender => lender.nth(n))
+    }
+
+    #[inline]
+    fn try_fold<B, F, T>(&mut self, init: B, f: F) -> Result<T, Self::Error>
+    where
+        Self: Sized,
+        F: FnMut(B, FallibleLend<'_, Self>) -> Result<T, Self::Error>,
+        T: Try<Output = B>,
+    {
+        either!(self, lender => lender.try_fold(init, f))
+    }
+
+    #[inline]
+    fn fold<B, F>(self, init: B, f: F) -> Result<B, Self::Error>
+    where
+        Self: Sized,
+        F: FnMut(B, FallibleLend<'_, Self>) -> Result<B, Self::Error>,
+    {
+        either!(self, lender => lender.fold(init, f))
+    }
+}
+
+impl<L, R> DoubleEndedFallibleLender for Either<L, R>
+where
+    L: DoubleEndedFallibleLender,
+    R: DoubleEndedFallibleLender<Error = L::Error>
+        + for<'all> FallibleLending<'all, Lend = FallibleLend<'all, L>>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Result<Option<FallibleLend<'_, Self>>, Self::Error> {
+        either!(self, lender => lender.next_back())
+    }
+
+    #[inline]
+    fn advance_back_by(&mut self, n: usize) -> Result<Result<(), NonZeroUsize>, Self::Error> {
+        either!(self, lender => lender.advance_back_by(n))
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Result<Option<FallibleLend<'_, Self>>, Self::Error> {
+        either!(self, lender => lender.nth_back(n))
+    }
+
+    #[inline]
+    fn try_rfold<B, F, T>(&mut self, init: B, f: F) -> Result<T, Self::Error>
+    where
+        Self: Sized,
+        F: FnMut(B, FallibleLend<'_, Self>) -> Result<T, Self::Error>,
+        T: Try<Output = B>,
+    {
+        either!(self, lender => lender.try_rfold(init, f))
+    }
+
+    #[inline]
+    fn rfold<B, F>(self, init: B, f: F) -> Result<B, Self::Error>
+    where
+        Self: Sized,
+        F: FnMut(B, FallibleLend<'_, Self>) -> Result<B, Self::Error>,
+    {
+        either!(self, lender => lender.rfold(init, f))
+    }
+}
+
+impl<L, R> ExactSizeFallibleLender for Either<L, R>
+where
+    L: ExactSizeFallibleLender,
+    R: ExactSizeFallibleLender<Error = L::Error>
+        + for<'all> FallibleLending<'all, Lend = FallibleLend<'all, L>>,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        either!(self, lender => lender.len())
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        either!(self, lender => lender.is_empty())
+    }
+}
+
+impl<L, R> FusedFallibleLender for Either<L, R>
+where
+    L: FusedFallibleLender,
+    R: FusedFallibleLender<Error = L::Error>
+        + for<'all> FallibleLending<'all, Lend = FallibleLend<'all, L>>,
+{
+}
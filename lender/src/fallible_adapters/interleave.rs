@@ -0,0 +1,168 @@
+use crate::{
+    ExactSizeFallibleLender, FallibleLend, FallibleLender, FallibleLending, Fuse,
+    FusedFallibleLender, Interleave, InterleaveShortest,
+    adapters::interleave::{interleave_shortest_len, interleave_shortest_size_hint},
+};
+
+impl<A: FallibleLender, B: FallibleLender> Interleave<A, B> {
+    #[inline]
+    pub(crate) fn new_fallible(a: A, b: B) -> Self {
+        crate::__check_fallible_lender_covariance::<A>();
+        crate::__check_fallible_lender_covariance::<B>();
+        Self {
+            a: Fuse::new_fallible(a),
+            b: Fuse::new_fallible(b),
+            a_next: true,
+        }
+    }
+}
+
+impl<'lend, A, B> FallibleLending<'lend> for Interleave<A, B>
+where
+    A: FallibleLender,
+    B: FallibleLender<Error = A::Error>
+        + for<'all> FallibleLending<'all, Lend = FallibleLend<'all, A>>,
+{
+    type Lend = FallibleLend<'lend, A>;
+}
+
+impl<A, B> FallibleLender for Interleave<A, B>
+where
+    A: FallibleLender,
+    B: FallibleLender<Error = A::Error>
+        + for<'all> FallibleLending<'all, Lend = FallibleLend<'all, A>>,
+{
+    type Error = A::Error;
+    // SAFETY: the lend is that of A (and B has the same lend type)
+    crate::unsafe_assume_covariance_fallible!();
+
+    #[inline]
+    fn next(&mut self) -> Result<Option<FallibleLend<'_, Self>>, Self::Error> {
+        let a_next = self.a_next;
+        self.a_next = !a_next;
+        if a_next {
+            match self.a.next()? {
+                None => self.b.next(),
+                x => Ok(x),
+            }
+        } else {
+            match self.b.next()? {
+                None => self.a.next(),
+                x => Ok(x),
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a_lower, a_upper) = self.a.size_hint();
+        let (b_lower, b_upper) = self.b.size_hint();
+        let lower = a_lower.saturating_add(b_lower);
+        let upper = match (a_upper, b_upper) {
+            (Some(x), Some(y)) => x.checked_add(y),
+            _ => None,
+        };
+        (lower, upper)
+    }
+}
+
+impl<A, B> ExactSizeFallibleLender for Interleave<A, B>
+where
+    A: ExactSizeFallibleLender,
+    B: ExactSizeFallibleLender<Error = A::Error>
+        + for<'all> FallibleLending<'all, Lend = FallibleLend<'all, A>>,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.a.len() + self.b.len()
+    }
+}
+
+impl<A, B> FusedFallibleLender for Interleave<A, B>
+where
+    A: FallibleLender,
+    B: FallibleLender<Error = A::Error>
+        + for<'all> FallibleLending<'all, Lend = FallibleLend<'all, A>>,
+{
+}
+
+impl<A: FallibleLender, B: FallibleLender> InterleaveShortest<A, B> {
+    #[inline]
+    pub(crate) fn new_fallible(a: A, b: B) -> Self {
+        crate::__check_fallible_lender_covariance::<A>();
+        crate::__check_fallible_lender_covariance::<B>();
+        Self {
+            a,
+            b,
+            b_next: false,
+        }
+    }
+}
+
+impl<'lend, A, B> FallibleLending<'lend> for InterleaveShortest<A, B>
+where
+    A: FallibleLender,
+    B: FallibleLender<Error = A::Error>
+        + for<'all> FallibleLending<'all, Lend = FallibleLend<'all, A>>,
+{
+    type Lend = FallibleLend<'lend, A>;
+}
+
+impl<A, B> FallibleLender for InterleaveShortest<A, B>
+where
+    A: FallibleLender,
+    B: FallibleLender<Error = A::Error>
+        + for<'all> FallibleLending<'all, Lend = FallibleLend<'all, A>>,
+{
+    type Error = A::Error;
+    // SAFETY: the lend is that of A (and B has the same lend type)
+    crate::unsafe_assume_covariance_fallible!();
+
+    #[inline]
+    fn next(&mut self) -> Result<Option<FallibleLend<'_, Self>>, Self::Error> {
+        let next = if self.b_next {
+            self.b.next()?
+        } else {
+            self.a.next()?
+        };
+        if next.is_some() {
+            self.b_next = !self.b_next;
+        }
+        Ok(next)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a, b) = (self.a.size_hint(), self.b.size_hint());
+        if self.b_next {
+            interleave_shortest_size_hint(b, a)
+        } else {
+            interleave_shortest_size_hint(a, b)
+        }
+    }
+}
+
+impl<A, B> ExactSizeFallibleLender for InterleaveShortest<A, B>
+where
+    A: ExactSizeFallibleLender,
+    B: ExactSizeFallibleLender<Error = A::Error>
+        + for<'all> FallibleLending<'all, Lend = FallibleLend<'all, A>>,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        let (a, b) = (self.a.len(), self.b.len());
+        if self.b_next {
+            interleave_shortest_len(b, a)
+        } else {
+            interleave_shortest_len(a, b)
+        }
+    }
+}
+
+impl<A, B> FusedFallibleLender for InterleaveShortest<A, B>
+where
+    A: FusedFallibleLender,
+    B: FusedFallibleLender<Error = A::Error>
+        + for<'all> FallibleLending<'all, Lend = FallibleLend<'all, A>>,
+{
+}
@@ -0,0 +1,159 @@
+use alloc::borrow::ToOwned;
+use core::iter::FusedIterator;
+
+use crate::{
+    DoubleEndedFallibleLender, DoubleEndedLender, FallibleLend, FallibleLender,
+    FusedFallibleLender, FusedLender, Lend, Lender, Lending,
+};
+
+/// A lender that wraps a fallible lender, lending the results of its
+/// [`next()`](FallibleLender::next) method.
+///
+/// The lender ends after lending the first error. Since the error can come
+/// at any call, the lower bound of the [size hint](Lender::size_hint) is at
+/// most one, and the upper bound counts the error.
+///
+/// This `struct` is created by the
+/// [`into_results()`](crate::FallibleLender::into_results) method on
+/// [`FallibleLender`].
+#[derive(Clone, Debug)]
+#[must_use = "lenders are lazy and do nothing unless consumed"]
+pub struct IntoResults<L> {
+    lender: L,
+    done: bool,
+}
+
+impl<L: FallibleLender> IntoResults<L> {
+    #[inline]
+    pub(crate) fn new(lender: L) -> Self {
+        crate::__check_fallible_lender_covariance::<L>();
+        Self {
+            lender,
+            done: false,
+        }
+    }
+
+    /// Returns the inner lender.
+    #[inline]
+    pub fn into_inner(self) -> L {
+        self.lender
+    }
+}
+
+impl<'lend, L: FallibleLender> Lending<'lend> for IntoResults<L> {
+    type Lend = Result<FallibleLend<'lend, L>, L::Error>;
+}
+
+impl<L: FallibleLender> Lender for IntoResults<L> {
+    // SAFETY: the lend is a result containing the lend of L
+    crate::unsafe_assume_covariance!();
+    #[inline]
+    fn next(&mut self) -> Option<Lend<'_, Self>> {
+        if self.done {
+            return None;
+        }
+        match self.lender.next() {
+            Ok(x) => x.map(Ok),
+            Err(e) => {
+                self.done = true;
+                Some(Err(e))
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            // The inner hint assumes that all calls succeed, but the next
+            // one may return the error that ends the lender
+            let (lower, upper) = self.lender.size_hint();
+            (lower.min(1), upper.and_then(|u| u.checked_add(1)))
+        }
+    }
+}
+
+impl<L: DoubleEndedFallibleLender> DoubleEndedLender for IntoResults<L> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Lend<'_, Self>> {
+        if self.done {
+            return None;
+        }
+        match self.lender.next_back() {
+            Ok(x) => x.map(Ok),
+            Err(e) => {
+                self.done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+impl<L: FusedFallibleLender> FusedLender for IntoResults<L> {}
+
+/// An iterator that converts the lends of a fallible lender into owned
+/// values, returning [`Result`]s.
+///
+/// The iterator ends after returning the first error, so it can be used with
+/// the standard combinators on iterators of results, such as
+/// [`collect::<Result<Vec<_>, _>>()`](Iterator::collect).
+///
+/// This `struct` is created by the
+/// [`owned_results()`](crate::FallibleLender::owned_results) method on
+/// [`FallibleLender`].
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct OwnedResults<L> {
+    lender: IntoResults<L>,
+}
+
+impl<L: FallibleLender> OwnedResults<L> {
+    #[inline]
+    pub(crate) fn new(lender: L) -> Self {
+        Self {
+            lender: IntoResults::new(lender),
+        }
+    }
+
+    /// Returns the inner lender.
+    #[inline]
+    pub fn into_inner(self) -> L {
+        self.lender.into_inner()
+    }
+}
+
+impl<T, L> Iterator for OwnedResults<L>
+where
+    L: FallibleLender,
+    for<'all> FallibleLend<'all, L>: ToOwned<Owned = T>,
+{
+    type Item = Result<T, L::Error>;
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.lender.next().map(|x| x.map(|ref x| x.to_owned()))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.lender.size_hint()
+    }
+}
+
+impl<T, L> DoubleEndedIterator for OwnedResults<L>
+where
+    L: DoubleEndedFallibleLender,
+    for<'all> FallibleLend<'all, L>: ToOwned<Owned = T>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.lender.next_back().map(|x| x.map(|ref x| x.to_owned()))
+    }
+}
+
+impl<T, L> FusedIterator for OwnedResults<L>
+where
+    L: FusedFallibleLender,
+    for<'all> FallibleLend<'all, L>: ToOwned<Owned = T>,
+{
+}
@@ -1,5 +1,7 @@
 use crate::TryShunt;
 
+mod batching;
+pub(crate) mod boxed;
 mod chain;
 mod chunk;
 mod chunky;
@@ -7,14 +9,17 @@ mod cloned;
 mod convert;
 mod copied;
 mod cycle;
+mod either;
 mod enumerate;
 mod filter;
 mod filter_map;
 mod flatten;
 mod fuse;
 mod inspect;
+mod interleave;
 mod intersperse;
 mod into_fallible;
+mod into_results;
 mod iter;
 mod map;
 mod map_err;
@@ -28,20 +33,28 @@ mod rev;
 mod scan;
 mod skip;
 mod skip_while;
+mod split;
 mod step_by;
+#[cfg(feature = "streaming-iterator")]
+mod streaming_iter;
 mod take;
 mod take_while;
 mod zip;
 
+pub use boxed::BoxedFallibleLender;
 pub use convert::Convert;
 pub use flatten::{FlatMap as FallibleFlatMap, Flatten as FallibleFlatten};
 pub use intersperse::{
     Intersperse as FallibleIntersperse, IntersperseWith as FallibleIntersperseWith,
 };
 pub use into_fallible::IntoFallible;
+pub use into_results::{IntoResults, OwnedResults};
 pub use map_err::MapErr;
 pub(crate) use non_fallible_adapter::NonFallibleAdapter;
 pub use peekable::Peekable as FalliblePeekable;
+pub use split::SplitInclusive as FallibleSplitInclusive;
+#[cfg(feature = "streaming-iterator")]
+pub use streaming_iter::FallibleStreamingIter;
 
 pub type FallibleTryShuntAdapter<'a, 'b, 'c, 'd, L> =
     TryShunt<'a, &'b mut NonFallibleAdapter<'c, &'d mut L>>;
@@ -0,0 +1,298 @@
+use core::fmt;
+
+use crate::{
+    FallibleLend, FallibleLender, FallibleLending, FalliblePeekable, FusedFallibleLender, Split,
+    SplitPart,
+};
+
+impl<L: FallibleLender, P> Split<L, P> {
+    #[inline]
+    pub(crate) fn new_fallible(lender: L, predicate: P) -> Split<L, P> {
+        crate::__check_fallible_lender_covariance::<L>();
+        Split {
+            lender,
+            predicate,
+            in_part: false,
+            finished: false,
+        }
+    }
+}
+
+impl<'lend, L, P> FallibleLending<'lend> for Split<L, P>
+where
+    P: FnMut(&FallibleLend<'_, L>) -> Result<bool, L::Error>,
+    L: FallibleLender,
+{
+    type Lend = SplitPart<'lend, Self>;
+}
+
+impl<L, P> FallibleLender for Split<L, P>
+where
+    P: FnMut(&FallibleLend<'_, L>) -> Result<bool, L::Error>,
+    L: FallibleLender,
+{
+    type Error = L::Error;
+    // SAFETY: the lend is a SplitPart wrapping a reference to Self
+    crate::unsafe_assume_covariance_fallible!();
+
+    #[inline]
+    fn next(&mut self) -> Result<Option<FallibleLend<'_, Self>>, Self::Error> {
+        // Skip what is left of the previous part, if anything
+        while self.in_part {
+            match self.lender.next()? {
+                Some(x) => self.in_part = !(self.predicate)(&x)?,
+                None => {
+                    self.in_part = false;
+                    self.finished = true;
+                }
+            }
+        }
+        if self.finished {
+            return Ok(None);
+        }
+        self.in_part = true;
+        Ok(Some(SplitPart { split: self }))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            return (0, Some(0));
+        }
+        let (_, upper) = self.lender.size_hint();
+        if self.in_part {
+            // Every further part needs a separator from the underlying lender
+            (0, upper)
+        } else {
+            (1, upper.and_then(|x| x.checked_add(1)))
+        }
+    }
+}
+
+// Once the underlying lender has returned None, Split never polls it again.
+impl<L, P> FusedFallibleLender for Split<L, P>
+where
+    P: FnMut(&FallibleLend<'_, L>) -> Result<bool, L::Error>,
+    L: FallibleLender,
+{
+}
+
+/// A fallible lender yielding sub-lenders ([`SplitPart`]s) of the underlying
+/// lender, each terminated by a lend that satisfies a predicate.
+///
+/// This `struct` is created by the
+/// [`split_inclusive()`](crate::FallibleLender::split_inclusive) method on
+/// [`FallibleLender`]. See its documentation for more.
+#[must_use = "lenders are lazy and do nothing unless consumed"]
+pub struct SplitInclusive<'this, L, P>
+where
+    L: FallibleLender,
+{
+    // Peeking tells whether there is another part before lending it
+    pub(crate) lender: FalliblePeekable<'this, L>,
+    pub(crate) predicate: P,
+    // A part has been lent and its separator has not been reached yet.
+    pub(crate) in_part: bool,
+}
+
+impl<'this, L: FallibleLender, P> SplitInclusive<'this, L, P> {
+    #[inline]
+    pub(crate) fn new(lender: L, predicate: P) -> SplitInclusive<'this, L, P> {
+        crate::__check_fallible_lender_covariance::<L>();
+        SplitInclusive {
+            lender: FalliblePeekable::new(lender),
+            predicate,
+            in_part: false,
+        }
+    }
+
+    /// Returns the inner lender.
+    #[inline]
+    pub fn into_inner(self) -> L {
+        self.lender.into_inner()
+    }
+
+    /// Returns the inner lender and the predicate.
+    #[inline]
+    pub fn into_parts(self) -> (L, P) {
+        (self.lender.into_inner(), self.predicate)
+    }
+}
+
+impl<'this, L, P> fmt::Debug for SplitInclusive<'this, L, P>
+where
+    L: FallibleLender + fmt::Debug,
+    FallibleLend<'this, L>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FallibleSplitInclusive")
+            .field("lender", &self.lender)
+            .field("in_part", &self.in_part)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'lend, L, P> FallibleLending<'lend> for SplitInclusive<'_, L, P>
+where
+    P: FnMut(&FallibleLend<'_, L>) -> Result<bool, L::Error>,
+    L: FallibleLender,
+{
+    type Lend = SplitPart<'lend, Self>;
+}
+
+impl<L, P> FallibleLender for SplitInclusive<'_, L, P>
+where
+    P: FnMut(&FallibleLend<'_, L>) -> Result<bool, L::Error>,
+    L: FallibleLender,
+{
+    type Error = L::Error;
+    // SAFETY: the lend is a SplitPart wrapping a reference to Self
+    crate::unsafe_assume_covariance_fallible!();
+
+    #[inline]
+    fn next(&mut self) -> Result<Option<FallibleLend<'_, Self>>, Self::Error> {
+        // Skip what is left of the previous part, if anything
+        while self.in_part {
+            match self.lender.next()? {
+                Some(x) => self.in_part = !(self.predicate)(&x)?,
+                None => self.in_part = false,
+            }
+        }
+        if self.lender.peek()?.is_none() {
+            return Ok(None);
+        }
+        self.in_part = true;
+        Ok(Some(SplitPart { split: self }))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.lender.size_hint();
+        // Every part contains at least one lend
+        if self.in_part {
+            (0, upper)
+        } else {
+            (lower.min(1), upper)
+        }
+    }
+}
+
+impl<L, P> FusedFallibleLender for SplitInclusive<'_, L, P>
+where
+    P: FnMut(&FallibleLend<'_, L>) -> Result<bool, L::Error>,
+    L: FusedFallibleLender,
+{
+}
+
+impl<'lend, L, P> FallibleLending<'lend> for SplitPart<'_, Split<L, P>>
+where
+    P: FnMut(&FallibleLend<'_, L>) -> Result<bool, L::Error>,
+    L: FallibleLender,
+{
+    type Lend = FallibleLend<'lend, L>;
+}
+
+impl<L, P> FallibleLender for SplitPart<'_, Split<L, P>>
+where
+    P: FnMut(&FallibleLend<'_, L>) -> Result<bool, L::Error>,
+    L: FallibleLender,
+{
+    type Error = L::Error;
+    // SAFETY: the lend is that of L
+    crate::unsafe_assume_covariance_fallible!();
+
+    #[inline]
+    fn next(&mut self) -> Result<Option<FallibleLend<'_, Self>>, Self::Error> {
+        let split = &mut *self.split;
+        if !split.in_part {
+            return Ok(None);
+        }
+        match split.lender.next()? {
+            Some(x) => {
+                if (split.predicate)(&x)? {
+                    split.in_part = false;
+                    Ok(None)
+                } else {
+                    Ok(Some(x))
+                }
+            }
+            None => {
+                split.in_part = false;
+                split.finished = true;
+                Ok(None)
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.split.in_part {
+            let (_, upper) = self.split.lender.size_hint();
+            (0, upper)
+        } else {
+            (0, Some(0))
+        }
+    }
+}
+
+impl<L, P> FusedFallibleLender for SplitPart<'_, Split<L, P>>
+where
+    P: FnMut(&FallibleLend<'_, L>) -> Result<bool, L::Error>,
+    L: FallibleLender,
+{
+}
+
+impl<'lend, L, P> FallibleLending<'lend> for SplitPart<'_, SplitInclusive<'_, L, P>>
+where
+    P: FnMut(&FallibleLend<'_, L>) -> Result<bool, L::Error>,
+    L: FallibleLender,
+{
+    type Lend = FallibleLend<'lend, L>;
+}
+
+impl<L, P> FallibleLender for SplitPart<'_, SplitInclusive<'_, L, P>>
+where
+    P: FnMut(&FallibleLend<'_, L>) -> Result<bool, L::Error>,
+    L: FallibleLender,
+{
+    type Error = L::Error;
+    // SAFETY: the lend is that of L
+    crate::unsafe_assume_covariance_fallible!();
+
+    #[inline]
+    fn next(&mut self) -> Result<Option<FallibleLend<'_, Self>>, Self::Error> {
+        let split = &mut *self.split;
+        if !split.in_part {
+            return Ok(None);
+        }
+        match split.lender.next()? {
+            Some(x) => {
+                if (split.predicate)(&x)? {
+                    split.in_part = false;
+                }
+                Ok(Some(x))
+            }
+            None => {
+                split.in_part = false;
+                Ok(None)
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.split.in_part {
+            let (lower, upper) = self.split.lender.size_hint();
+            (lower.min(1), upper)
+        } else {
+            (0, Some(0))
+        }
+    }
+}
+
+impl<L, P> FusedFallibleLender for SplitPart<'_, SplitInclusive<'_, L, P>>
+where
+    P: FnMut(&FallibleLend<'_, L>) -> Result<bool, L::Error>,
+    L: FallibleLender,
+{
+}
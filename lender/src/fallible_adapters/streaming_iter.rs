@@ -0,0 +1,99 @@
+use aliasable::boxed::AliasableBox;
+use core::fmt;
+use fallible_streaming_iterator::{
+    DoubleEndedFallibleStreamingIterator, FallibleStreamingIterator,
+};
+use maybe_dangling::MaybeDangling;
+
+use crate::{DoubleEndedFallibleLender, FallibleLender, FallibleLending};
+
+/// [`FallibleStreamingIterator`] adapter for any [`FallibleLender`] lending
+/// references.
+///
+/// The most recent lend is cached, so that it can be returned by
+/// [`get`](FallibleStreamingIterator::get).
+///
+/// This `struct` is created by the
+/// [`into_fallible_streaming_iter()`](crate::FallibleLender::into_fallible_streaming_iter)
+/// method on [`FallibleLender`], which is available only if the
+/// `streaming-iterator` feature is enabled.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct FallibleStreamingIter<'this, L, T: ?Sized + 'this> {
+    // See StreamingIter.
+    current: MaybeDangling<Option<&'this T>>,
+    lender: AliasableBox<L>,
+}
+
+impl<'this, L, T> FallibleStreamingIter<'this, L, T>
+where
+    L: FallibleLender + for<'all> FallibleLending<'all, Lend = &'all T>,
+    T: ?Sized + 'this,
+{
+    #[inline]
+    pub(crate) fn new(lender: L) -> Self {
+        Self {
+            current: MaybeDangling::new(None),
+            lender: AliasableBox::from_unique(alloc::boxed::Box::new(lender)),
+        }
+    }
+
+    /// Returns the inner fallible lender.
+    #[inline]
+    pub fn into_inner(mut self) -> L {
+        *self.current = None;
+        *AliasableBox::into_unique(self.lender)
+    }
+}
+
+impl<L: fmt::Debug, T: ?Sized + fmt::Debug> fmt::Debug for FallibleStreamingIter<'_, L, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FallibleStreamingIter")
+            .field("current", &*self.current)
+            .field("lender", &*self.lender)
+            .finish()
+    }
+}
+
+impl<'this, L, T> FallibleStreamingIterator for FallibleStreamingIter<'this, L, T>
+where
+    L: FallibleLender + for<'all> FallibleLending<'all, Lend = &'all T>,
+    T: ?Sized + 'this,
+{
+    type Item = T;
+    type Error = L::Error;
+
+    #[inline]
+    fn advance(&mut self) -> Result<(), Self::Error> {
+        *self.current = None;
+        // SAFETY: see StreamingIter::advance
+        *self.current =
+            unsafe { core::mem::transmute::<Option<&'_ T>, Option<&'this T>>(self.lender.next()?) };
+        Ok(())
+    }
+
+    #[inline]
+    fn get(&self) -> Option<&T> {
+        *self.current
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.lender.size_hint()
+    }
+}
+
+impl<'this, L, T> DoubleEndedFallibleStreamingIterator for FallibleStreamingIter<'this, L, T>
+where
+    L: DoubleEndedFallibleLender + for<'all> FallibleLending<'all, Lend = &'all T>,
+    T: ?Sized + 'this,
+{
+    #[inline]
+    fn advance_back(&mut self) -> Result<(), Self::Error> {
+        *self.current = None;
+        // SAFETY: see StreamingIter::advance
+        *self.current = unsafe {
+            core::mem::transmute::<Option<&'_ T>, Option<&'this T>>(self.lender.next_back()?)
+        };
+        Ok(())
+    }
+}
@@ -0,0 +1,426 @@
+use alloc::vec::Vec;
+use core::{fmt, str::Utf8Error};
+use std::io::{self, BufRead};
+
+use crate::{FallibleLend, FallibleLender, FallibleLending, FusedFallibleLender};
+
+/// The kind of a [`DelimitedError`].
+#[derive(Debug)]
+pub enum DelimitedErrorKind {
+    /// Reading from the underlying reader failed.
+    Io(io::Error),
+    /// The input ended inside a quoted field.
+    UnterminatedQuote,
+    /// A closing quote is followed by something other than a delimiter or
+    /// the end of the record.
+    TrailingCharacters,
+}
+
+/// The error returned by the lender created by [`delimited_records()`].
+///
+/// Besides its [kind](DelimitedErrorKind), the error contains the
+/// position in the input at which it was detected.
+#[derive(Debug)]
+pub struct DelimitedError {
+    kind: DelimitedErrorKind,
+    line: u64,
+    offset: u64,
+}
+
+impl DelimitedError {
+    /// Returns the kind of the error.
+    #[inline]
+    pub fn kind(&self) -> &DelimitedErrorKind {
+        &self.kind
+    }
+
+    /// Returns the kind of the error, consuming the error.
+    #[inline]
+    pub fn into_kind(self) -> DelimitedErrorKind {
+        self.kind
+    }
+
+    /// Returns the line number (starting from one) at which the error was
+    /// detected.
+    #[inline]
+    pub fn line(&self) -> u64 {
+        self.line
+    }
+
+    /// Returns the byte offset in the input at which the error was detected.
+    #[inline]
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+}
+
+impl fmt::Display for DelimitedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            DelimitedErrorKind::Io(e) => write!(f, "I/O error: {e}")?,
+            DelimitedErrorKind::UnterminatedQuote => f.write_str("unterminated quoted field")?,
+            DelimitedErrorKind::TrailingCharacters => {
+                f.write_str("unexpected characters after closing quote")?
+            }
+        }
+        write!(f, " at line {}, byte {}", self.line, self.offset)
+    }
+}
+
+impl std::error::Error for DelimitedError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.kind {
+            DelimitedErrorKind::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// Creates a new fallible lender that parses delimited records, such as
+/// CSV or TSV lines, from a buffered reader.
+///
+/// Records are terminated by `\n` or `\r\n`, and fields are separated by
+/// `delim`. A field starting with a double quote is quoted: it ends at the
+/// next unpaired quote, and may contain delimiters, line terminators, and
+/// pairs of quotes standing for a single quote. Quoting can be changed or
+/// disabled with [`DelimitedRecords::quote`].
+///
+/// Each record is lent as a [`Record`], whose fields are slices of a
+/// single reused buffer, collected in reused vectors of fields: after the
+/// first few records, parsing does not allocate. After an error, the lender
+/// returns `Ok(None)`.
+///
+/// # Panics
+///
+/// Panics if `delim` is not an ASCII byte, or if it is a line terminator
+/// or a double quote.
+///
+/// # Examples
+/// ```rust
+/// # use lender::prelude::*;
+/// let data = "name,notes\r\nalice,\"likes \"\"tea\"\", cake\"\nbob,\n";
+/// let mut lender = lender::delimited_records(data.as_bytes(), b',');
+/// lender.next().unwrap(); // Skip the header
+/// let record = lender.next().unwrap().unwrap();
+/// assert_eq!(record.get_str(0).unwrap(), Ok("alice"));
+/// assert_eq!(record.get_str(1).unwrap(), Ok("likes \"tea\", cake"));
+/// let record = lender.next().unwrap().unwrap();
+/// assert_eq!(record.fields(), [&b"bob"[..], b""]);
+/// assert_eq!(record.line(), 3);
+/// assert!(lender.next().unwrap().is_none());
+/// ```
+#[inline]
+pub fn delimited_records<R: BufRead>(reader: R, delim: u8) -> DelimitedRecords<R> {
+    assert!(
+        delim.is_ascii() && !matches!(delim, b'\n' | b'\r' | b'"'),
+        "the delimiter must be an ASCII byte other than a line terminator or a quote"
+    );
+    DelimitedRecords {
+        reader,
+        delim,
+        quote: Some(b'"'),
+        buf: Vec::new(),
+        ends: Vec::new(),
+        fields: Vec::new(),
+        str_fields: Vec::new(),
+        utf8_error: None,
+        line: 1,
+        offset: 0,
+        next_line: 1,
+        next_offset: 0,
+        done: false,
+    }
+}
+
+/// A fallible lender over the delimited records of a buffered reader.
+///
+/// This `struct` is created by the [`delimited_records()`] function.
+#[must_use = "lenders are lazy and do nothing unless consumed"]
+pub struct DelimitedRecords<R> {
+    reader: R,
+    delim: u8,
+    quote: Option<u8>,
+    // The raw bytes of the current record, starting with the concatenation
+    // of its unescaped fields.
+    buf: Vec<u8>,
+    // The end offsets in buf of the fields of the current record.
+    ends: Vec<usize>,
+    // The fields of the current record, and its fields as strings up to the
+    // first one that is not valid UTF-8. The slices borrow from buf, so their
+    // lifetime is a placeholder: they are valid until buf is modified, and
+    // these vectors are cleared before that happens.
+    fields: Vec<&'static [u8]>,
+    str_fields: Vec<&'static str>,
+    utf8_error: Option<Utf8Error>,
+    // The position of the current record.
+    line: u64,
+    offset: u64,
+    // The position of the next record.
+    next_line: u64,
+    next_offset: u64,
+    done: bool,
+}
+
+impl<R> DelimitedRecords<R> {
+    /// Sets the quote byte, or disables quoting if `quote` is `None`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `quote` is not an ASCII byte, or if it is a line terminator
+    /// or the delimiter.
+    #[inline]
+    pub fn quote(mut self, quote: Option<u8>) -> Self {
+        if let Some(q) = quote {
+            assert!(
+                q.is_ascii() && !matches!(q, b'\n' | b'\r') && q != self.delim,
+                "the quote must be an ASCII byte other than a line terminator or the delimiter"
+            );
+        }
+        self.quote = quote;
+        self
+    }
+
+    /// Returns the underlying reader.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: fmt::Debug> fmt::Debug for DelimitedRecords<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DelimitedRecords")
+            .field("reader", &self.reader)
+            .field("delim", &self.delim)
+            .field("quote", &self.quote)
+            .field("line", &self.next_line)
+            .field("offset", &self.next_offset)
+            .field("done", &self.done)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<R: BufRead> DelimitedRecords<R> {
+    /// Returns an error of the given kind detected at position `r` of the
+    /// buffer, while `w` unescaped bytes have been written.
+    #[inline]
+    fn error(&self, kind: DelimitedErrorKind, r: usize, w: usize) -> DelimitedError {
+        // Unescaping drops quotes and delimiters, but not line terminators
+        let newlines = self.buf[..w].iter().filter(|&&b| b == b'\n').count();
+        DelimitedError {
+            kind,
+            line: self.line + newlines as u64,
+            offset: self.offset + r as u64,
+        }
+    }
+
+    /// Appends the next line of input to the buffer, returning its length.
+    #[inline]
+    fn read_line(&mut self, w: usize) -> Result<usize, DelimitedError> {
+        let r = self.buf.len();
+        match self.reader.read_until(b'\n', &mut self.buf) {
+            Ok(n) => Ok(n),
+            Err(e) => Err(self.error(DelimitedErrorKind::Io(e), r, w)),
+        }
+    }
+
+    /// Parses the next record, unescaping its fields in place, and returns
+    /// whether there was one.
+    fn parse(&mut self) -> Result<bool, DelimitedError> {
+        self.line = self.next_line;
+        self.offset = self.next_offset;
+        self.fields.clear();
+        self.str_fields.clear();
+        self.utf8_error = None;
+        self.buf.clear();
+        self.ends.clear();
+        if self.read_line(0)? == 0 {
+            return Ok(false);
+        }
+        // Bytes are read at r and written at w <= r
+        let (mut r, mut w) = (0, 0);
+        loop {
+            match self.quote {
+                Some(q) if self.buf.get(r) == Some(&q) => {
+                    r += 1;
+                    loop {
+                        if r == self.buf.len() && self.read_line(w)? == 0 {
+                            return Err(self.error(DelimitedErrorKind::UnterminatedQuote, r, w));
+                        }
+                        let b = self.buf[r];
+                        r += 1;
+                        if b == q {
+                            if self.buf.get(r) != Some(&q) {
+                                break;
+                            }
+                            r += 1;
+                        }
+                        self.buf[w] = b;
+                        w += 1;
+                    }
+                    match self.buf.get(r) {
+                        Some(&b) if b == self.delim => {}
+                        None | Some(b'\n') => break,
+                        Some(b'\r') if self.buf.get(r + 1) == Some(&b'\n') => break,
+                        Some(_) => {
+                            return Err(self.error(DelimitedErrorKind::TrailingCharacters, r, w));
+                        }
+                    }
+                }
+                _ => {
+                    let start = w;
+                    while let Some(&b) = self.buf.get(r) {
+                        if b == self.delim || b == b'\n' {
+                            break;
+                        }
+                        self.buf[w] = b;
+                        w += 1;
+                        r += 1;
+                    }
+                    if self.buf.get(r) != Some(&self.delim) {
+                        if w > start && self.buf[w - 1] == b'\r' && r < self.buf.len() {
+                            w -= 1;
+                        }
+                        break;
+                    }
+                }
+            }
+            // Skip the delimiter
+            self.ends.push(w);
+            r += 1;
+        }
+        self.ends.push(w);
+        let newlines = self.buf[..w].iter().filter(|&&b| b == b'\n').count()
+            + usize::from(self.buf.last() == Some(&b'\n'));
+        self.next_line = self.line + newlines as u64;
+        self.next_offset = self.offset + self.buf.len() as u64;
+
+        let mut start = 0;
+        for &end in &self.ends {
+            // SAFETY: see the comment on the fields field
+            let field: &'static [u8] = unsafe { &*core::ptr::from_ref(&self.buf[start..end]) };
+            self.fields.push(field);
+            if self.utf8_error.is_none() {
+                match core::str::from_utf8(field) {
+                    Ok(s) => self.str_fields.push(s),
+                    Err(e) => self.utf8_error = Some(e),
+                }
+            }
+            start = end;
+        }
+        Ok(true)
+    }
+}
+
+impl<'lend, R: BufRead> FallibleLending<'lend> for DelimitedRecords<R> {
+    type Lend = Record<'lend>;
+}
+
+impl<R: BufRead> FallibleLender for DelimitedRecords<R> {
+    type Error = DelimitedError;
+    crate::check_covariance_fallible!();
+
+    #[inline]
+    fn next(&mut self) -> Result<Option<FallibleLend<'_, Self>>, Self::Error> {
+        if self.done {
+            return Ok(None);
+        }
+        match self.parse() {
+            Ok(true) => Ok(Some(Record {
+                fields: &self.fields,
+                str_fields: match self.utf8_error {
+                    None => Ok(&self.str_fields),
+                    Some(e) => Err(e),
+                },
+                line: self.line,
+                offset: self.offset,
+            })),
+            Ok(false) => {
+                self.done = true;
+                Ok(None)
+            }
+            Err(e) => {
+                self.done = true;
+                Err(e)
+            }
+        }
+    }
+}
+
+impl<R: BufRead> FusedFallibleLender for DelimitedRecords<R> {}
+
+/// A record parsed by [`DelimitedRecords`].
+///
+/// A record has at least one field; an empty line is a record with a single
+/// empty field. Fields are unescaped byte slices, and can be accessed as
+/// strings with [`get_str`](Record::get_str) and
+/// [`str_fields`](Record::str_fields), which check UTF-8 validity.
+///
+/// This `struct` is lent by [`DelimitedRecords`].
+#[derive(Clone, Copy)]
+pub struct Record<'a> {
+    fields: &'a [&'a [u8]],
+    str_fields: Result<&'a [&'a str], Utf8Error>,
+    line: u64,
+    offset: u64,
+}
+
+impl fmt::Debug for Record<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(
+                self.fields
+                    .iter()
+                    .map(|f| alloc::string::String::from_utf8_lossy(f)),
+            )
+            .finish()
+    }
+}
+
+impl<'a> Record<'a> {
+    /// Returns the number of fields of the record.
+    #[inline]
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    /// Returns field `i` of the record, or `None` if out of bounds.
+    #[inline]
+    pub fn get(&self, i: usize) -> Option<&'a [u8]> {
+        self.fields.get(i).copied()
+    }
+
+    /// Returns field `i` of the record as a string, or `None` if out of
+    /// bounds.
+    #[inline]
+    pub fn get_str(&self, i: usize) -> Option<Result<&'a str, Utf8Error>> {
+        self.get(i).map(core::str::from_utf8)
+    }
+
+    /// Returns the fields of the record.
+    #[inline]
+    pub fn fields(&self) -> &'a [&'a [u8]] {
+        self.fields
+    }
+
+    /// Returns the fields of the record as strings, or the error of the first
+    /// field that is not valid UTF-8.
+    #[inline]
+    pub fn str_fields(&self) -> Result<&'a [&'a str], Utf8Error> {
+        self.str_fields
+    }
+
+    /// Returns the line number (starting from one) at which the record
+    /// starts.
+    #[inline]
+    pub fn line(&self) -> u64 {
+        self.line
+    }
+
+    /// Returns the byte offset in the input at which the record starts.
+    #[inline]
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+}
@@ -0,0 +1,448 @@
+use core::{fmt, ops::Range};
+
+use bytes::{Buf, BytesMut};
+
+use crate::{FallibleLend, FallibleLender, FallibleLending};
+
+/// The default maximum length of a frame of the decoders in this module
+/// (8 MiB).
+const DEFAULT_MAX_LEN: usize = 8 * 1024 * 1024;
+
+/// The error returned by the decoders in this module, and that must be
+/// convertible into the error of a [`FrameDecoder`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FrameError {
+    /// The input ends in the middle of a frame, of which `len` bytes are
+    /// available.
+    Truncated {
+        /// The number of bytes of the truncated frame.
+        len: usize,
+    },
+    /// A frame is longer than the maximum length of the decoder.
+    TooLong {
+        /// The length of the frame, or the number of bytes examined without
+        /// finding its end.
+        len: usize,
+        /// The maximum length of a frame.
+        max_len: usize,
+    },
+}
+
+impl fmt::Display for FrameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FrameError::Truncated { len } => {
+                write!(f, "truncated frame of {len} bytes at end of input")
+            }
+            FrameError::TooLong { len, max_len } => {
+                write!(f, "frame of {len} bytes exceeds maximum length {max_len}")
+            }
+        }
+    }
+}
+
+impl core::error::Error for FrameError {}
+
+/// The position of a frame in a buffer, as returned by a [`FrameDecoder`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Frame {
+    /// The range of the buffer containing the data of the frame, which is
+    /// what is lent.
+    pub data: Range<usize>,
+    /// The number of bytes of the buffer making up the frame, including
+    /// headers and delimiters, which are discarded before decoding the next
+    /// frame.
+    pub len: usize,
+}
+
+/// A decoder splitting a buffer into frames, to be used with [`frames()`].
+///
+/// Decoders do not copy data: they just return the [position](Frame) of the
+/// next frame in the buffer.
+pub trait FrameDecoder {
+    /// The error returned when decoding fails.
+    type Error: From<FrameError>;
+
+    /// Returns the position of the first frame in `buf`, or `None` if `buf`
+    /// does not contain a complete frame yet.
+    ///
+    /// Decoders may keep state between calls returning `None` (e.g., to
+    /// avoid scanning again the same bytes), as in that case the next call
+    /// will receive the same buffer, possibly with more data at the end.
+    fn decode(&mut self, buf: &[u8]) -> Result<Option<Frame>, Self::Error>;
+
+    /// Returns the position of the first frame in `buf`, or `None` if `buf`
+    /// is empty, knowing that no more data will be appended to `buf`.
+    ///
+    /// The default implementation calls [`decode`](FrameDecoder::decode),
+    /// and returns a [`FrameError::Truncated`] error if `buf` is not empty
+    /// but does not contain a complete frame.
+    fn decode_eof(&mut self, buf: &[u8]) -> Result<Option<Frame>, Self::Error> {
+        match self.decode(buf)? {
+            None if !buf.is_empty() => Err(FrameError::Truncated { len: buf.len() }.into()),
+            frame => Ok(frame),
+        }
+    }
+}
+
+impl<D: FrameDecoder + ?Sized> FrameDecoder for &mut D {
+    type Error = D::Error;
+
+    #[inline]
+    fn decode(&mut self, buf: &[u8]) -> Result<Option<Frame>, Self::Error> {
+        (**self).decode(buf)
+    }
+
+    #[inline]
+    fn decode_eof(&mut self, buf: &[u8]) -> Result<Option<Frame>, Self::Error> {
+        (**self).decode_eof(buf)
+    }
+}
+
+/// A [`FrameDecoder`] for frames made of an unsigned integer length prefix
+/// followed by as many bytes of data.
+///
+/// The prefix is big-endian by default, and the maximum length of a frame
+/// is 8 MiB by default.
+///
+/// # Examples
+/// ```rust
+/// # use lender::prelude::*;
+/// # use lender::LengthPrefixed;
+/// let data = [0_u8, 2, b'h', b'i', 0, 0, 1, b'!'];
+/// let mut lender = lender::frames(&data[..], LengthPrefixed::new(2));
+/// assert_eq!(lender.next().unwrap().as_deref(), Some(&b"hi"[..]));
+/// assert_eq!(lender.next().unwrap().as_deref(), Some(&b""[..]));
+/// assert!(lender.next().is_err()); // Truncated frame
+/// ```
+#[derive(Clone, Debug)]
+pub struct LengthPrefixed {
+    prefix_len: usize,
+    little_endian: bool,
+    max_len: usize,
+}
+
+impl LengthPrefixed {
+    /// Creates a new decoder for frames with a big-endian length prefix of
+    /// `prefix_len` bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `prefix_len` is zero or greater than eight.
+    #[inline]
+    pub fn new(prefix_len: usize) -> Self {
+        assert!(
+            (1..=8).contains(&prefix_len),
+            "the length of the prefix must be between one and eight bytes"
+        );
+        Self {
+            prefix_len,
+            little_endian: false,
+            max_len: DEFAULT_MAX_LEN,
+        }
+    }
+
+    /// Makes the length prefix little-endian.
+    #[inline]
+    pub fn little_endian(mut self) -> Self {
+        self.little_endian = true;
+        self
+    }
+
+    /// Sets the maximum length of the data of a frame; longer frames cause
+    /// a [`FrameError::TooLong`] error.
+    #[inline]
+    pub fn max_len(mut self, max_len: usize) -> Self {
+        self.max_len = max_len;
+        self
+    }
+}
+
+impl FrameDecoder for LengthPrefixed {
+    type Error = FrameError;
+
+    fn decode(&mut self, buf: &[u8]) -> Result<Option<Frame>, Self::Error> {
+        let Some(prefix) = buf.get(..self.prefix_len) else {
+            return Ok(None);
+        };
+        let len = if self.little_endian {
+            prefix
+                .iter()
+                .rev()
+                .fold(0, |len, &b| len << 8 | u64::from(b))
+        } else {
+            prefix.iter().fold(0, |len, &b| len << 8 | u64::from(b))
+        };
+        // The length is untrusted, so the end of the frame may overflow
+        let end = usize::try_from(len)
+            .ok()
+            .filter(|&len| len <= self.max_len)
+            .and_then(|len| self.prefix_len.checked_add(len));
+        let Some(end) = end else {
+            return Err(FrameError::TooLong {
+                len: usize::try_from(len).unwrap_or(usize::MAX),
+                max_len: self.max_len,
+            });
+        };
+        Ok((buf.len() >= end).then_some(Frame {
+            data: self.prefix_len..end,
+            len: end,
+        }))
+    }
+}
+
+/// A [`FrameDecoder`] for frames terminated by a delimiter byte, which is
+/// not part of the data.
+///
+/// At the end of the input, trailing bytes without a delimiter form a last
+/// frame. The maximum length of a frame is 8 MiB by default.
+///
+/// # Examples
+/// ```rust
+/// # use lender::prelude::*;
+/// # use lender::ByteDelimited;
+/// let mut lender = lender::frames("a,bc,,d", ByteDelimited::new(b','));
+/// assert_eq!(lender.next().unwrap().as_deref(), Some(&b"a"[..]));
+/// assert_eq!(lender.next().unwrap().as_deref(), Some(&b"bc"[..]));
+/// assert_eq!(lender.next().unwrap().as_deref(), Some(&b""[..]));
+/// assert_eq!(lender.next().unwrap().as_deref(), Some(&b"d"[..]));
+/// assert_eq!(lender.next(), Ok(None));
+/// ```
+#[derive(Clone, Debug)]
+pub struct ByteDelimited {
+    delim: u8,
+    max_len: usize,
+    // The number of bytes already searched for the delimiter.
+    searched: usize,
+}
+
+impl ByteDelimited {
+    /// Creates a new decoder for frames terminated by `delim`.
+    #[inline]
+    pub fn new(delim: u8) -> Self {
+        Self {
+            delim,
+            max_len: DEFAULT_MAX_LEN,
+            searched: 0,
+        }
+    }
+
+    /// Sets the maximum length of the data of a frame; longer frames cause
+    /// a [`FrameError::TooLong`] error.
+    #[inline]
+    pub fn max_len(mut self, max_len: usize) -> Self {
+        self.max_len = max_len;
+        self
+    }
+}
+
+impl FrameDecoder for ByteDelimited {
+    type Error = FrameError;
+
+    fn decode(&mut self, buf: &[u8]) -> Result<Option<Frame>, Self::Error> {
+        // Look at one more byte than max_len, as it might be the delimiter
+        let end = buf.len().min(self.max_len.saturating_add(1));
+        let start = self.searched.min(end);
+        match buf[start..end].iter().position(|&b| b == self.delim) {
+            Some(pos) => {
+                self.searched = 0;
+                Ok(Some(Frame {
+                    data: 0..start + pos,
+                    len: start + pos + 1,
+                }))
+            }
+            None if buf.len() > self.max_len => {
+                self.searched = 0;
+                Err(FrameError::TooLong {
+                    len: buf.len(),
+                    max_len: self.max_len,
+                })
+            }
+            None => {
+                self.searched = end;
+                Ok(None)
+            }
+        }
+    }
+
+    fn decode_eof(&mut self, buf: &[u8]) -> Result<Option<Frame>, Self::Error> {
+        match self.decode(buf)? {
+            None if !buf.is_empty() => {
+                self.searched = 0;
+                Ok(Some(Frame {
+                    data: 0..buf.len(),
+                    len: buf.len(),
+                }))
+            }
+            frame => Ok(frame),
+        }
+    }
+}
+
+/// Creates a new fallible lender that splits a [`BytesMut`] into frames
+/// using a [`FrameDecoder`], lending each frame as a mutable slice of the
+/// buffer.
+///
+/// Frames are not copied: consumed bytes are discarded lazily, at the next
+/// call to [`next`](FallibleLender::next), by advancing the buffer, and
+/// their space is reclaimed by [`BytesMut`] when more data is appended.
+///
+/// The buffer is initially assumed to contain the whole input, so a
+/// trailing incomplete frame is handled by [`FrameDecoder::decode_eof`]. To
+/// decode data as it arrives, call [`set_eof(false)`](Frames::set_eof) and
+/// append data using [`buffer_mut`](Frames::buffer_mut) or
+/// [`extend_from_buf`](Frames::extend_from_buf): then, the lender returns
+/// `Ok(None)` when the buffer does not contain a complete frame, and
+/// continues after more data is appended. After an error, the lender
+/// returns `Ok(None)`.
+///
+/// This function is available only if the `bytes` feature is enabled.
+///
+/// # Panics
+///
+/// The lender panics if the decoder returns a frame of length zero, or
+/// whose data is not within the frame, or which is longer than the buffer.
+///
+/// # Examples
+/// ```rust
+/// # use lender::prelude::*;
+/// # use lender::{ByteDelimited, LengthPrefixed};
+/// let mut lender = lender::frames(&b"ab\ncd\nef"[..], ByteDelimited::new(b'\n'));
+/// let frame = lender.next().unwrap().unwrap();
+/// frame.make_ascii_uppercase();
+/// assert_eq!(frame, b"AB");
+/// assert_eq!(lender.next().unwrap().as_deref(), Some(&b"cd"[..]));
+///
+/// let mut lender = lender::frames(&[1_u8, 7][..], LengthPrefixed::new(1));
+/// lender.set_eof(false);
+/// assert_eq!(lender.next(), Ok(Some(&mut [7][..])));
+/// assert_eq!(lender.next(), Ok(None));
+/// lender.extend_from_buf(&[2_u8, 3][..]);
+/// assert_eq!(lender.next(), Ok(None)); // Incomplete frame
+/// lender.extend_from_buf(&[4_u8][..]);
+/// assert_eq!(lender.next(), Ok(Some(&mut [3, 4][..])));
+/// ```
+#[inline]
+pub fn frames<B: Into<BytesMut>, D: FrameDecoder>(buf: B, decoder: D) -> Frames<D> {
+    Frames {
+        buf: buf.into(),
+        decoder,
+        consumed: 0,
+        eof: true,
+        done: false,
+    }
+}
+
+/// A fallible lender over the frames of a [`BytesMut`].
+///
+/// This `struct` is created by the [`frames()`] function.
+#[derive(Debug)]
+#[must_use = "lenders are lazy and do nothing unless consumed"]
+pub struct Frames<D> {
+    buf: BytesMut,
+    decoder: D,
+    // The number of bytes of the last frame, to be discarded.
+    consumed: usize,
+    eof: bool,
+    done: bool,
+}
+
+impl<D> Frames<D> {
+    /// Sets whether the buffer contains the rest of the input.
+    ///
+    /// If `eof` is false, incomplete frames at the end of the buffer are
+    /// left for later calls, after more data has been appended.
+    #[inline]
+    pub fn set_eof(&mut self, eof: bool) {
+        self.eof = eof;
+    }
+
+    /// Returns whether the buffer contains the rest of the input.
+    #[inline]
+    pub fn is_eof(&self) -> bool {
+        self.eof
+    }
+
+    /// Returns the bytes of the buffer that have not been consumed yet.
+    #[inline]
+    pub fn buffer(&self) -> &[u8] {
+        &self.buf[self.consumed..]
+    }
+
+    /// Returns the underlying buffer, after discarding consumed bytes, so
+    /// that more data can be appended.
+    #[inline]
+    pub fn buffer_mut(&mut self) -> &mut BytesMut {
+        self.discard_consumed();
+        &mut self.buf
+    }
+
+    /// Appends the contents of a [`Buf`] to the buffer.
+    #[inline]
+    pub fn extend_from_buf<B: Buf>(&mut self, mut buf: B) {
+        let buffer = self.buffer_mut();
+        buffer.reserve(buf.remaining());
+        while buf.has_remaining() {
+            let chunk = buf.chunk();
+            buffer.extend_from_slice(chunk);
+            let len = chunk.len();
+            buf.advance(len);
+        }
+    }
+
+    /// Returns a reference to the decoder.
+    #[inline]
+    pub fn decoder(&self) -> &D {
+        &self.decoder
+    }
+
+    /// Returns the bytes of the buffer that have not been consumed yet,
+    /// consuming the lender.
+    #[inline]
+    pub fn into_inner(mut self) -> BytesMut {
+        self.discard_consumed();
+        self.buf
+    }
+
+    #[inline]
+    fn discard_consumed(&mut self) {
+        self.buf.advance(core::mem::take(&mut self.consumed));
+    }
+}
+
+impl<'lend, D: FrameDecoder> FallibleLending<'lend> for Frames<D> {
+    type Lend = &'lend mut [u8];
+}
+
+impl<D: FrameDecoder> FallibleLender for Frames<D> {
+    type Error = D::Error;
+    crate::check_covariance_fallible!();
+
+    fn next(&mut self) -> Result<Option<FallibleLend<'_, Self>>, Self::Error> {
+        if self.done {
+            return Ok(None);
+        }
+        self.discard_consumed();
+        let frame = if self.eof {
+            self.decoder.decode_eof(&self.buf)
+        } else {
+            self.decoder.decode(&self.buf)
+        };
+        match frame {
+            Ok(Some(Frame { data, len })) => {
+                assert!(
+                    len != 0 && data.start <= data.end && data.end <= len && len <= self.buf.len(),
+                    "the decoder returned an invalid frame (data {data:?}, length {len}) for a buffer of {} bytes",
+                    self.buf.len()
+                );
+                self.consumed = len;
+                Ok(Some(&mut self.buf[data]))
+            }
+            Ok(None) => Ok(None),
+            Err(e) => {
+                self.done = true;
+                Err(e)
+            }
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use fallible_streaming_iterator::{
+    DoubleEndedFallibleStreamingIterator, FallibleStreamingIterator,
+};
+
+use crate::{DoubleEndedFallibleLender, FallibleLend, FallibleLender, FallibleLending};
+
+/// Creates a fallible lender from a [`FallibleStreamingIterator`], lending
+/// references to its items.
+///
+/// This function is available only if the `streaming-iterator` feature is
+/// enabled.
+///
+/// Note that the
+/// [`FallibleStreamingIteratorExt`](crate::FallibleStreamingIteratorExt)
+/// trait provides a convenient entry point for this function as a method on
+/// fallible streaming iterators.
+///
+/// # Examples
+/// ```rust
+/// # use lender::prelude::*;
+/// let data = [Ok(&1), Err("error")];
+/// let mut lender =
+///     lender::from_fallible_streaming_iter(fallible_streaming_iterator::convert(data.into_iter()));
+/// assert_eq!(lender.next(), Ok(Some(&1)));
+/// assert_eq!(lender.next(), Err("error"));
+/// ```
+#[inline]
+pub const fn from_streaming_iter<I: FallibleStreamingIterator>(iter: I) -> FromStreamingIter<I> {
+    FromStreamingIter { iter }
+}
+
+/// A fallible lender that lends references to the items of a
+/// [`FallibleStreamingIterator`].
+///
+/// This `struct` is created by the
+/// [`from_fallible_streaming_iter()`](crate::from_fallible_streaming_iter)
+/// function.
+#[derive(Clone, Debug)]
+#[must_use = "lenders are lazy and do nothing unless consumed"]
+pub struct FromStreamingIter<I> {
+    iter: I,
+}
+
+impl<I> FromStreamingIter<I> {
+    /// Returns the underlying fallible streaming iterator.
+    #[inline]
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<'lend, I: FallibleStreamingIterator> FallibleLending<'lend> for FromStreamingIter<I> {
+    type Lend = &'lend I::Item;
+}
+
+impl<I: FallibleStreamingIterator> FallibleLender for FromStreamingIter<I> {
+    type Error = I::Error;
+    crate::check_covariance_fallible!();
+
+    #[inline]
+    fn next(&mut self) -> Result<Option<FallibleLend<'_, Self>>, Self::Error> {
+        self.iter.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I: DoubleEndedFallibleStreamingIterator> DoubleEndedFallibleLender for FromStreamingIter<I> {
+    #[inline]
+    fn next_back(&mut self) -> Result<Option<FallibleLend<'_, Self>>, Self::Error> {
+        self.iter.next_back()
+    }
+}
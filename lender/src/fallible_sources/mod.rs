@@ -1,9 +1,17 @@
+#[cfg(feature = "std")]
+mod delimited;
 mod empty;
+#[cfg(feature = "bytes")]
+mod frames;
 mod from_fn;
 mod from_iter;
 mod from_iter_ref;
+#[cfg(feature = "streaming-iterator")]
+mod from_streaming_iter;
 mod once;
 mod once_with;
+#[cfg(feature = "std")]
+mod records;
 mod repeat;
 mod repeat_with;
 
@@ -33,3 +41,22 @@ pub use self::{
         repeat_with as fallible_repeat_with, repeat_with_err as fallible_repeat_with_err,
     },
 };
+
+#[cfg(feature = "std")]
+pub use self::delimited::{
+    DelimitedError, DelimitedErrorKind, DelimitedRecords, Record, delimited_records,
+};
+#[cfg(feature = "bytes")]
+pub use self::frames::{
+    ByteDelimited, Frame, FrameDecoder, FrameError, Frames, LengthPrefixed, frames,
+};
+#[cfg(feature = "streaming-iterator")]
+pub use self::from_streaming_iter::{
+    FromStreamingIter as FromFallibleStreamingIter,
+    from_streaming_iter as from_fallible_streaming_iter,
+};
+#[cfg(feature = "std")]
+pub use self::records::{
+    ArrayRecords, PartialRecordError, Records, RecordsBuffered, array_records, records,
+    records_buffered,
+};
@@ -0,0 +1,386 @@
+use alloc::{vec, vec::Vec};
+use core::fmt;
+use std::io::{self, ErrorKind, Read};
+
+use crate::{FallibleLend, FallibleLender, FallibleLending, FusedFallibleLender};
+
+/// The error wrapped in the [`io::Error`] returned by record readers when the
+/// input ends in the middle of a record.
+///
+/// The [`io::Error`] has kind [`ErrorKind::UnexpectedEof`], and this error
+/// can be recovered with [`io::Error::get_ref`] and
+/// [`downcast_ref`](std::error::Error#method.downcast_ref).
+///
+/// # Examples
+/// ```rust
+/// # use lender::prelude::*;
+/// # use lender::PartialRecordError;
+/// let mut lender = lender::records(&[1_u8, 2, 3][..], 2);
+/// assert_eq!(lender.next().unwrap(), Some(&mut [1, 2][..]));
+/// let err = lender.next().unwrap_err();
+/// let partial = err.get_ref().unwrap().downcast_ref::<PartialRecordError>();
+/// assert_eq!(partial, Some(&PartialRecordError { len: 1, record_len: 2 }));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PartialRecordError {
+    /// The number of bytes of the partial record.
+    pub len: usize,
+    /// The length of a record.
+    pub record_len: usize,
+}
+
+impl fmt::Display for PartialRecordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "trailing partial record of {} bytes (record length {})",
+            self.len, self.record_len
+        )
+    }
+}
+
+impl std::error::Error for PartialRecordError {}
+
+impl PartialRecordError {
+    #[inline]
+    fn into_io(self) -> io::Error {
+        io::Error::new(ErrorKind::UnexpectedEof, self)
+    }
+}
+
+/// Fills `buf` from `reader`, stopping early only at end of input, and
+/// returns the number of bytes read.
+#[inline]
+fn read_full<R: Read>(reader: &mut R, buf: &mut [u8]) -> io::Result<usize> {
+    let mut len = 0;
+    while len < buf.len() {
+        match reader.read(&mut buf[len..]) {
+            Ok(0) => break,
+            Ok(n) => len += n,
+            Err(e) if e.kind() == ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(len)
+}
+
+/// Reads the next record into `buf`, returning whether there was one.
+#[inline]
+fn read_record<R: Read>(reader: &mut R, buf: &mut [u8], done: &mut bool) -> io::Result<bool> {
+    if *done {
+        return Ok(false);
+    }
+    let len = read_full(reader, buf).inspect_err(|_| *done = true)?;
+    if len == buf.len() {
+        return Ok(true);
+    }
+    *done = true;
+    if len == 0 {
+        Ok(false)
+    } else {
+        Err(PartialRecordError {
+            len,
+            record_len: buf.len(),
+        }
+        .into_io())
+    }
+}
+
+/// Creates a new fallible lender that reads fixed-length records from a
+/// reader, lending each record as a mutable slice of a reused buffer.
+///
+/// Each record is read with as many calls to [`Read::read`] as necessary;
+/// [interrupted](ErrorKind::Interrupted) reads are retried. If the input
+/// ends in the middle of a record, the lender returns an error wrapping a
+/// [`PartialRecordError`]. After an error, the lender returns `Ok(None)`.
+///
+/// Since every call to [`next`](FallibleLender::next) reads from `reader`,
+/// unbuffered readers should use [`records_buffered()`] instead. The const
+/// generic equivalent is [`array_records()`].
+///
+/// # Panics
+///
+/// Panics if `record_len` is zero.
+///
+/// # Examples
+/// ```rust
+/// # use lender::prelude::*;
+/// let data = [1_u8, 2, 3, 4, 5, 6];
+/// let mut lender = lender::records(&data[..], 3);
+/// let record = lender.next().unwrap().unwrap();
+/// record.reverse();
+/// assert_eq!(record, &[3, 2, 1]);
+/// assert_eq!(lender.next().unwrap(), Some(&mut [4, 5, 6][..]));
+/// assert_eq!(lender.next().unwrap(), None);
+/// ```
+#[inline]
+pub fn records<R: Read>(reader: R, record_len: usize) -> Records<R> {
+    assert!(record_len != 0, "record length must be non-zero");
+    Records {
+        reader,
+        buf: vec![0; record_len],
+        done: false,
+    }
+}
+
+/// A fallible lender over the fixed-length records of a reader.
+///
+/// This `struct` is created by the [`records()`] function.
+#[must_use = "lenders are lazy and do nothing unless consumed"]
+pub struct Records<R> {
+    reader: R,
+    buf: Vec<u8>,
+    done: bool,
+}
+
+impl<R> Records<R> {
+    /// Returns the underlying reader.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: fmt::Debug> fmt::Debug for Records<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Records")
+            .field("reader", &self.reader)
+            .field("record_len", &self.buf.len())
+            .field("done", &self.done)
+            .finish()
+    }
+}
+
+impl<'lend, R: Read> FallibleLending<'lend> for Records<R> {
+    type Lend = &'lend mut [u8];
+}
+
+impl<R: Read> FallibleLender for Records<R> {
+    type Error = io::Error;
+    crate::check_covariance_fallible!();
+
+    #[inline]
+    fn next(&mut self) -> Result<Option<FallibleLend<'_, Self>>, Self::Error> {
+        if read_record(&mut self.reader, &mut self.buf, &mut self.done)? {
+            Ok(Some(&mut self.buf))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+impl<R: Read> FusedFallibleLender for Records<R> {}
+
+/// Creates a new fallible lender that reads records of `RECORD_LEN` bytes
+/// from a reader, lending each record as a mutable reference to a reused
+/// array.
+///
+/// This is the const generic equivalent of [`records()`].
+///
+/// # Panics
+///
+/// Panics if `RECORD_LEN` is zero.
+///
+/// # Examples
+/// ```rust
+/// # use lender::prelude::*;
+/// let data = [1_u8, 0, 2, 0];
+/// let mut lender = lender::array_records::<2, _>(&data[..]);
+/// let mut sum = 0;
+/// while let Some(record) = lender.next().unwrap() {
+///     sum += u16::from_le_bytes(*record);
+/// }
+/// assert_eq!(sum, 3);
+/// ```
+#[inline]
+pub fn array_records<const RECORD_LEN: usize, R: Read>(reader: R) -> ArrayRecords<R, RECORD_LEN> {
+    assert!(RECORD_LEN != 0, "record length must be non-zero");
+    ArrayRecords {
+        reader,
+        buf: [0; RECORD_LEN],
+        done: false,
+    }
+}
+
+/// A fallible lender over the fixed-length records of a reader as arrays.
+///
+/// This `struct` is created by the [`array_records()`] function.
+#[must_use = "lenders are lazy and do nothing unless consumed"]
+pub struct ArrayRecords<R, const RECORD_LEN: usize> {
+    reader: R,
+    buf: [u8; RECORD_LEN],
+    done: bool,
+}
+
+impl<R, const RECORD_LEN: usize> ArrayRecords<R, RECORD_LEN> {
+    /// Returns the underlying reader.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: fmt::Debug, const RECORD_LEN: usize> fmt::Debug for ArrayRecords<R, RECORD_LEN> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArrayRecords")
+            .field("reader", &self.reader)
+            .field("done", &self.done)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'lend, R: Read, const RECORD_LEN: usize> FallibleLending<'lend>
+    for ArrayRecords<R, RECORD_LEN>
+{
+    type Lend = &'lend mut [u8; RECORD_LEN];
+}
+
+impl<R: Read, const RECORD_LEN: usize> FallibleLender for ArrayRecords<R, RECORD_LEN> {
+    type Error = io::Error;
+    crate::check_covariance_fallible!();
+
+    #[inline]
+    fn next(&mut self) -> Result<Option<FallibleLend<'_, Self>>, Self::Error> {
+        if read_record(&mut self.reader, &mut self.buf, &mut self.done)? {
+            Ok(Some(&mut self.buf))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+impl<R: Read, const RECORD_LEN: usize> FusedFallibleLender for ArrayRecords<R, RECORD_LEN> {}
+
+/// Creates a new fallible lender that reads fixed-length records from a
+/// reader, filling a buffer of `records_per_read` records at a time.
+///
+/// The lender behaves like [`records()`], but each refill of the buffer
+/// issues a single call to [`Read::read`], and records are then lent
+/// directly from the buffer, so most calls to
+/// [`next`](FallibleLender::next) do not perform any I/O. Records are thus
+/// lent from different positions of the buffer, and the bytes of a record
+/// split between two reads are moved to the start of the buffer.
+///
+/// # Panics
+///
+/// Panics if `record_len` or `records_per_read` is zero, or if the size of
+/// the buffer overflows a `usize`.
+///
+/// # Examples
+/// ```rust
+/// # use lender::prelude::*;
+/// let data: Vec<u8> = (0..10).collect();
+/// let mut lender = lender::records_buffered(&data[..], 2, 4);
+/// let mut firsts = vec![];
+/// while let Some(record) = lender.next().unwrap() {
+///     firsts.push(record[0]);
+/// }
+/// assert_eq!(firsts, [0, 2, 4, 6, 8]);
+/// ```
+#[inline]
+pub fn records_buffered<R: Read>(
+    reader: R,
+    record_len: usize,
+    records_per_read: usize,
+) -> RecordsBuffered<R> {
+    assert!(record_len != 0, "record length must be non-zero");
+    assert!(
+        records_per_read != 0,
+        "number of records per read must be non-zero"
+    );
+    let capacity = record_len
+        .checked_mul(records_per_read)
+        .expect("the buffer size must fit in a usize");
+    RecordsBuffered {
+        reader,
+        buf: vec![0; capacity],
+        record_len,
+        start: 0,
+        end: 0,
+        eof: false,
+        done: false,
+    }
+}
+
+/// A fallible lender over the fixed-length records of a reader, reading
+/// many records at a time.
+///
+/// This `struct` is created by the [`records_buffered()`] function.
+#[must_use = "lenders are lazy and do nothing unless consumed"]
+pub struct RecordsBuffered<R> {
+    reader: R,
+    buf: Vec<u8>,
+    record_len: usize,
+    // The bytes read but not yet lent.
+    start: usize,
+    end: usize,
+    eof: bool,
+    done: bool,
+}
+
+impl<R> RecordsBuffered<R> {
+    /// Returns the underlying reader.
+    ///
+    /// Note that bytes already read into the buffer are lost.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: fmt::Debug> fmt::Debug for RecordsBuffered<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RecordsBuffered")
+            .field("reader", &self.reader)
+            .field("record_len", &self.record_len)
+            .field("buffered", &(self.end - self.start))
+            .field("done", &self.done)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'lend, R: Read> FallibleLending<'lend> for RecordsBuffered<R> {
+    type Lend = &'lend mut [u8];
+}
+
+impl<R: Read> FallibleLender for RecordsBuffered<R> {
+    type Error = io::Error;
+    crate::check_covariance_fallible!();
+
+    #[inline]
+    fn next(&mut self) -> Result<Option<FallibleLend<'_, Self>>, Self::Error> {
+        while !self.done && self.end - self.start < self.record_len {
+            if self.eof {
+                self.done = true;
+                if self.end > self.start {
+                    return Err(PartialRecordError {
+                        len: self.end - self.start,
+                        record_len: self.record_len,
+                    }
+                    .into_io());
+                }
+                break;
+            }
+            self.buf.copy_within(self.start..self.end, 0);
+            self.end -= self.start;
+            self.start = 0;
+            match self.reader.read(&mut self.buf[self.end..]) {
+                Ok(0) => self.eof = true,
+                Ok(n) => self.end += n,
+                Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => {
+                    self.done = true;
+                    return Err(e);
+                }
+            }
+        }
+        if self.done {
+            return Ok(None);
+        }
+        let record = self.start..self.start + self.record_len;
+        self.start = record.end;
+        Ok(Some(&mut self.buf[record]))
+    }
+}
+
+impl<R: Read> FusedFallibleLender for RecordsBuffered<R> {}
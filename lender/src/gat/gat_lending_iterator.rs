@@ -0,0 +1,100 @@
+//! Conversions between lenders and the lending iterators of the
+//! [`gat-lending-iterator`](https://crates.io/crates/gat-lending-iterator)
+//! crate.
+//!
+//! This module, available only if the `gat-lending-iterator` feature is
+//! enabled, provides
+//!
+//! - an implementation of [`LendingIterator`] for [`LendingIter`], so that
+//!   [`Lender::into_lending_iter`] views any [`Lender`] as a
+//!   [`LendingIterator`];
+//!
+//! - a [`FromLendingIter`] adapter, created by [`from_lending_iter`], viewing
+//!   any [`LendingIterator`] as a [`Lender`].
+//!
+//! See the [parent module](super) for a discussion of covariance and of the
+//! limitations of GAT-based lending iterators.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use lender::prelude::*;
+//! use gat_lending_iterator::ToLendingIterator;
+//!
+//! // The windows of an iterator, kept in a buffer by gat-lending-iterator
+//! let iter = (0..5).windows_mut(2);
+//! // SAFETY: &'a mut [i32] is covariant in 'a
+//! let lender = unsafe { lender::gat::gat_lending_iterator::from_lending_iter(iter) };
+//! let mut sums = vec![];
+//! lender.for_each(|w| sums.push(w[0] + w[1]));
+//! assert_eq!(sums, [1, 3, 5, 7]);
+//! ```
+
+use ::gat_lending_iterator::LendingIterator;
+
+use crate::{Lend, Lender, Lending, gat::LendingIter};
+
+impl<L: Lender> LendingIterator for LendingIter<L> {
+    type Item<'a>
+        = Lend<'a, L>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        self.lender.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.lender.size_hint()
+    }
+}
+
+/// Creates a lender from a
+/// [`gat_lending_iterator::LendingIterator`].
+///
+/// # Safety
+///
+/// The item type `I::Item<'a>` must be covariant in `'a`, as for
+/// [`gat::from_lending_iter`](super::from_lending_iter).
+#[inline]
+pub const unsafe fn from_lending_iter<I: LendingIterator>(iter: I) -> FromLendingIter<I> {
+    FromLendingIter { iter }
+}
+
+/// A lender that lends the items of a
+/// [`gat_lending_iterator::LendingIterator`].
+///
+/// This `struct` is created by the [`from_lending_iter()`] function.
+#[derive(Clone, Debug)]
+#[must_use = "lenders are lazy and do nothing unless consumed"]
+pub struct FromLendingIter<I> {
+    iter: I,
+}
+
+impl<I> FromLendingIter<I> {
+    /// Returns the underlying lending iterator.
+    #[inline]
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<'lend, I: LendingIterator> Lending<'lend> for FromLendingIter<I> {
+    type Lend = I::Item<'lend>;
+}
+
+impl<I: LendingIterator> Lender for FromLendingIter<I> {
+    // SAFETY: covariance is guaranteed by the caller of from_lending_iter
+    crate::unsafe_assume_covariance!();
+    #[inline]
+    fn next(&mut self) -> Option<Lend<'_, Self>> {
+        self.iter.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
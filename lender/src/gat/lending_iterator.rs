@@ -0,0 +1,89 @@
+//! Conversions between lenders and the lending iterators of the
+//! [`lending-iterator`](https://crates.io/crates/lending-iterator) crate.
+//!
+//! This module, available only if the `lending-iterator` feature is enabled,
+//! provides
+//!
+//! - an implementation of [`LendingIterator`] for [`LendingIter`], so that
+//!   [`Lender::into_lending_iter`] views any [`Lender`] as a
+//!   [`LendingIterator`];
+//!
+//! - a [`FromLendingIter`] adapter, created by [`from_lending_iter`], viewing
+//!   any [`LendingIterator`] as a [`Lender`].
+//!
+//! The `lending-iterator` crate emulates generic associated types with
+//! higher-rank trait bounds, so the limitations described in the [parent
+//! module](super) do not apply, but covariance still cannot be checked.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use lender::prelude::*;
+//! let mut data = [0, 1, 0, 0, 0];
+//! let iter = lending_iterator::windows_mut::<_, 3>(&mut data);
+//! // SAFETY: &'a mut [i32; 3] is covariant in 'a
+//! let lender = unsafe { lender::gat::lending_iterator::from_lending_iter(iter) };
+//! lender.for_each(|w| w[2] = w[0] + w[1]);
+//! assert_eq!(data, [0, 1, 1, 2, 3]);
+//! ```
+
+use ::lending_iterator::prelude::{Item, LendingIterator, gat};
+
+use crate::{Lend, Lender, Lending, gat::LendingIter};
+
+// The attribute needs the full path of the trait to find its hidden supertrait
+#[gat]
+impl<L: Lender> ::lending_iterator::prelude::LendingIterator for LendingIter<L> {
+    type Item<'next>
+    where
+        Self: 'next,
+    = Lend<'next, L>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Lend<'_, L>> {
+        self.lender.next()
+    }
+}
+
+/// Creates a lender from a
+/// [`lending_iterator::LendingIterator`].
+///
+/// # Safety
+///
+/// The item type `Item<'a, I>` must be covariant in `'a`, as for
+/// [`gat::from_lending_iter`](super::from_lending_iter).
+#[inline]
+pub const unsafe fn from_lending_iter<I: LendingIterator>(iter: I) -> FromLendingIter<I> {
+    FromLendingIter { iter }
+}
+
+/// A lender that lends the items of a
+/// [`lending_iterator::LendingIterator`].
+///
+/// This `struct` is created by the [`from_lending_iter()`] function.
+#[derive(Clone, Debug)]
+#[must_use = "lenders are lazy and do nothing unless consumed"]
+pub struct FromLendingIter<I> {
+    iter: I,
+}
+
+impl<I> FromLendingIter<I> {
+    /// Returns the underlying lending iterator.
+    #[inline]
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<'lend, I: LendingIterator> Lending<'lend> for FromLendingIter<I> {
+    type Lend = Item<'lend, I>;
+}
+
+impl<I: LendingIterator> Lender for FromLendingIter<I> {
+    // SAFETY: covariance is guaranteed by the caller of from_lending_iter
+    crate::unsafe_assume_covariance!();
+    #[inline]
+    fn next(&mut self) -> Option<Lend<'_, Self>> {
+        self.iter.next()
+    }
+}
@@ -0,0 +1,221 @@
+//! # Interoperability with GAT-Based Lending Iterators
+//!
+//! Several crates in the ecosystem (e.g.,
+//! [`gat-lending-iterator`](https://crates.io/crates/gat-lending-iterator) and
+//! [`lending-iterator`](https://crates.io/crates/lending-iterator)) define
+//! lending iterators using a generic associated type `type Item<'a> where
+//! Self: 'a`, rather than the higher-rank trait bounds used by [`Lender`].
+//!
+//! This module, available only if the `gat` feature is enabled, provides
+//!
+//! - a [`LendingIterator`] trait with the same shape as those crates;
+//!
+//! - a [`LendingIter`] adapter, created by [`Lender::into_lending_iter`],
+//!   viewing any [`Lender`] as a [`LendingIterator`];
+//!
+//! - a [`FromLendingIter`] adapter, created by [`from_lending_iter`], viewing
+//!   any [`LendingIterator`] as a [`Lender`];
+//!
+//! - if the `gat-lending-iterator` or `lending-iterator` features are
+//!   enabled, the submodules `gat_lending_iterator` and
+//!   `lending_iterator`, which provide the same conversions for the
+//!   lending-iterator traits of the corresponding crates; in particular,
+//!   [`LendingIter`] implements those traits, too.
+//!
+//! Due to the orphan rule, the [`LendingIterator`] trait of this module
+//! cannot be implemented for the types of other crates: to use it with the
+//! lending iterators of a crate without a submodule, wrap them in a newtype.
+//!
+//! # Covariance
+//!
+//! A [`Lender`] must lend a type that is covariant in its lifetime, but a
+//! generic associated type carries no such guarantee, and covariance cannot be
+//! checked for a generic type. For this reason, [`from_lending_iter`] is
+//! `unsafe`. For the common case of a lender that has been turned into a
+//! [`LendingIterator`], [`LendingIter::into_inner`] recovers the original
+//! lender without any `unsafe` code.
+//!
+//! # Limitations
+//!
+//! Due to the `where Self: 'a` clause on the generic associated type, the
+//! compiler cannot resolve the item type of a [`LendingIterator`] under a
+//! higher-rank bound (e.g., in a closure taking a lend, or in a bound such as
+//! `for<'a> LendingIterator<Item<'a> = &'a mut T>`) unless the iterator is
+//! `'static`. This is one of the reasons behind the design of [`Lender`], and
+//! applies also to the lenders returned by [`from_lending_iter`]: if the
+//! underlying iterator borrows data, use a `while let` loop instead of
+//! adapters taking closures.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use lender::prelude::*;
+//! use lender::gat::LendingIterator;
+//!
+//! let mut data = [1, 2, 3, 4];
+//! let mut iter = lender::windows_mut(&mut data, 2).into_lending_iter();
+//! while let Some(w) = LendingIterator::next(&mut iter) {
+//!     w[1] += w[0];
+//! }
+//! assert_eq!(data, [1, 3, 6, 10]);
+//! ```
+
+use crate::{Lend, Lender, Lending};
+
+#[cfg(feature = "gat-lending-iterator")]
+pub mod gat_lending_iterator;
+#[cfg(feature = "lending-iterator")]
+pub mod lending_iterator;
+
+/// A lending iterator based on a generic associated type.
+///
+/// This trait has the same shape as the lending-iterator traits of GAT-based
+/// crates. See the [module documentation](self) for the available
+/// conversions.
+pub trait LendingIterator {
+    /// The type of the elements being lent.
+    type Item<'a>
+    where
+        Self: 'a;
+
+    /// Advances the iterator and returns the next element.
+    fn next(&mut self) -> Option<Self::Item<'_>>;
+
+    /// Returns the bounds on the remaining length of the iterator.
+    ///
+    /// See [`Iterator::size_hint`].
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, None)
+    }
+}
+
+impl<I: LendingIterator + ?Sized> LendingIterator for &mut I {
+    type Item<'a>
+        = I::Item<'a>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        (**self).next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (**self).size_hint()
+    }
+}
+
+/// A [`LendingIterator`] view of a [`Lender`].
+///
+/// This `struct` is created by the
+/// [`into_lending_iter()`](crate::Lender::into_lending_iter) method on
+/// [`Lender`], which is available only if the `gat` feature is enabled.
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct LendingIter<L> {
+    lender: L,
+}
+
+impl<L> LendingIter<L> {
+    #[inline]
+    pub(crate) fn new(lender: L) -> Self {
+        Self { lender }
+    }
+
+    /// Returns the inner lender.
+    #[inline]
+    pub fn into_inner(self) -> L {
+        self.lender
+    }
+}
+
+impl<L: Lender> LendingIterator for LendingIter<L> {
+    type Item<'a>
+        = Lend<'a, L>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        self.lender.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.lender.size_hint()
+    }
+}
+
+/// Creates a lender from a [`LendingIterator`].
+///
+/// # Safety
+///
+/// The item type `I::Item<'a>` must be covariant in `'a`, as required of the
+/// [`Lend`](Lending::Lend) type of every [`Lender`]. This is the case for
+/// references, slices, and any type built from them without interior
+/// mutability or function arguments; it is not the case, for example, for
+/// `&'a Cell<&'a T>`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use lender::prelude::*;
+/// use lender::gat::LendingIterator;
+///
+/// struct Countdown(u32);
+///
+/// impl LendingIterator for Countdown {
+///     type Item<'a> = &'a mut u32 where Self: 'a;
+///
+///     fn next(&mut self) -> Option<&mut u32> {
+///         self.0 = self.0.checked_sub(1)?;
+///         Some(&mut self.0)
+///     }
+/// }
+///
+/// // SAFETY: &'a mut u32 is covariant in 'a
+/// let mut lender = unsafe { lender::gat::from_lending_iter(Countdown(3)) };
+/// assert_eq!(lender.next(), Some(&mut 2));
+/// assert_eq!(lender.count(), 2);
+/// ```
+#[inline]
+pub const unsafe fn from_lending_iter<I: LendingIterator>(iter: I) -> FromLendingIter<I> {
+    FromLendingIter { iter }
+}
+
+/// A lender that lends the items of a [`LendingIterator`].
+///
+/// This `struct` is created by the [`from_lending_iter()`] function.
+#[derive(Clone, Debug)]
+#[must_use = "lenders are lazy and do nothing unless consumed"]
+pub struct FromLendingIter<I> {
+    iter: I,
+}
+
+impl<I> FromLendingIter<I> {
+    /// Returns the underlying lending iterator.
+    #[inline]
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<'lend, I: LendingIterator> Lending<'lend> for FromLendingIter<I> {
+    type Lend = I::Item<'lend>;
+}
+
+impl<I: LendingIterator> Lender for FromLendingIter<I> {
+    // SAFETY: covariance is guaranteed by the caller of from_lending_iter
+    crate::unsafe_assume_covariance!();
+    #[inline]
+    fn next(&mut self) -> Option<Lend<'_, Self>> {
+        self.iter.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
@@ -2,6 +2,8 @@
 #![no_std]
 
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 #[cfg(doctest)]
 #[allow(non_camel_case_types)]
@@ -42,12 +44,21 @@ mod fallible_adapters;
 pub use fallible_adapters::*;
 mod traits;
 pub use traits::*;
+pub mod boxed;
 pub mod higher_order;
 pub use higher_order::Covar;
 mod sources;
 pub use sources::*;
 mod fallible_sources;
 pub use fallible_sources::*;
+#[cfg(feature = "gat")]
+pub mod gat;
+#[cfg(feature = "rayon")]
+mod par;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "testing")]
+pub mod testing;
 pub use stable_try_trait_v2 as try_trait_v2;
 
 pub mod prelude {
@@ -60,9 +71,11 @@ pub mod prelude {
         ExtendLender, FallibleIteratorExt, FallibleIteratorRefExt, FallibleLend, FallibleLender,
         FallibleLending, FromFallibleLender, FromLender, FusedFallibleLender, FusedLender,
         IntoFallibleIteratorExt, IntoFallibleLender, IntoIteratorExt, IntoLender, IteratorExt,
-        IteratorRefExt, Lend, Lender, Lending, ProductFallibleLender, ProductLender,
+        IteratorRefExt, Lend, Lender, Lending, PairsMutExt, ProductFallibleLender, ProductLender,
         SumFallibleLender, SumLender, WindowsMutExt, check_covariance, check_covariance_fallible,
         covar, covar_mut, covar_once, covariant_fallible_lend, covariant_lend, fallible_lend, lend,
         unsafe_assume_covariance, unsafe_assume_covariance_fallible,
     };
+    #[cfg(feature = "streaming-iterator")]
+    pub use crate::{FallibleStreamingIteratorExt, StreamingIteratorExt, StreamingIteratorMutExt};
 }
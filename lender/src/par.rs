@@ -0,0 +1,128 @@
+//! Parallel consumption of owned batches using [`rayon`].
+//!
+//! The lender is consumed on the calling thread, which converts lends into
+//! owned batches and spawns a [`rayon`] task for each batch. To bound memory
+//! usage, the number of batches in flight is limited to twice the number of
+//! threads of the current pool: when the limit is reached, the calling thread
+//! helps executing tasks if it belongs to the pool, and waits otherwise.
+
+use alloc::{borrow::ToOwned, vec::Vec};
+use std::sync::{Condvar, Mutex, MutexGuard, PoisonError};
+
+use crate::{Lend, Lender};
+
+/// A counter of the batches in flight.
+struct InFlight {
+    count: Mutex<usize>,
+    released: Condvar,
+    max: usize,
+}
+
+/// Decrements the counter of batches in flight on drop, so that the calling
+/// thread is not blocked forever if a task panics.
+struct Release<'a>(&'a InFlight);
+
+impl Drop for Release<'_> {
+    fn drop(&mut self) {
+        *self.0.lock() -= 1;
+        self.0.released.notify_one();
+    }
+}
+
+impl InFlight {
+    fn new() -> Self {
+        Self {
+            count: Mutex::new(0),
+            released: Condvar::new(),
+            max: 2 * rayon::current_num_threads(),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, usize> {
+        self.count.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Waits until a batch can be spawned, and accounts for it.
+    fn acquire(&self) -> Release<'_> {
+        let mut count = self.lock();
+        while *count >= self.max {
+            if rayon::current_thread_index().is_some() {
+                // We are a worker of the pool: waiting might deadlock, as the
+                // tasks we are waiting for might be queued on this thread.
+                drop(count);
+                if rayon::yield_now() != Some(rayon::Yield::Executed) {
+                    std::thread::yield_now();
+                }
+                count = self.lock();
+            } else {
+                count = self
+                    .released
+                    .wait(count)
+                    .unwrap_or_else(PoisonError::into_inner);
+            }
+        }
+        *count += 1;
+        Release(self)
+    }
+}
+
+/// Converts the lends of `lender` into owned batches of `batch_size`
+/// elements, and calls `f` in parallel on each batch together with its index.
+pub(crate) fn for_each_batch<L, T, F>(mut lender: L, batch_size: usize, f: F)
+where
+    L: Lender,
+    for<'all> Lend<'all, L>: ToOwned<Owned = T>,
+    T: Send,
+    F: Fn(usize, Vec<T>) + Sync,
+{
+    assert!(batch_size != 0, "batch size must be non-zero");
+    let in_flight = InFlight::new();
+    let (in_flight, f) = (&in_flight, &f);
+    rayon::in_place_scope(|scope| {
+        for index in 0.. {
+            let mut batch = Vec::with_capacity(batch_size);
+            while batch.len() < batch_size {
+                match lender.next() {
+                    Some(x) => batch.push(x.to_owned()),
+                    None => break,
+                }
+            }
+            if batch.is_empty() {
+                break;
+            }
+            let last = batch.len() < batch_size;
+            let release = in_flight.acquire();
+            scope.spawn(move |_| {
+                let _release = release;
+                f(index, batch);
+            });
+            if last {
+                break;
+            }
+        }
+    });
+}
+
+/// Like [`for_each_batch`], but maps elements using `f` and collects the
+/// results in the order of the lends.
+pub(crate) fn map_collect<L, T, R, B, F>(lender: L, batch_size: usize, f: F) -> B
+where
+    L: Lender,
+    for<'all> Lend<'all, L>: ToOwned<Owned = T>,
+    T: Send,
+    R: Send,
+    B: FromIterator<R>,
+    F: Fn(T) -> R + Sync,
+{
+    let results = Mutex::new(Vec::new());
+    for_each_batch(lender, batch_size, |index, batch| {
+        let batch = batch.into_iter().map(&f).collect::<Vec<_>>();
+        results
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push((index, batch));
+    });
+    let mut results = results.into_inner().unwrap_or_else(PoisonError::into_inner);
+    results.sort_unstable_by_key(|&(index, _)| index);
+    results.into_iter().flat_map(|(_, batch)| batch).collect()
+}
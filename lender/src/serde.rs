@@ -0,0 +1,269 @@
+//! # Serialization and Deserialization with Serde
+//!
+//! This module, available only if the `serde` feature is enabled, provides
+//!
+//! - a [`SerializeLender`] wrapper, which serializes the lends of a [`Lender`]
+//!   as a sequence, without collecting them;
+//!
+//! - a [`SeqElements`] fallible lender, created by [`seq_elements`], which
+//!   deserializes the elements of a sequence one at a time into a reused
+//!   value.
+//!
+//! Both work with any [Serde](https://serde.rs/) data format, and make it
+//! possible to write and read sequences of millions of records while keeping
+//! in memory a single record at a time.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use lender::prelude::*;
+//! use lender::serde::SerializeLender;
+//!
+//! let mut data = [1, 2, 3, 4];
+//! let windows = lender::windows_mut(&mut data, 2);
+//! let json = serde_json::to_string(&SerializeLender::new(windows)).unwrap();
+//! assert_eq!(json, "[[1,2],[2,3],[3,4]]");
+//! ```
+
+use core::{cell::Cell, fmt, marker::PhantomData};
+
+use ::serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{DeserializeSeed, SeqAccess},
+    ser::{Error as _, SerializeSeq},
+};
+
+use crate::{FallibleLend, FallibleLender, FallibleLending, FusedFallibleLender, Lend, Lender};
+
+/// A wrapper serializing the lends of a [`Lender`] as a sequence.
+///
+/// Since [`Serialize::serialize`] takes `&self`, the lender is kept in a
+/// [`Cell`] and taken out when serialization starts: thus, a
+/// [`SerializeLender`] can be serialized only once, and further attempts will
+/// return an error.
+///
+/// The length of the sequence is passed to the serializer if the
+/// [`size_hint`](Lender::size_hint) of the lender is exact, as some formats
+/// require it.
+///
+/// # Examples
+///
+/// ```rust
+/// # use lender::prelude::*;
+/// use lender::serde::SerializeLender;
+///
+/// let data = ["a", "b"];
+/// let lender = SerializeLender::new(lender::from_iter_ref(data.into_iter()));
+/// assert_eq!(serde_json::to_string(&lender).unwrap(), r#"["a","b"]"#);
+/// // The lender has been consumed
+/// assert!(serde_json::to_string(&lender).is_err());
+/// ```
+pub struct SerializeLender<L> {
+    lender: Cell<Option<L>>,
+}
+
+impl<L> SerializeLender<L> {
+    /// Creates a new wrapper serializing the lends of `lender`.
+    #[inline]
+    pub const fn new(lender: L) -> Self {
+        Self {
+            lender: Cell::new(Some(lender)),
+        }
+    }
+
+    /// Returns the inner lender, or `None` if it has already been serialized.
+    #[inline]
+    pub fn into_inner(self) -> Option<L> {
+        self.lender.into_inner()
+    }
+}
+
+impl<L> From<L> for SerializeLender<L> {
+    #[inline]
+    fn from(lender: L) -> Self {
+        Self::new(lender)
+    }
+}
+
+impl<L> fmt::Debug for SerializeLender<L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SerializeLender").finish_non_exhaustive()
+    }
+}
+
+impl<L> Serialize for SerializeLender<L>
+where
+    L: Lender,
+    for<'all> Lend<'all, L>: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let Some(mut lender) = self.lender.take() else {
+            return Err(S::Error::custom(
+                "a SerializeLender can be serialized only once",
+            ));
+        };
+        let len = match lender.size_hint() {
+            (lower, Some(upper)) if lower == upper => Some(lower),
+            _ => None,
+        };
+        let mut seq = serializer.serialize_seq(len)?;
+        while let Some(x) = lender.next() {
+            seq.serialize_element(&x)?;
+        }
+        seq.end()
+    }
+}
+
+/// Creates a fallible lender that deserializes the elements of a sequence
+/// from a [`SeqAccess`], lending a mutable reference to a reused value.
+///
+/// Each element is deserialized using [`Deserialize::deserialize_in_place`],
+/// so types such as [`Vec`](alloc::vec::Vec) and
+/// [`String`](alloc::string::String) reuse their allocations. The initial
+/// value is [`T::default()`](Default::default).
+///
+/// A [`SeqAccess`] is handed out by a [`Deserializer`] to
+/// [`Visitor::visit_seq`](::serde::de::Visitor::visit_seq); thus, to process a
+/// top-level sequence, call
+/// [`Deserializer::deserialize_seq`] with a visitor creating this lender.
+/// With a streaming deserializer, such as the one returned by
+/// `serde_json::Deserializer::from_reader`, the sequence is never
+/// materialized.
+///
+/// # Examples
+///
+/// ```rust
+/// # use lender::prelude::*;
+/// use serde::de::{Deserializer, SeqAccess, Visitor};
+///
+/// // Sums the lengths of the strings in a sequence
+/// struct TotalLen;
+///
+/// impl<'de> Visitor<'de> for TotalLen {
+///     type Value = usize;
+///
+///     fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+///         f.write_str("a sequence of strings")
+///     }
+///
+///     fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<usize, A::Error> {
+///         let mut lender = lender::serde::seq_elements::<String, _>(seq);
+///         let mut total = 0;
+///         while let Some(s) = lender.next()? {
+///             total += s.len();
+///         }
+///         Ok(total)
+///     }
+/// }
+///
+/// let json = r#"["a", "bc", "def"]"#;
+/// let mut de = serde_json::Deserializer::from_reader(json.as_bytes());
+/// assert_eq!(de.deserialize_seq(TotalLen).unwrap(), 6);
+/// ```
+#[inline]
+pub fn seq_elements<'de, T, A>(seq: A) -> SeqElements<'de, A, T>
+where
+    T: Deserialize<'de> + Default,
+    A: SeqAccess<'de>,
+{
+    SeqElements {
+        seq,
+        value: T::default(),
+        done: false,
+        _marker: PhantomData,
+    }
+}
+
+/// A fallible lender that deserializes the elements of a sequence into a
+/// reused value.
+///
+/// This `struct` is created by the [`seq_elements()`] function.
+#[must_use = "lenders are lazy and do nothing unless consumed"]
+pub struct SeqElements<'de, A, T> {
+    seq: A,
+    value: T,
+    done: bool,
+    _marker: PhantomData<fn(&'de ())>,
+}
+
+impl<A, T> SeqElements<'_, A, T> {
+    /// Returns the underlying sequence access.
+    #[inline]
+    pub fn into_inner(self) -> A {
+        self.seq
+    }
+}
+
+impl<A, T: fmt::Debug> fmt::Debug for SeqElements<'_, A, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SeqElements")
+            .field("value", &self.value)
+            .field("done", &self.done)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A [`DeserializeSeed`] deserializing in place.
+struct InPlace<'a, T>(&'a mut T);
+
+impl<'de, T: Deserialize<'de>> DeserializeSeed<'de> for InPlace<'_, T> {
+    type Value = ();
+
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        T::deserialize_in_place(deserializer, self.0)
+    }
+}
+
+impl<'lend, 'de, A, T> FallibleLending<'lend> for SeqElements<'de, A, T>
+where
+    A: SeqAccess<'de>,
+    T: Deserialize<'de>,
+{
+    type Lend = &'lend mut T;
+}
+
+impl<'de, A, T> FallibleLender for SeqElements<'de, A, T>
+where
+    A: SeqAccess<'de>,
+    T: Deserialize<'de>,
+{
+    type Error = A::Error;
+    crate::check_covariance_fallible!();
+
+    #[inline]
+    fn next(&mut self) -> Result<Option<FallibleLend<'_, Self>>, Self::Error> {
+        if self.done {
+            return Ok(None);
+        }
+        match self.seq.next_element_seed(InPlace(&mut self.value)) {
+            Ok(Some(())) => Ok(Some(&mut self.value)),
+            Ok(None) => {
+                self.done = true;
+                Ok(None)
+            }
+            Err(err) => {
+                self.done = true;
+                Err(err)
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+        match self.seq.size_hint() {
+            Some(len) => (len, Some(len)),
+            None => (0, None),
+        }
+    }
+}
+
+impl<'de, A, T> FusedFallibleLender for SeqElements<'de, A, T>
+where
+    A: SeqAccess<'de>,
+    T: Deserialize<'de>,
+{
+}
@@ -0,0 +1,265 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::{DoubleEndedLender, ExactSizeLender, FusedLender, Lend, Lender, Lending};
+
+/// Creates a new lender that returns all index tuples of a grid with the
+/// given dimensions, in lexicographic order, like an odometer.
+///
+/// The last index varies fastest. Each tuple is lent as a slice of an
+/// internal buffer that is updated in place, so no allocation happens after
+/// construction. There are no tuples if some dimension is zero, and a single
+/// empty tuple if there are no dimensions.
+///
+/// The lender is an [`ExactSizeLender`] as long as the number of remaining
+/// tuples fits in a `usize`: otherwise, [`size_hint()`](Lender::size_hint)
+/// returns `(usize::MAX, None)` and [`len()`](ExactSizeLender::len) panics.
+///
+/// # Examples
+/// ```rust
+/// # use lender::prelude::*;
+/// let mut lender = lender::product_indices(&[2, 3]);
+/// assert_eq!(lender.len(), 6);
+/// assert_eq!(lender.next(), Some(&[0, 0][..]));
+/// assert_eq!(lender.next(), Some(&[0, 1][..]));
+/// assert_eq!(lender.next_back(), Some(&[1, 2][..]));
+/// assert_eq!(lender.next(), Some(&[0, 2][..]));
+/// assert_eq!(lender.next(), Some(&[1, 0][..]));
+/// assert_eq!(lender.next(), Some(&[1, 1][..]));
+/// assert_eq!(lender.next(), None);
+/// ```
+#[inline]
+pub fn product_indices(dims: &[usize]) -> ProductIndices {
+    let remaining = if dims.contains(&0) {
+        Some(0)
+    } else {
+        dims.iter().try_fold(1_usize, |acc, &d| acc.checked_mul(d))
+    };
+    ProductIndices {
+        dims: dims.to_vec(),
+        front: alloc::vec![0; dims.len()],
+        back: dims.iter().map(|d| d.saturating_sub(1)).collect(),
+        front_started: false,
+        back_started: false,
+        remaining,
+    }
+}
+
+/// A lender over the index tuples of a grid.
+///
+/// This `struct` is created by the [`product_indices()`] function.
+#[derive(Clone, Debug)]
+#[must_use = "lenders are lazy and do nothing unless consumed"]
+pub struct ProductIndices {
+    dims: Vec<usize>,
+    // The tuple most recently returned from the front.
+    front: Vec<usize>,
+    // The tuple most recently returned from the back.
+    back: Vec<usize>,
+    front_started: bool,
+    back_started: bool,
+    // None if the number of remaining tuples overflows.
+    remaining: Option<usize>,
+}
+
+impl ProductIndices {
+    /// Moves the front tuple forward, returning the first position that
+    /// changed, or None if there are no more tuples.
+    #[inline]
+    fn advance_front(&mut self) -> Option<usize> {
+        if self.remaining == Some(0) {
+            return None;
+        }
+        let first = if self.front_started {
+            // The odometer wraps around only if the number of tuples
+            // overflows, as otherwise a tuple remains
+            let mut i = self.dims.len();
+            loop {
+                if i == 0 {
+                    self.remaining = Some(0);
+                    return None;
+                }
+                i -= 1;
+                self.front[i] += 1;
+                if self.front[i] < self.dims[i] {
+                    break i;
+                }
+                self.front[i] = 0;
+            }
+        } else {
+            self.front_started = true;
+            0
+        };
+        self.remaining = self.remaining.map(|x| x - 1);
+        Some(first)
+    }
+
+    /// Moves the back tuple backward, returning the first position that
+    /// changed, or None if there are no more tuples.
+    #[inline]
+    fn advance_back(&mut self) -> Option<usize> {
+        if self.remaining == Some(0) {
+            return None;
+        }
+        let first = if self.back_started {
+            let mut i = self.dims.len();
+            loop {
+                if i == 0 {
+                    self.remaining = Some(0);
+                    return None;
+                }
+                i -= 1;
+                if self.back[i] > 0 {
+                    self.back[i] -= 1;
+                    break i;
+                }
+                self.back[i] = self.dims[i] - 1;
+            }
+        } else {
+            self.back_started = true;
+            0
+        };
+        self.remaining = self.remaining.map(|x| x - 1);
+        Some(first)
+    }
+}
+
+impl<'lend> Lending<'lend> for ProductIndices {
+    type Lend = &'lend [usize];
+}
+
+impl Lender for ProductIndices {
+    crate::check_covariance!();
+    #[inline]
+    fn next(&mut self) -> Option<Lend<'_, Self>> {
+        self.advance_front()?;
+        Some(&self.front)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.remaining {
+            Some(x) => (x, Some(x)),
+            None => (usize::MAX, None),
+        }
+    }
+}
+
+impl DoubleEndedLender for ProductIndices {
+    #[inline]
+    fn next_back(&mut self) -> Option<Lend<'_, Self>> {
+        self.advance_back()?;
+        Some(&self.back)
+    }
+}
+
+impl ExactSizeLender for ProductIndices {}
+
+impl FusedLender for ProductIndices {}
+
+/// Creates a new lender that returns the n-ary cartesian product of some
+/// slices, in lexicographic order, as slices of references.
+///
+/// The elements of the last slice vary fastest. Each tuple is lent as a slice
+/// of an internal buffer, in which only the references after the first
+/// changed position are updated, so no allocation happens after construction.
+/// See [`product_indices()`] for the corner cases and for the behavior when
+/// the number of tuples does not fit in a `usize`.
+///
+/// # Examples
+/// ```rust
+/// # use lender::prelude::*;
+/// let mut lender = lender::multi_cartesian(&[&["a", "b"][..], &["x", "y"][..]]);
+/// assert_eq!(lender.next(), Some(&[&"a", &"x"][..]));
+/// assert_eq!(lender.next(), Some(&[&"a", &"y"][..]));
+/// assert_eq!(lender.next_back(), Some(&[&"b", &"y"][..]));
+/// assert_eq!(lender.next(), Some(&[&"b", &"x"][..]));
+/// assert_eq!(lender.next(), None);
+/// ```
+#[inline]
+pub fn multi_cartesian<'a, T>(slices: &[&'a [T]]) -> MultiCartesian<'a, T> {
+    let dims: Vec<usize> = slices.iter().map(|s| s.len()).collect();
+    MultiCartesian {
+        indices: product_indices(&dims),
+        slices: slices.to_vec(),
+        front: Vec::with_capacity(slices.len()),
+        back: Vec::with_capacity(slices.len()),
+    }
+}
+
+/// A lender over the n-ary cartesian product of some slices.
+///
+/// This `struct` is created by the [`multi_cartesian()`] function.
+#[must_use = "lenders are lazy and do nothing unless consumed"]
+pub struct MultiCartesian<'a, T> {
+    indices: ProductIndices,
+    slices: Vec<&'a [T]>,
+    front: Vec<&'a T>,
+    back: Vec<&'a T>,
+}
+
+impl<T> Clone for MultiCartesian<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            indices: self.indices.clone(),
+            slices: self.slices.clone(),
+            front: self.front.clone(),
+            back: self.back.clone(),
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for MultiCartesian<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MultiCartesian")
+            .field("indices", &self.indices)
+            .field("slices", &self.slices)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Updates the references of `buf` from position `first` on, so that they
+/// match `indices`.
+#[inline]
+fn update<'a, T>(buf: &mut Vec<&'a T>, slices: &[&'a [T]], indices: &[usize], first: usize) {
+    buf.truncate(first);
+    buf.extend(
+        slices[first..]
+            .iter()
+            .zip(&indices[first..])
+            .map(|(s, &i)| &s[i]),
+    );
+}
+
+impl<'lend, 'a, T> Lending<'lend> for MultiCartesian<'a, T> {
+    type Lend = &'lend [&'a T];
+}
+
+impl<T> Lender for MultiCartesian<'_, T> {
+    crate::check_covariance!();
+    #[inline]
+    fn next(&mut self) -> Option<Lend<'_, Self>> {
+        let first = self.indices.advance_front()?;
+        update(&mut self.front, &self.slices, &self.indices.front, first);
+        Some(&self.front)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indices.size_hint()
+    }
+}
+
+impl<T> DoubleEndedLender for MultiCartesian<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Lend<'_, Self>> {
+        let first = self.indices.advance_back()?;
+        update(&mut self.back, &self.slices, &self.indices.back, first);
+        Some(&self.back)
+    }
+}
+
+impl<T> ExactSizeLender for MultiCartesian<'_, T> {}
+
+impl<T> FusedLender for MultiCartesian<'_, T> {}
@@ -0,0 +1,288 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::{ExactSizeLender, FusedLender, Lend, Lender, Lending};
+
+/// Creates a new lender that returns the `k`-combinations of `0..n` in
+/// lexicographic order, as sorted slices of indices.
+///
+/// Each combination is lent as a slice of the same buffer, which is updated
+/// in place. There are no combinations if `k > n`, and a single empty
+/// combination if `k` is zero.
+///
+/// The lender is an [`ExactSizeLender`] as long as the number of remaining
+/// combinations fits in a `usize`: otherwise,
+/// [`size_hint()`](Lender::size_hint) returns `(usize::MAX, None)` and
+/// [`len()`](ExactSizeLender::len) panics.
+///
+/// # Examples
+/// ```rust
+/// # use lender::prelude::*;
+/// let mut lender = lender::combinations(4, 2);
+/// assert_eq!(lender.len(), 6);
+/// assert_eq!(lender.next(), Some(&[0, 1][..]));
+/// assert_eq!(lender.next(), Some(&[0, 2][..]));
+/// assert_eq!(lender.next(), Some(&[0, 3][..]));
+/// assert_eq!(lender.next(), Some(&[1, 2][..]));
+/// assert_eq!(lender.next(), Some(&[1, 3][..]));
+/// assert_eq!(lender.next(), Some(&[2, 3][..]));
+/// assert_eq!(lender.next(), None);
+/// ```
+#[inline]
+pub fn combinations(n: usize, k: usize) -> Combinations {
+    Combinations {
+        indices: (0..k).collect(),
+        n,
+        started: false,
+        remaining: binomial(n, k),
+    }
+}
+
+/// A lender over the `k`-combinations of `0..n` in lexicographic order.
+///
+/// This `struct` is created by the [`combinations()`] function.
+#[derive(Clone, Debug)]
+#[must_use = "lenders are lazy and do nothing unless consumed"]
+pub struct Combinations {
+    indices: Vec<usize>,
+    n: usize,
+    started: bool,
+    // None if the number of remaining combinations overflows.
+    remaining: Option<usize>,
+}
+
+impl Combinations {
+    /// Moves to the next combination, returning the first position of the
+    /// buffer that changed, or None if there are no more combinations.
+    #[inline]
+    fn advance(&mut self) -> Option<usize> {
+        if !self.started {
+            self.started = true;
+            return (self.remaining != Some(0)).then_some(0);
+        }
+        if self.remaining == Some(0) {
+            return None;
+        }
+        let k = self.indices.len();
+        // The rightmost index that has not reached its maximum value
+        let Some(i) = (0..k).rposition(|i| self.indices[i] < self.n - k + i) else {
+            self.remaining = Some(0);
+            return None;
+        };
+        self.indices[i] += 1;
+        for j in i + 1..k {
+            self.indices[j] = self.indices[j - 1] + 1;
+        }
+        Some(i)
+    }
+}
+
+impl<'lend> Lending<'lend> for Combinations {
+    type Lend = &'lend [usize];
+}
+
+impl Lender for Combinations {
+    crate::check_covariance!();
+    #[inline]
+    fn next(&mut self) -> Option<Lend<'_, Self>> {
+        self.advance()?;
+        self.remaining = self.remaining.map(|x| x - 1);
+        Some(&self.indices)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.remaining {
+            Some(x) => (x, Some(x)),
+            None => (usize::MAX, None),
+        }
+    }
+}
+
+impl ExactSizeLender for Combinations {}
+
+impl FusedLender for Combinations {}
+
+/// Creates a new lender that returns the `k`-combinations of the elements of
+/// a slice in lexicographic order of positions, as slices of references.
+///
+/// Each combination is lent as a slice of the same buffer; only the
+/// references after the first changed position are updated at each step.
+/// See [`combinations()`] for details on the number of combinations.
+///
+/// # Examples
+/// ```rust
+/// # use lender::prelude::*;
+/// let data = ["a", "b", "c"];
+/// let mut lender = lender::combinations_of(&data, 2);
+/// assert_eq!(lender.next(), Some(&[&"a", &"b"][..]));
+/// assert_eq!(lender.next(), Some(&[&"a", &"c"][..]));
+/// assert_eq!(lender.next(), Some(&[&"b", &"c"][..]));
+/// assert_eq!(lender.next(), None);
+/// ```
+#[inline]
+pub fn combinations_of<T>(slice: &[T], k: usize) -> CombinationsOf<'_, T> {
+    CombinationsOf {
+        combinations: combinations(slice.len(), k),
+        slice,
+        buf: Vec::with_capacity(k),
+    }
+}
+
+/// A lender over the `k`-combinations of the elements of a slice.
+///
+/// This `struct` is created by the [`combinations_of()`] function.
+#[must_use = "lenders are lazy and do nothing unless consumed"]
+pub struct CombinationsOf<'a, T> {
+    combinations: Combinations,
+    slice: &'a [T],
+    buf: Vec<&'a T>,
+}
+
+impl<T> Clone for CombinationsOf<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            combinations: self.combinations.clone(),
+            slice: self.slice,
+            buf: self.buf.clone(),
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for CombinationsOf<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CombinationsOf")
+            .field("combinations", &self.combinations)
+            .field("slice", &self.slice)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'lend, 'a, T> Lending<'lend> for CombinationsOf<'a, T> {
+    type Lend = &'lend [&'a T];
+}
+
+impl<T> Lender for CombinationsOf<'_, T> {
+    crate::check_covariance!();
+    #[inline]
+    fn next(&mut self) -> Option<Lend<'_, Self>> {
+        let first = self.combinations.advance()?;
+        self.combinations.remaining = self.combinations.remaining.map(|x| x - 1);
+        self.buf.truncate(first);
+        let slice = self.slice;
+        self.buf.extend(
+            self.combinations.indices[first..]
+                .iter()
+                .map(|&i| &slice[i]),
+        );
+        Some(&self.buf)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.combinations.size_hint()
+    }
+}
+
+impl<T> ExactSizeLender for CombinationsOf<'_, T> {}
+
+impl<T> FusedLender for CombinationsOf<'_, T> {}
+
+/// Creates a new lender that returns all subsets of `0..n` in Gray-code
+/// order, as sorted slices of indices.
+///
+/// Consecutive subsets differ by exactly one element, which is added to or
+/// removed from the same buffer in place. The first subset is the empty one.
+///
+/// The lender is an [`ExactSizeLender`] as long as 2<sup>`n`</sup> fits in a
+/// `usize`: otherwise, [`size_hint()`](Lender::size_hint) returns
+/// `(usize::MAX, None)` and [`len()`](ExactSizeLender::len) panics.
+///
+/// # Examples
+/// ```rust
+/// # use lender::prelude::*;
+/// let mut lender = lender::powerset(2);
+/// assert_eq!(lender.len(), 4);
+/// assert_eq!(lender.next(), Some(&[][..]));
+/// assert_eq!(lender.next(), Some(&[0][..]));
+/// assert_eq!(lender.next(), Some(&[0, 1][..]));
+/// assert_eq!(lender.next(), Some(&[1][..]));
+/// assert_eq!(lender.next(), None);
+/// ```
+#[inline]
+pub fn powerset(n: usize) -> Powerset {
+    Powerset {
+        subset: Vec::new(),
+        count: 0,
+        remaining: u32::try_from(n).ok().and_then(|n| 1_usize.checked_shl(n)),
+    }
+}
+
+/// A lender over the subsets of `0..n` in Gray-code order.
+///
+/// This `struct` is created by the [`powerset()`] function.
+#[derive(Clone, Debug)]
+#[must_use = "lenders are lazy and do nothing unless consumed"]
+pub struct Powerset {
+    subset: Vec<usize>,
+    // The number of subsets returned so far.
+    count: usize,
+    // None if the number of remaining subsets overflows.
+    remaining: Option<usize>,
+}
+
+impl<'lend> Lending<'lend> for Powerset {
+    type Lend = &'lend [usize];
+}
+
+impl Lender for Powerset {
+    crate::check_covariance!();
+    #[inline]
+    fn next(&mut self) -> Option<Lend<'_, Self>> {
+        if self.remaining == Some(0) {
+            return None;
+        }
+        if self.count != 0 {
+            // The Gray codes of count - 1 and count differ in this bit
+            let bit = self.count.trailing_zeros() as usize;
+            match self.subset.binary_search(&bit) {
+                Ok(pos) => {
+                    self.subset.remove(pos);
+                }
+                Err(pos) => self.subset.insert(pos, bit),
+            }
+        }
+        self.count += 1;
+        self.remaining = self.remaining.map(|x| x - 1);
+        Some(&self.subset)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.remaining {
+            Some(x) => (x, Some(x)),
+            None => (usize::MAX, None),
+        }
+    }
+}
+
+impl ExactSizeLender for Powerset {}
+
+impl FusedLender for Powerset {}
+
+/// Returns the binomial coefficient of `n` and `k`, or None if it does not
+/// fit in a `usize`.
+fn binomial(n: usize, k: usize) -> Option<usize> {
+    if k > n {
+        return Some(0);
+    }
+    let k = k.min(n - k);
+    let mut result: usize = 1;
+    for i in 1..=k {
+        // result * (n - k + i) / i is always an integer
+        let next = result as u128 * (n - k + i) as u128 / i as u128;
+        result = usize::try_from(next).ok()?;
+    }
+    Some(result)
+}
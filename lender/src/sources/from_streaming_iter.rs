@@ -0,0 +1,139 @@
+use streaming_iterator::{
+    DoubleEndedStreamingIterator, DoubleEndedStreamingIteratorMut, StreamingIterator,
+    StreamingIteratorMut,
+};
+
+use crate::{DoubleEndedLender, Lend, Lender, Lending};
+
+/// Creates a lender from a [`StreamingIterator`], lending references to its
+/// items.
+///
+/// This function is available only if the `streaming-iterator` feature is
+/// enabled.
+///
+/// Note that the
+/// [`StreamingIteratorExt`](crate::StreamingIteratorExt) trait
+/// provides a convenient entry point for this function as a method on
+/// streaming iterators.
+///
+/// # Examples
+/// ```rust
+/// # use lender::prelude::*;
+/// let mut lender = lender::from_streaming_iter(streaming_iterator::convert([1, 2]));
+/// assert_eq!(lender.next(), Some(&1));
+/// assert_eq!(lender.next(), Some(&2));
+/// assert_eq!(lender.next(), None);
+/// ```
+#[inline]
+pub const fn from_streaming_iter<I: StreamingIterator>(iter: I) -> FromStreamingIter<I> {
+    FromStreamingIter { iter }
+}
+
+/// A lender that lends references to the items of a [`StreamingIterator`].
+///
+/// This `struct` is created by the [`from_streaming_iter()`] function.
+#[derive(Clone, Debug)]
+#[must_use = "lenders are lazy and do nothing unless consumed"]
+pub struct FromStreamingIter<I> {
+    iter: I,
+}
+
+impl<I> FromStreamingIter<I> {
+    /// Returns the underlying streaming iterator.
+    #[inline]
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<'lend, I: StreamingIterator> Lending<'lend> for FromStreamingIter<I> {
+    type Lend = &'lend I::Item;
+}
+
+impl<I: StreamingIterator> Lender for FromStreamingIter<I> {
+    crate::check_covariance!();
+    #[inline]
+    fn next(&mut self) -> Option<Lend<'_, Self>> {
+        self.iter.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I: DoubleEndedStreamingIterator> DoubleEndedLender for FromStreamingIter<I> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Lend<'_, Self>> {
+        self.iter.next_back()
+    }
+}
+
+/// Creates a lender from a [`StreamingIteratorMut`], lending mutable
+/// references to its items.
+///
+/// This function is available only if the `streaming-iterator` feature is
+/// enabled.
+///
+/// Note that the
+/// [`StreamingIteratorMutExt`](crate::StreamingIteratorMutExt) trait
+/// provides a convenient entry point for this function as a method on
+/// streaming iterators.
+///
+/// # Examples
+/// ```rust
+/// # use lender::prelude::*;
+/// let mut v = [1, 2];
+/// let mut lender = lender::from_streaming_iter_mut(streaming_iterator::convert_mut(&mut v));
+/// while let Some(x) = lender.next() {
+///     *x *= 10;
+/// }
+/// assert_eq!(v, [10, 20]);
+/// ```
+#[inline]
+pub const fn from_streaming_iter_mut<I: StreamingIteratorMut>(iter: I) -> FromStreamingIterMut<I> {
+    FromStreamingIterMut { iter }
+}
+
+/// A lender that lends mutable references to the items of a
+/// [`StreamingIteratorMut`].
+///
+/// This `struct` is created by the [`from_streaming_iter_mut()`] function.
+#[derive(Clone, Debug)]
+#[must_use = "lenders are lazy and do nothing unless consumed"]
+pub struct FromStreamingIterMut<I> {
+    iter: I,
+}
+
+impl<I> FromStreamingIterMut<I> {
+    /// Returns the underlying streaming iterator.
+    #[inline]
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<'lend, I: StreamingIteratorMut> Lending<'lend> for FromStreamingIterMut<I> {
+    type Lend = &'lend mut I::Item;
+}
+
+impl<I: StreamingIteratorMut> Lender for FromStreamingIterMut<I> {
+    crate::check_covariance!();
+    #[inline]
+    fn next(&mut self) -> Option<Lend<'_, Self>> {
+        self.iter.next_mut()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I: DoubleEndedStreamingIteratorMut> DoubleEndedLender for FromStreamingIterMut<I> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Lend<'_, Self>> {
+        self.iter.next_back_mut()
+    }
+}
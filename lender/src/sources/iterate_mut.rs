@@ -0,0 +1,223 @@
+use core::fmt;
+
+use crate::{FusedLender, Lend, Lender, Lending};
+
+/// Creates a lender that lends a mutable reference to a state, updating it
+/// in place with a step function `F: FnMut(&mut St) -> bool` between lends.
+///
+/// The first lend is the initial state; before each subsequent lend, the
+/// step function is applied to the state, and the lender ends as soon as it
+/// returns false. Since the state is never cloned, this is suitable for
+/// simulations whose state is a large buffer. Changes made through a lend
+/// are seen by the next step.
+///
+/// # Examples
+/// ```rust
+/// # use lender::prelude::*;
+/// // Rows of Pascal's triangle, computed in place
+/// let mut lender = lender::iterate_mut(vec![1_u64], |row: &mut Vec<u64>| {
+///     row.push(1);
+///     for i in (1..row.len() - 1).rev() {
+///         row[i] += row[i - 1];
+///     }
+///     row.len() <= 4
+/// });
+/// assert_eq!(lender.next(), Some(&mut vec![1]));
+/// assert_eq!(lender.next(), Some(&mut vec![1, 1]));
+/// assert_eq!(lender.next(), Some(&mut vec![1, 2, 1]));
+/// assert_eq!(lender.next(), Some(&mut vec![1, 3, 3, 1]));
+/// assert_eq!(lender.next(), None);
+/// ```
+#[inline]
+pub const fn iterate_mut<St, F>(init: St, f: F) -> IterateMut<St, F>
+where
+    F: FnMut(&mut St) -> bool,
+{
+    IterateMut {
+        state: init,
+        f,
+        started: false,
+        done: false,
+    }
+}
+
+/// A lender that lends a mutable reference to a state updated in place.
+///
+/// This `struct` is created by the [`iterate_mut()`] function.
+#[derive(Clone)]
+#[must_use = "lenders are lazy and do nothing unless consumed"]
+pub struct IterateMut<St, F> {
+    state: St,
+    f: F,
+    started: bool,
+    done: bool,
+}
+
+impl<St, F> IterateMut<St, F> {
+    /// Returns the current state.
+    #[inline]
+    pub fn into_inner(self) -> St {
+        self.state
+    }
+}
+
+impl<St: fmt::Debug, F> fmt::Debug for IterateMut<St, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IterateMut")
+            .field("state", &self.state)
+            .field("started", &self.started)
+            .field("done", &self.done)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'lend, St, F> Lending<'lend> for IterateMut<St, F>
+where
+    F: FnMut(&mut St) -> bool,
+{
+    type Lend = &'lend mut St;
+}
+
+impl<St, F> Lender for IterateMut<St, F>
+where
+    F: FnMut(&mut St) -> bool,
+{
+    crate::check_covariance!();
+    #[inline]
+    fn next(&mut self) -> Option<Lend<'_, Self>> {
+        if self.done {
+            return None;
+        }
+        if self.started {
+            if !(self.f)(&mut self.state) {
+                self.done = true;
+                return None;
+            }
+        } else {
+            self.started = true;
+        }
+        Some(&mut self.state)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            (usize::from(!self.started), None)
+        }
+    }
+}
+
+impl<St, F> FusedLender for IterateMut<St, F> where F: FnMut(&mut St) -> bool {}
+
+/// Creates a lender that lends a reference to a state, starting from an
+/// optional initial state and updating it in place with a step function
+/// `F: FnMut(&mut St) -> bool` between lends.
+///
+/// This is the in-place version of
+/// [`iter::successors()`](core::iter::successors): if the initial state is
+/// `None` the lender is empty; otherwise, the first lend is the initial
+/// state, and before each subsequent lend the step function is applied to
+/// the state, ending the lender as soon as it returns false. Unlike
+/// [`iterate_mut()`], lends are shared references, so the state can only be
+/// changed by the step function.
+///
+/// # Examples
+/// ```rust
+/// # use lender::prelude::*;
+/// let mut lender = lender::successors_mut(Some(1_u32), |x: &mut u32| {
+///     *x *= 10;
+///     *x < 1000
+/// });
+/// assert_eq!(lender.next(), Some(&1));
+/// assert_eq!(lender.next(), Some(&10));
+/// assert_eq!(lender.next(), Some(&100));
+/// assert_eq!(lender.next(), None);
+/// ```
+#[inline]
+pub const fn successors_mut<St, F>(first: Option<St>, f: F) -> SuccessorsMut<St, F>
+where
+    F: FnMut(&mut St) -> bool,
+{
+    SuccessorsMut {
+        state: first,
+        f,
+        started: false,
+        done: false,
+    }
+}
+
+/// A lender that lends a reference to a state updated in place.
+///
+/// This `struct` is created by the [`successors_mut()`] function.
+#[derive(Clone)]
+#[must_use = "lenders are lazy and do nothing unless consumed"]
+pub struct SuccessorsMut<St, F> {
+    state: Option<St>,
+    f: F,
+    started: bool,
+    done: bool,
+}
+
+impl<St, F> SuccessorsMut<St, F> {
+    /// Returns the current state.
+    #[inline]
+    pub fn into_inner(self) -> Option<St> {
+        self.state
+    }
+}
+
+impl<St: fmt::Debug, F> fmt::Debug for SuccessorsMut<St, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SuccessorsMut")
+            .field("state", &self.state)
+            .field("started", &self.started)
+            .field("done", &self.done)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'lend, St, F> Lending<'lend> for SuccessorsMut<St, F>
+where
+    F: FnMut(&mut St) -> bool,
+{
+    type Lend = &'lend St;
+}
+
+impl<St, F> Lender for SuccessorsMut<St, F>
+where
+    F: FnMut(&mut St) -> bool,
+{
+    crate::check_covariance!();
+    #[inline]
+    fn next(&mut self) -> Option<Lend<'_, Self>> {
+        if self.done {
+            return None;
+        }
+        let Some(state) = self.state.as_mut() else {
+            self.done = true;
+            return None;
+        };
+        if self.started {
+            if !(self.f)(state) {
+                self.done = true;
+                return None;
+            }
+        } else {
+            self.started = true;
+        }
+        Some(state)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done || self.state.is_none() {
+            (0, Some(0))
+        } else {
+            (usize::from(!self.started), None)
+        }
+    }
+}
+
+impl<St, F> FusedLender for SuccessorsMut<St, F> where F: FnMut(&mut St) -> bool {}
@@ -1,21 +1,62 @@
+mod cartesian;
+mod combinations;
 mod empty;
 mod from_fn;
 mod from_iter;
 mod from_iter_ref;
+#[cfg(feature = "streaming-iterator")]
+mod from_streaming_iter;
+mod iterate_mut;
 mod once;
 mod once_with;
+mod pairs_mut;
+#[cfg(feature = "rayon")]
+mod par_chunks_mut;
+mod permutations;
 mod repeat;
 mod repeat_with;
+mod split_mut;
+mod str_windows;
+mod vec_cursor_mut;
+mod windows2d_mut;
 mod windows_mut;
 
 pub use self::{
+    cartesian::{MultiCartesian, ProductIndices, multi_cartesian, product_indices},
+    combinations::{
+        Combinations, CombinationsOf, Powerset, combinations, combinations_of, powerset,
+    },
     empty::{Empty, empty},
     from_fn::{FromFn, from_fn},
     from_iter::{FromIntoIter, FromIter, LendIter, from_into_iter, from_iter, lend_iter},
     from_iter_ref::{FromIterRef, from_iter_ref},
+    iterate_mut::{IterateMut, SuccessorsMut, iterate_mut, successors_mut},
     once::{Once, once},
     once_with::{OnceWith, once_with},
+    pairs_mut::{NeighborsMut, PairsMut, PairsMutExt, neighbors_mut, pairs_mut},
+    permutations::{
+        LexicographicPermutations, Permutations, lexicographic_permutations, permutations,
+    },
     repeat::{Repeat, repeat},
     repeat_with::{RepeatWith, repeat_with},
+    split_mut::{
+        AsciiFieldsMut, SplitInclusiveMut, SplitMut, ascii_fields_mut, split_inclusive_mut,
+        split_mut,
+    },
+    str_windows::{StrWindows, str_windows},
+    vec_cursor_mut::{
+        CursorItem, VecCursorMut, VecDequeCursorMut, vec_cursor_mut, vec_deque_cursor_mut,
+    },
     windows_mut::{ArrayWindowsMut, WindowsMut, WindowsMutExt, array_windows_mut, windows_mut},
+    windows2d_mut::{
+        ArrayWindows2dMut, Window2dMut, Windows2dMut, array_windows2d_mut, windows2d_mut,
+    },
 };
+
+#[cfg(feature = "streaming-iterator")]
+pub use self::from_streaming_iter::{
+    FromStreamingIter, FromStreamingIterMut, from_streaming_iter, from_streaming_iter_mut,
+};
+
+#[cfg(feature = "rayon")]
+pub use self::par_chunks_mut::{ParChunksMut, par_chunks_mut};
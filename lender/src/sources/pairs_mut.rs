@@ -0,0 +1,227 @@
+use core::fmt;
+
+use crate::{DoubleEndedLender, ExactSizeLender, FusedLender, Lend, Lender, Lending};
+
+/// Creates a new lender that returns mutable references to the two elements
+/// of every unordered pair of distinct positions of a slice.
+///
+/// Pairs `(i, j)` with `i < j` are returned in lexicographic order, and the
+/// lend is `(&mut slice[i], &mut slice[j])`. This gives simultaneous mutable
+/// access to non-adjacent elements, which [`windows_mut`](crate::windows_mut)
+/// cannot provide.
+///
+/// Note that the [`PairsMutExt`] trait provides a convenient entry point for
+/// this function as a method on slices and arrays.
+///
+/// # Examples
+/// ```rust
+/// # use lender::prelude::*;
+/// let mut s = [1, 2, 3];
+/// // Add to each element the elements following it
+/// s.pairs_mut().for_each(|(a, b)| *a += *b);
+/// assert_eq!(s, [6, 5, 3]);
+/// ```
+#[inline]
+pub fn pairs_mut<T>(slice: &mut [T]) -> PairsMut<'_, T> {
+    PairsMut { slice, i: 0, j: 1 }
+}
+
+/// A lender over mutable references to the elements of the unordered pairs
+/// of a slice.
+///
+/// This `struct` is created by the [`pairs_mut()`] function.
+#[must_use = "lenders are lazy and do nothing unless consumed"]
+pub struct PairsMut<'a, T> {
+    slice: &'a mut [T],
+    // The next pair to return, if j is in bounds; otherwise, the pair
+    // (i + 1, i + 2).
+    i: usize,
+    j: usize,
+}
+
+impl<T: fmt::Debug> fmt::Debug for PairsMut<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PairsMut")
+            .field("slice", &self.slice)
+            .field("i", &self.i)
+            .field("j", &self.j)
+            .finish()
+    }
+}
+
+impl<'lend, T> Lending<'lend> for PairsMut<'_, T> {
+    type Lend = (&'lend mut T, &'lend mut T);
+}
+
+impl<T> Lender for PairsMut<'_, T> {
+    crate::check_covariance!();
+    #[inline]
+    fn next(&mut self) -> Option<Lend<'_, Self>> {
+        let len = self.slice.len();
+        if self.j >= len {
+            if self.i + 2 >= len {
+                return None;
+            }
+            self.i += 1;
+            self.j = self.i + 1;
+        }
+        let (head, tail) = self.slice.split_at_mut(self.j);
+        self.j += 1;
+        Some((&mut head[self.i], &mut tail[0]))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.slice.len();
+        // Pairs (i, j..len), plus all pairs among the elements after i
+        let m = len.saturating_sub(self.i + 1) as u128;
+        let remaining = len.saturating_sub(self.j) as u128 + m * m.saturating_sub(1) / 2;
+        match usize::try_from(remaining) {
+            Ok(x) => (x, Some(x)),
+            Err(_) => (usize::MAX, None),
+        }
+    }
+}
+
+impl<T> ExactSizeLender for PairsMut<'_, T> {}
+
+impl<T> FusedLender for PairsMut<'_, T> {}
+
+/// Creates a new lender that returns, for each position of a slice, the
+/// elements before it, a mutable reference to the element, and the elements
+/// after it.
+///
+/// The lend is `(&slice[..i], &mut slice[i], &slice[i + 1..])`.
+///
+/// Note that the [`PairsMutExt`] trait provides a convenient entry point for
+/// this function as a method on slices and arrays.
+///
+/// # Examples
+/// ```rust
+/// # use lender::prelude::*;
+/// let mut s = [3, 1, 2];
+/// // Replace each element with the number of smaller elements
+/// let mut lender = s.neighbors_mut();
+/// while let Some((prefix, x, suffix)) = lender.next() {
+///     *x = prefix.iter().chain(suffix).filter(|&&y| y < *x).count() as i32;
+/// }
+/// assert_eq!(s, [2, 0, 1]);
+/// ```
+#[inline]
+pub fn neighbors_mut<T>(slice: &mut [T]) -> NeighborsMut<'_, T> {
+    let back = slice.len();
+    NeighborsMut {
+        slice,
+        front: 0,
+        back,
+    }
+}
+
+/// A lender over the positions of a slice, lending the prefix, a mutable
+/// reference to the current element, and the suffix.
+///
+/// This `struct` is created by the [`neighbors_mut()`] function.
+#[must_use = "lenders are lazy and do nothing unless consumed"]
+pub struct NeighborsMut<'a, T> {
+    slice: &'a mut [T],
+    // The positions in front..back have not been returned yet.
+    front: usize,
+    back: usize,
+}
+
+impl<T: fmt::Debug> fmt::Debug for NeighborsMut<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NeighborsMut")
+            .field("slice", &self.slice)
+            .field("front", &self.front)
+            .field("back", &self.back)
+            .finish()
+    }
+}
+
+/// Splits `slice` around position `i`.
+#[inline]
+fn split_around<T>(slice: &mut [T], i: usize) -> (&[T], &mut T, &[T]) {
+    let (prefix, rest) = slice.split_at_mut(i);
+    let (x, suffix) = rest.split_first_mut().unwrap();
+    (prefix, x, suffix)
+}
+
+impl<'lend, T> Lending<'lend> for NeighborsMut<'_, T> {
+    type Lend = (&'lend [T], &'lend mut T, &'lend [T]);
+}
+
+impl<T> Lender for NeighborsMut<'_, T> {
+    crate::check_covariance!();
+    #[inline]
+    fn next(&mut self) -> Option<Lend<'_, Self>> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.front += 1;
+        Some(split_around(self.slice, self.front - 1))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedLender for NeighborsMut<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Lend<'_, Self>> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(split_around(self.slice, self.back))
+    }
+}
+
+impl<T> ExactSizeLender for NeighborsMut<'_, T> {}
+
+impl<T> FusedLender for NeighborsMut<'_, T> {}
+
+/// Extension trait adding to slices and arrays the methods
+/// [`pairs_mut`](PairsMutExt::pairs_mut) and
+/// [`neighbors_mut`](PairsMutExt::neighbors_mut).
+pub trait PairsMutExt<T> {
+    /// Returns a lender over mutable references to the elements of every
+    /// unordered pair of distinct positions.
+    ///
+    /// See [`pairs_mut`] for more details.
+    fn pairs_mut(&mut self) -> PairsMut<'_, T>;
+    /// Returns a lender over the positions, lending the prefix, a mutable
+    /// reference to the current element, and the suffix.
+    ///
+    /// See [`neighbors_mut`] for more details.
+    fn neighbors_mut(&mut self) -> NeighborsMut<'_, T>;
+}
+
+impl<T> PairsMutExt<T> for [T] {
+    /// This method is a convenient entry point for [`pairs_mut`].
+    #[inline]
+    fn pairs_mut(&mut self) -> PairsMut<'_, T> {
+        pairs_mut(self)
+    }
+    /// This method is a convenient entry point for [`neighbors_mut`].
+    #[inline]
+    fn neighbors_mut(&mut self) -> NeighborsMut<'_, T> {
+        neighbors_mut(self)
+    }
+}
+
+impl<T, const N: usize> PairsMutExt<T> for [T; N] {
+    /// This method is a convenient entry point for [`pairs_mut`].
+    #[inline]
+    fn pairs_mut(&mut self) -> PairsMut<'_, T> {
+        pairs_mut(self)
+    }
+    /// This method is a convenient entry point for [`neighbors_mut`].
+    #[inline]
+    fn neighbors_mut(&mut self) -> NeighborsMut<'_, T> {
+        neighbors_mut(self)
+    }
+}
@@ -0,0 +1,119 @@
+use core::{mem, num::NonZero};
+
+use rayon::{
+    iter::IntoParallelIterator,
+    slice::{ChunksMut, ParallelSliceMut},
+};
+
+use crate::{DoubleEndedLender, ExactSizeLender, FusedLender, Lend, Lender, Lending};
+
+/// Creates a new lender that returns mutable non-overlapping chunks of a
+/// slice, and that can be turned into a [`rayon`] parallel iterator handing
+/// the remaining chunks to worker threads.
+///
+/// The last chunk is shorter than `chunk_size` if `chunk_size` does not
+/// divide the length of the slice.
+///
+/// Since chunks are disjoint, the [`IntoParallelIterator`] implementation
+/// yields chunks with the lifetime of the slice, so chunks can be processed
+/// sequentially by lender methods and adapters first, and in parallel by
+/// [`rayon`] afterwards.
+///
+/// This function is available only if the `rayon` feature is enabled.
+///
+/// # Panics
+///
+/// Panics if `chunk_size` is zero.
+///
+/// # Examples
+/// ```rust
+/// # use lender::prelude::*;
+/// use rayon::prelude::*;
+///
+/// let mut data = [0; 10];
+/// let mut lender = lender::par_chunks_mut(&mut data, 3);
+/// // The first chunk is processed sequentially
+/// lender.next().unwrap().fill(1);
+/// // The remaining chunks in parallel
+/// lender.into_par_iter().for_each(|chunk| chunk.fill(2));
+/// assert_eq!(data, [1, 1, 1, 2, 2, 2, 2, 2, 2, 2]);
+/// ```
+#[inline]
+pub const fn par_chunks_mut<T: Send>(slice: &mut [T], chunk_size: usize) -> ParChunksMut<'_, T> {
+    let chunk_size = NonZero::new(chunk_size).expect("chunk size must be non-zero");
+    ParChunksMut { slice, chunk_size }
+}
+
+/// A lender over mutable non-overlapping chunks of a slice, which can be
+/// turned into a [`rayon`] parallel iterator.
+///
+/// This `struct` is created by the [`par_chunks_mut()`] function.
+#[derive(Debug)]
+#[must_use = "lenders are lazy and do nothing unless consumed"]
+pub struct ParChunksMut<'a, T> {
+    slice: &'a mut [T],
+    chunk_size: NonZero<usize>,
+}
+
+impl<'a, T> ParChunksMut<'a, T> {
+    /// Returns the part of the slice that has not been lent yet.
+    #[inline]
+    pub fn into_remainder(self) -> &'a mut [T] {
+        self.slice
+    }
+}
+
+impl<'lend, T: Send> Lending<'lend> for ParChunksMut<'_, T> {
+    type Lend = &'lend mut [T];
+}
+
+impl<T: Send> Lender for ParChunksMut<'_, T> {
+    crate::check_covariance!();
+    #[inline]
+    fn next(&mut self) -> Option<Lend<'_, Self>> {
+        if self.slice.is_empty() {
+            return None;
+        }
+        let mid = self.chunk_size.get().min(self.slice.len());
+        let (chunk, rest) = mem::take(&mut self.slice).split_at_mut(mid);
+        self.slice = rest;
+        Some(chunk)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.slice.len().div_ceil(self.chunk_size.get());
+        (len, Some(len))
+    }
+}
+
+impl<T: Send> DoubleEndedLender for ParChunksMut<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Lend<'_, Self>> {
+        if self.slice.is_empty() {
+            return None;
+        }
+        let len = self.slice.len();
+        let last = match len % self.chunk_size {
+            0 => self.chunk_size.get(),
+            rem => rem,
+        };
+        let (rest, chunk) = mem::take(&mut self.slice).split_at_mut(len - last);
+        self.slice = rest;
+        Some(chunk)
+    }
+}
+
+impl<T: Send> ExactSizeLender for ParChunksMut<'_, T> {}
+
+impl<T: Send> FusedLender for ParChunksMut<'_, T> {}
+
+impl<'a, T: Send> IntoParallelIterator for ParChunksMut<'a, T> {
+    type Item = &'a mut [T];
+    type Iter = ChunksMut<'a, T>;
+
+    #[inline]
+    fn into_par_iter(self) -> Self::Iter {
+        self.slice.par_chunks_mut(self.chunk_size.get())
+    }
+}
@@ -0,0 +1,233 @@
+use alloc::{vec, vec::Vec};
+
+use crate::{ExactSizeLender, FusedLender, Lend, Lender, Lending};
+
+/// Creates a new lender that returns all permutations of a vector, permuting
+/// it in place using [Heap's algorithm].
+///
+/// Each permutation differs from the previous one by a single swap, and is
+/// lent as a slice of the same buffer. The first permutation is the vector
+/// itself; a vector of length *n* has *n*! permutations (one for an empty
+/// vector). Elements are not compared, so equal elements yield repeated
+/// permutations; see [`lexicographic_permutations`] for distinct
+/// permutations in lexicographic order.
+///
+/// The lender is an [`ExactSizeLender`] as long as the number of remaining
+/// permutations fits in a `usize`: otherwise,
+/// [`size_hint()`](Lender::size_hint) returns `(usize::MAX, None)` and
+/// [`len()`](ExactSizeLender::len) panics.
+///
+/// [Heap's algorithm]: https://en.wikipedia.org/wiki/Heap%27s_algorithm
+///
+/// # Examples
+/// ```rust
+/// # use lender::prelude::*;
+/// let mut lender = lender::permutations(vec![1, 2, 3]);
+/// assert_eq!(lender.len(), 6);
+/// assert_eq!(lender.next(), Some(&[1, 2, 3][..]));
+/// assert_eq!(lender.next(), Some(&[2, 1, 3][..]));
+/// assert_eq!(lender.next(), Some(&[3, 1, 2][..]));
+/// assert_eq!(lender.next(), Some(&[1, 3, 2][..]));
+/// assert_eq!(lender.next(), Some(&[2, 3, 1][..]));
+/// assert_eq!(lender.next(), Some(&[3, 2, 1][..]));
+/// assert_eq!(lender.next(), None);
+/// ```
+#[inline]
+pub fn permutations<T>(vec: Vec<T>) -> Permutations<T> {
+    let n = vec.len();
+    Permutations {
+        remaining: (1..=n).try_fold(1_usize, usize::checked_mul),
+        buf: vec,
+        counters: vec![0; n],
+        i: 0,
+    }
+}
+
+/// A lender over the permutations of a vector, generated in place by Heap's
+/// algorithm.
+///
+/// This `struct` is created by the [`permutations()`] function.
+#[derive(Clone, Debug)]
+#[must_use = "lenders are lazy and do nothing unless consumed"]
+pub struct Permutations<T> {
+    buf: Vec<T>,
+    // The loop counters of the recursive formulation of the algorithm.
+    counters: Vec<usize>,
+    // The current level of the algorithm; zero before the first permutation.
+    i: usize,
+    // None if the number of remaining permutations overflows.
+    remaining: Option<usize>,
+}
+
+impl<T> Permutations<T> {
+    /// Returns the underlying vector, in its current permutation.
+    #[inline]
+    pub fn into_inner(self) -> Vec<T> {
+        self.buf
+    }
+}
+
+impl<'lend, T> Lending<'lend> for Permutations<T> {
+    type Lend = &'lend [T];
+}
+
+impl<T> Lender for Permutations<T> {
+    crate::check_covariance!();
+    #[inline]
+    fn next(&mut self) -> Option<Lend<'_, Self>> {
+        if self.i == 0 {
+            self.i = 1;
+        } else {
+            loop {
+                let i = self.i;
+                if i >= self.buf.len() {
+                    return None;
+                }
+                if self.counters[i] < i {
+                    if i % 2 == 0 {
+                        self.buf.swap(0, i);
+                    } else {
+                        self.buf.swap(self.counters[i], i);
+                    }
+                    self.counters[i] += 1;
+                    self.i = 1;
+                    break;
+                }
+                self.counters[i] = 0;
+                self.i += 1;
+            }
+        }
+        self.remaining = self.remaining.map(|x| x - 1);
+        Some(&self.buf)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.remaining {
+            Some(x) => (x, Some(x)),
+            None => (usize::MAX, None),
+        }
+    }
+}
+
+impl<T> ExactSizeLender for Permutations<T> {}
+
+impl<T> FusedLender for Permutations<T> {}
+
+/// Creates a new lender that returns the distinct permutations of a vector in
+/// lexicographic order, permuting it in place.
+///
+/// The vector is sorted first, and each subsequent permutation is obtained by
+/// the classical “next permutation” step, so equal elements do not yield
+/// repeated permutations. Each permutation is lent as a slice of the same
+/// buffer.
+///
+/// The lender is an [`ExactSizeLender`] as long as the number of remaining
+/// permutations fits in a `usize`: otherwise,
+/// [`size_hint()`](Lender::size_hint) returns `(usize::MAX, None)` and
+/// [`len()`](ExactSizeLender::len) panics.
+///
+/// # Examples
+/// ```rust
+/// # use lender::prelude::*;
+/// let mut lender = lender::lexicographic_permutations(vec![2, 1, 1]);
+/// assert_eq!(lender.len(), 3);
+/// assert_eq!(lender.next(), Some(&[1, 1, 2][..]));
+/// assert_eq!(lender.next(), Some(&[1, 2, 1][..]));
+/// assert_eq!(lender.next(), Some(&[2, 1, 1][..]));
+/// assert_eq!(lender.next(), None);
+/// ```
+#[inline]
+pub fn lexicographic_permutations<T: Ord>(mut vec: Vec<T>) -> LexicographicPermutations<T> {
+    vec.sort();
+    LexicographicPermutations {
+        remaining: multiset_permutations(&vec),
+        buf: vec,
+        started: false,
+    }
+}
+
+/// A lender over the distinct permutations of a vector in lexicographic
+/// order.
+///
+/// This `struct` is created by the [`lexicographic_permutations()`]
+/// function.
+#[derive(Clone, Debug)]
+#[must_use = "lenders are lazy and do nothing unless consumed"]
+pub struct LexicographicPermutations<T> {
+    buf: Vec<T>,
+    started: bool,
+    // None if the number of remaining permutations overflows.
+    remaining: Option<usize>,
+}
+
+impl<T> LexicographicPermutations<T> {
+    /// Returns the underlying vector, in its current permutation.
+    #[inline]
+    pub fn into_inner(self) -> Vec<T> {
+        self.buf
+    }
+}
+
+impl<'lend, T> Lending<'lend> for LexicographicPermutations<T> {
+    type Lend = &'lend [T];
+}
+
+impl<T: Ord> Lender for LexicographicPermutations<T> {
+    crate::check_covariance!();
+    #[inline]
+    fn next(&mut self) -> Option<Lend<'_, Self>> {
+        if !self.started {
+            self.started = true;
+        } else if self.remaining == Some(0) || !next_permutation(&mut self.buf) {
+            // Keep the last permutation in place, so the lender stays fused
+            self.remaining = Some(0);
+            return None;
+        }
+        self.remaining = self.remaining.map(|x| x - 1);
+        Some(&self.buf)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.remaining {
+            Some(x) => (x, Some(x)),
+            None => (usize::MAX, None),
+        }
+    }
+}
+
+impl<T: Ord> ExactSizeLender for LexicographicPermutations<T> {}
+
+impl<T: Ord> FusedLender for LexicographicPermutations<T> {}
+
+/// Rearranges `buf` into the next permutation in lexicographic order,
+/// returning false (and leaving `buf` unchanged) if it is the last one.
+fn next_permutation<T: Ord>(buf: &mut [T]) -> bool {
+    let Some(i) = buf.windows(2).rposition(|w| w[0] < w[1]) else {
+        return false;
+    };
+    // There is at least one element after i greater than buf[i]
+    let j = buf.iter().rposition(|x| buf[i] < *x).unwrap();
+    buf.swap(i, j);
+    buf[i + 1..].reverse();
+    true
+}
+
+/// Returns the number of distinct permutations of a sorted slice, that is,
+/// the multinomial coefficient of the multiplicities of its elements, or
+/// None if it does not fit in a `usize`.
+fn multiset_permutations<T: Ord>(sorted: &[T]) -> Option<usize> {
+    let mut result: usize = 1;
+    let mut total = 0;
+    for group in sorted.chunk_by(|a, b| a == b) {
+        // Multiply by binomial(total + group.len(), group.len())
+        for k in 1..=group.len() {
+            total += 1;
+            // result * total / k is always an integer
+            let next = result as u128 * total as u128 / k as u128;
+            result = usize::try_from(next).ok()?;
+        }
+    }
+    Some(result)
+}
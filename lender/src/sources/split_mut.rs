@@ -0,0 +1,362 @@
+use core::{fmt, ops::Range};
+
+use crate::{DoubleEndedLender, FusedLender, Lend, Lender, Lending};
+
+/// The part of a slice whose segments have not been returned yet.
+#[derive(Clone, Copy, Debug)]
+struct SplitState {
+    front: usize,
+    back: usize,
+    finished: bool,
+}
+
+impl SplitState {
+    #[inline]
+    fn new(len: usize) -> Self {
+        Self {
+            front: 0,
+            back: len,
+            finished: false,
+        }
+    }
+
+    /// Returns the range of the first segment, excluding its separator.
+    #[inline]
+    fn next<T>(&mut self, slice: &[T], mut pred: impl FnMut(&T) -> bool) -> Option<Range<usize>> {
+        if self.finished {
+            return None;
+        }
+        let start = self.front;
+        match slice[start..self.back].iter().position(&mut pred) {
+            Some(i) => {
+                self.front = start + i + 1;
+                Some(start..start + i)
+            }
+            None => {
+                self.finished = true;
+                Some(start..self.back)
+            }
+        }
+    }
+
+    /// Returns the range of the last segment, excluding its separator.
+    #[inline]
+    fn next_back<T>(
+        &mut self,
+        slice: &[T],
+        mut pred: impl FnMut(&T) -> bool,
+    ) -> Option<Range<usize>> {
+        if self.finished {
+            return None;
+        }
+        let end = self.back;
+        match slice[self.front..end].iter().rposition(&mut pred) {
+            Some(i) => {
+                self.back = self.front + i;
+                Some(self.back + 1..end)
+            }
+            None => {
+                self.finished = true;
+                Some(self.front..end)
+            }
+        }
+    }
+
+    /// Returns the range of the first segment, including its terminator.
+    #[inline]
+    fn next_inclusive<T>(
+        &mut self,
+        slice: &[T],
+        mut pred: impl FnMut(&T) -> bool,
+    ) -> Option<Range<usize>> {
+        let start = self.front;
+        if start >= self.back {
+            return None;
+        }
+        self.front = match slice[start..self.back].iter().position(&mut pred) {
+            Some(i) => start + i + 1,
+            None => self.back,
+        };
+        Some(start..self.front)
+    }
+
+    /// Returns the range of the last segment, including its terminator.
+    #[inline]
+    fn next_back_inclusive<T>(
+        &mut self,
+        slice: &[T],
+        mut pred: impl FnMut(&T) -> bool,
+    ) -> Option<Range<usize>> {
+        let end = self.back;
+        if self.front >= end {
+            return None;
+        }
+        // The last element terminates the last segment, whatever it is
+        self.back = match slice[self.front..end - 1].iter().rposition(&mut pred) {
+            Some(i) => self.front + i + 1,
+            None => self.front,
+        };
+        Some(self.back..end)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            (0, Some(0))
+        } else {
+            (1, Some(self.back - self.front + 1))
+        }
+    }
+
+    #[inline]
+    fn size_hint_inclusive(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (usize::from(len != 0), Some(len))
+    }
+}
+
+/// Creates a new lender that returns the mutable subslices of a slice
+/// separated by elements matching a predicate.
+///
+/// This is the lending equivalent of [`slice::split_mut`]: the separators
+/// are not included in the subslices, and two adjacent separators, or a
+/// separator at either end of the slice, delimit an empty subslice.
+///
+/// # Examples
+/// ```rust
+/// # use lender::prelude::*;
+/// let mut s = [1, 2, 0, 3, 0, 4, 5];
+/// let mut lender = lender::split_mut(&mut s, |&x| x == 0);
+/// // Reverse each segment in place
+/// while let Some(segment) = lender.next() {
+///     segment.reverse();
+/// }
+/// assert_eq!(s, [2, 1, 0, 3, 0, 5, 4]);
+/// ```
+#[inline]
+pub fn split_mut<T, P>(slice: &mut [T], pred: P) -> SplitMut<'_, T, P>
+where
+    P: FnMut(&T) -> bool,
+{
+    SplitMut {
+        state: SplitState::new(slice.len()),
+        slice,
+        pred,
+    }
+}
+
+/// A lender over the mutable subslices of a slice separated by elements
+/// matching a predicate.
+///
+/// This `struct` is created by the [`split_mut()`] function.
+#[must_use = "lenders are lazy and do nothing unless consumed"]
+pub struct SplitMut<'a, T, P> {
+    slice: &'a mut [T],
+    state: SplitState,
+    pred: P,
+}
+
+impl<T: fmt::Debug, P> fmt::Debug for SplitMut<'_, T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SplitMut")
+            .field("slice", &self.slice)
+            .field("state", &self.state)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'lend, T, P> Lending<'lend> for SplitMut<'_, T, P>
+where
+    P: FnMut(&T) -> bool,
+{
+    type Lend = &'lend mut [T];
+}
+
+impl<T, P> Lender for SplitMut<'_, T, P>
+where
+    P: FnMut(&T) -> bool,
+{
+    crate::check_covariance!();
+    #[inline]
+    fn next(&mut self) -> Option<Lend<'_, Self>> {
+        let range = self.state.next(self.slice, &mut self.pred)?;
+        Some(&mut self.slice[range])
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.state.size_hint()
+    }
+}
+
+impl<T, P> DoubleEndedLender for SplitMut<'_, T, P>
+where
+    P: FnMut(&T) -> bool,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Lend<'_, Self>> {
+        let range = self.state.next_back(self.slice, &mut self.pred)?;
+        Some(&mut self.slice[range])
+    }
+}
+
+impl<T, P> FusedLender for SplitMut<'_, T, P> where P: FnMut(&T) -> bool {}
+
+/// Creates a new lender that returns the mutable subslices of a slice
+/// terminated by elements matching a predicate.
+///
+/// This is the lending equivalent of [`slice::split_inclusive_mut`]: each
+/// terminator is included at the end of its subslice, and if the slice ends
+/// with a terminator no empty subslice is returned after it.
+///
+/// # Examples
+/// ```rust
+/// # use lender::prelude::*;
+/// let mut s = *b"ab\ncd\n";
+/// let mut lender = lender::split_inclusive_mut(&mut s, |&b| b == b'\n');
+/// assert_eq!(lender.next().as_deref(), Some(&b"ab\n"[..]));
+/// assert_eq!(lender.next().as_deref(), Some(&b"cd\n"[..]));
+/// assert_eq!(lender.next(), None);
+/// ```
+#[inline]
+pub fn split_inclusive_mut<T, P>(slice: &mut [T], pred: P) -> SplitInclusiveMut<'_, T, P>
+where
+    P: FnMut(&T) -> bool,
+{
+    SplitInclusiveMut {
+        state: SplitState::new(slice.len()),
+        slice,
+        pred,
+    }
+}
+
+/// A lender over the mutable subslices of a slice terminated by elements
+/// matching a predicate.
+///
+/// This `struct` is created by the [`split_inclusive_mut()`] function.
+#[must_use = "lenders are lazy and do nothing unless consumed"]
+pub struct SplitInclusiveMut<'a, T, P> {
+    slice: &'a mut [T],
+    state: SplitState,
+    pred: P,
+}
+
+impl<T: fmt::Debug, P> fmt::Debug for SplitInclusiveMut<'_, T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SplitInclusiveMut")
+            .field("slice", &self.slice)
+            .field("state", &self.state)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'lend, T, P> Lending<'lend> for SplitInclusiveMut<'_, T, P>
+where
+    P: FnMut(&T) -> bool,
+{
+    type Lend = &'lend mut [T];
+}
+
+impl<T, P> Lender for SplitInclusiveMut<'_, T, P>
+where
+    P: FnMut(&T) -> bool,
+{
+    crate::check_covariance!();
+    #[inline]
+    fn next(&mut self) -> Option<Lend<'_, Self>> {
+        let range = self.state.next_inclusive(self.slice, &mut self.pred)?;
+        Some(&mut self.slice[range])
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.state.size_hint_inclusive()
+    }
+}
+
+impl<T, P> DoubleEndedLender for SplitInclusiveMut<'_, T, P>
+where
+    P: FnMut(&T) -> bool,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Lend<'_, Self>> {
+        let range = self.state.next_back_inclusive(self.slice, &mut self.pred)?;
+        Some(&mut self.slice[range])
+    }
+}
+
+impl<T, P> FusedLender for SplitInclusiveMut<'_, T, P> where P: FnMut(&T) -> bool {}
+
+/// Creates a new lender that returns the mutable fields of a byte buffer
+/// separated by a given ASCII byte.
+///
+/// This is [`split_mut()`] specialized to byte equality, with a nameable
+/// type, and is meant for in-place normalization of delimited records: for
+/// example, `ascii_fields_mut(buf, b',')` splits a line of comma-separated
+/// values.
+///
+/// # Panics
+///
+/// Panics if `sep` is not an ASCII byte, since a non-ASCII byte could split
+/// a UTF-8 encoded character.
+///
+/// # Examples
+/// ```rust
+/// # use lender::prelude::*;
+/// let mut buf = *b"Foo,BAR,bAz";
+/// lender::ascii_fields_mut(&mut buf, b',').for_each(|field: &mut [u8]| {
+///     field.make_ascii_lowercase();
+/// });
+/// assert_eq!(&buf, b"foo,bar,baz");
+/// ```
+#[inline]
+pub fn ascii_fields_mut(buf: &mut [u8], sep: u8) -> AsciiFieldsMut<'_> {
+    assert!(sep.is_ascii(), "the separator must be an ASCII byte");
+    AsciiFieldsMut {
+        state: SplitState::new(buf.len()),
+        buf,
+        sep,
+    }
+}
+
+/// A lender over the mutable fields of a byte buffer separated by an ASCII
+/// byte.
+///
+/// This `struct` is created by the [`ascii_fields_mut()`] function.
+#[derive(Debug)]
+#[must_use = "lenders are lazy and do nothing unless consumed"]
+pub struct AsciiFieldsMut<'a> {
+    buf: &'a mut [u8],
+    state: SplitState,
+    sep: u8,
+}
+
+impl<'lend> Lending<'lend> for AsciiFieldsMut<'_> {
+    type Lend = &'lend mut [u8];
+}
+
+impl Lender for AsciiFieldsMut<'_> {
+    crate::check_covariance!();
+    #[inline]
+    fn next(&mut self) -> Option<Lend<'_, Self>> {
+        let sep = self.sep;
+        let range = self.state.next(self.buf, |&b| b == sep)?;
+        Some(&mut self.buf[range])
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.state.size_hint()
+    }
+}
+
+impl DoubleEndedLender for AsciiFieldsMut<'_> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Lend<'_, Self>> {
+        let sep = self.sep;
+        let range = self.state.next_back(self.buf, |&b| b == sep)?;
+        Some(&mut self.buf[range])
+    }
+}
+
+impl FusedLender for AsciiFieldsMut<'_> {}
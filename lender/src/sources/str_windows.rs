@@ -0,0 +1,91 @@
+use crate::{FusedLender, Lend, Lender, Lending};
+
+/// Returns the length in bytes of the character starting at byte `i` of `s`.
+#[inline]
+fn char_len_at(s: &str, i: usize) -> usize {
+    s[i..].chars().next().map_or(0, char::len_utf8)
+}
+
+/// Creates a new lender that returns the contiguous overlapping windows of
+/// `size` characters of a string.
+///
+/// Windows always start and end on character boundaries, so each of them is
+/// a valid `&str`; a string with fewer than `size` characters has no windows.
+/// No allocation happens, and each step scans a constant number of bytes.
+///
+/// # Panics
+///
+/// Panics if `size` is zero.
+///
+/// # Examples
+/// ```rust
+/// # use lender::prelude::*;
+/// let mut lender = lender::str_windows("añb€", 2);
+/// assert_eq!(lender.next(), Some("añ"));
+/// assert_eq!(lender.next(), Some("ñb"));
+/// assert_eq!(lender.next(), Some("b€"));
+/// assert_eq!(lender.next(), None);
+/// ```
+#[inline]
+pub fn str_windows(s: &str, size: usize) -> StrWindows<'_> {
+    assert!(size != 0, "window size must be non-zero");
+    let end = s
+        .char_indices()
+        .nth(size - 1)
+        .map(|(i, c)| i + c.len_utf8());
+    StrWindows {
+        s,
+        start: 0,
+        end: end.unwrap_or(0),
+        done: end.is_none(),
+    }
+}
+
+/// A lender over the overlapping windows of characters of a string.
+///
+/// This `struct` is created by the [`str_windows()`] function.
+#[derive(Clone, Debug)]
+#[must_use = "lenders are lazy and do nothing unless consumed"]
+pub struct StrWindows<'a> {
+    s: &'a str,
+    // The byte range of the next window.
+    start: usize,
+    end: usize,
+    done: bool,
+}
+
+impl<'lend, 'a> Lending<'lend> for StrWindows<'a> {
+    type Lend = &'a str;
+}
+
+impl Lender for StrWindows<'_> {
+    crate::check_covariance!();
+    #[inline]
+    fn next(&mut self) -> Option<Lend<'_, Self>> {
+        if self.done {
+            return None;
+        }
+        let window = &self.s[self.start..self.end];
+        if self.end == self.s.len() {
+            self.done = true;
+        } else {
+            self.start += char_len_at(self.s, self.start);
+            self.end += char_len_at(self.s, self.end);
+        }
+        Some(window)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            // Each of the remaining bytes is at most one character, and each
+            // character is at most four bytes
+            let rest = self.s.len() - self.end;
+            (1 + rest.div_ceil(4), Some(1 + rest))
+        }
+    }
+}
+
+impl FusedLender for StrWindows<'_> {}
@@ -0,0 +1,398 @@
+use alloc::{collections::VecDeque, vec::Vec};
+use core::{fmt, mem, ptr, slice};
+
+use crate::{ExactSizeLender, FusedLender, Lend, Lender, Lending};
+
+/// Creates a new lender that visits the elements of a vector, lending for
+/// each of them a [`CursorItem`] handle that can modify or remove the element
+/// and insert new elements around it.
+///
+/// This is a version of [`Vec::retain_mut`] in which the closure sees the
+/// neighbors of the current element and can also insert elements, in the
+/// spirit of the cursors of [`LinkedList`](alloc::collections::LinkedList).
+/// The vector is compacted lazily in place while the lender advances, so a
+/// full pass takes linear time independently of the number of removals and
+/// insertions; the allocation grows only when insertions fill the gap left by
+/// visited and removed elements. Inserted elements are not visited.
+///
+/// The vector is in its final state only after the lender is dropped:
+/// dropping the lender before the end keeps all unvisited elements. If the
+/// lender is leaked (e.g., with [`mem::forget`]), the vector may lose
+/// elements.
+///
+/// # Examples
+/// ```rust
+/// # use lender::prelude::*;
+/// let mut v = vec![1, 2, 2, 3, 3, 3];
+/// let mut lender = lender::vec_cursor_mut(&mut v);
+/// // Remove duplicates, and put a zero after each odd element
+/// while let Some(mut item) = lender.next() {
+///     if item.peek_next() == Some(item.get()) {
+///         item.remove();
+///     } else if item.get() % 2 == 1 {
+///         item.insert_after(0);
+///     }
+/// }
+/// drop(lender);
+/// assert_eq!(v, [1, 0, 2, 3, 0]);
+/// ```
+#[inline]
+pub fn vec_cursor_mut<T>(vec: &mut Vec<T>) -> VecCursorMut<'_, T> {
+    let gap = Gap::new(vec);
+    VecCursorMut { vec, gap }
+}
+
+/// Creates a new lender that visits the elements of a deque, lending for
+/// each of them a [`CursorItem`] handle that can modify or remove the element
+/// and insert new elements around it.
+///
+/// This is the [`VecDeque`] version of [`vec_cursor_mut()`]: the deque is
+/// made contiguous and then edited in place in the same way, reusing its
+/// allocation.
+///
+/// # Examples
+/// ```rust
+/// # use lender::prelude::*;
+/// # use std::collections::VecDeque;
+/// let mut d = VecDeque::from([2, 3, 4]);
+/// d.push_front(1);
+/// let mut lender = lender::vec_deque_cursor_mut(&mut d);
+/// // Replace each even element with its halves
+/// while let Some(mut item) = lender.next() {
+///     if item.get() % 2 == 0 {
+///         let half = item.get() / 2;
+///         item.insert_before(half);
+///         *item.get_mut() = half;
+///     }
+/// }
+/// drop(lender);
+/// assert_eq!(d, [1, 1, 1, 3, 2, 2]);
+/// ```
+#[inline]
+pub fn vec_deque_cursor_mut<T>(deque: &mut VecDeque<T>) -> VecDequeCursorMut<'_, T> {
+    // Does not allocate, and moves the elements only if they wrap around
+    let mut vec = Vec::from(mem::take(deque));
+    let gap = Gap::new(&mut vec);
+    VecDequeCursorMut { deque, vec, gap }
+}
+
+/// The editing state of a vector whose length is temporarily set to zero.
+///
+/// The allocation of the vector is split in four regions:
+/// - `[0, write)` contains the elements of the final vector that precede the
+///   current one;
+/// - `[read - after, read)` contains the elements inserted after the current
+///   one, in reverse order of insertion;
+/// - `[read, end)` contains the elements not visited yet;
+/// - the rest is uninitialized.
+///
+/// The current element, if not removed, is kept out of the vector, and there
+/// is always room for it before the elements inserted after it.
+struct Gap<T> {
+    write: usize,
+    read: usize,
+    end: usize,
+    after: usize,
+    current: Option<T>,
+}
+
+impl<T> Gap<T> {
+    #[inline]
+    fn new(vec: &mut Vec<T>) -> Self {
+        let end = vec.len();
+        // SAFETY: the elements are now tracked by the gap
+        unsafe { vec.set_len(0) };
+        Self {
+            write: 0,
+            read: 0,
+            end,
+            after: 0,
+            current: None,
+        }
+    }
+
+    /// Moves the current element and the elements inserted after it to the
+    /// end of the final part of the vector.
+    #[inline]
+    fn flush(&mut self, vec: &mut Vec<T>) {
+        let base = vec.as_mut_ptr();
+        // SAFETY: the regions are as described in the documentation of Gap,
+        // and there is room for the current element
+        unsafe {
+            if let Some(x) = self.current.take() {
+                base.add(self.write).write(x);
+                self.write += 1;
+            }
+            if self.after != 0 {
+                let src = base.add(self.read - self.after);
+                slice::from_raw_parts_mut(src, self.after).reverse();
+                ptr::copy(src, base.add(self.write), self.after);
+                self.write += self.after;
+                self.after = 0;
+            }
+        }
+    }
+
+    /// Moves the next element out of the vector and makes it the current one.
+    #[inline]
+    fn advance(&mut self, vec: &mut Vec<T>) -> bool {
+        self.flush(vec);
+        if self.read == self.end {
+            return false;
+        }
+        // SAFETY: the element is initialized, and it is no longer tracked by
+        // the vector after incrementing read
+        self.current = Some(unsafe { vec.as_ptr().add(self.read).read() });
+        self.read += 1;
+        true
+    }
+
+    /// Ensures that there is room for one more inserted element.
+    #[inline]
+    fn reserve(&mut self, vec: &mut Vec<T>) {
+        let free = self.read - self.after - self.write;
+        if free > usize::from(self.current.is_some()) {
+            return;
+        }
+        let mut new = Vec::with_capacity(self.end.saturating_add(self.end.max(4)));
+        let shift = new.capacity() - self.end;
+        let start = self.read - self.after;
+        // SAFETY: we move the initialized regions to the new allocation,
+        // shifting the ones after the gap by the added capacity; the old
+        // vector has length zero, so replacing it only frees its allocation
+        unsafe {
+            let (src, dst) = (vec.as_ptr(), new.as_mut_ptr());
+            ptr::copy_nonoverlapping(src, dst, self.write);
+            ptr::copy_nonoverlapping(src.add(start), dst.add(start + shift), self.end - start);
+        }
+        *vec = new;
+        self.read += shift;
+        self.end += shift;
+    }
+
+    #[inline]
+    fn insert_before(&mut self, vec: &mut Vec<T>, value: T) {
+        self.reserve(vec);
+        // SAFETY: reserve() left room in the gap
+        unsafe { vec.as_mut_ptr().add(self.write).write(value) };
+        self.write += 1;
+    }
+
+    #[inline]
+    fn insert_after(&mut self, vec: &mut Vec<T>, value: T) {
+        self.reserve(vec);
+        self.after += 1;
+        // SAFETY: reserve() left room in the gap
+        unsafe { vec.as_mut_ptr().add(self.read - self.after).write(value) };
+    }
+
+    // The vector has length zero, so we need it to access its allocation
+    #[allow(clippy::ptr_arg)]
+    #[inline]
+    fn visited<'a>(&self, vec: &'a Vec<T>) -> &'a [T] {
+        // SAFETY: the region is initialized
+        unsafe { slice::from_raw_parts(vec.as_ptr(), self.write) }
+    }
+
+    #[allow(clippy::ptr_arg)]
+    #[inline]
+    fn remaining<'a>(&self, vec: &'a Vec<T>) -> &'a [T] {
+        // SAFETY: the region is initialized
+        unsafe { slice::from_raw_parts(vec.as_ptr().add(self.read), self.end - self.read) }
+    }
+
+    /// Moves the unvisited elements after the final ones and restores the
+    /// length of the vector.
+    #[inline]
+    fn finish(&mut self, vec: &mut Vec<T>) {
+        self.flush(vec);
+        let len = self.end - self.read;
+        // SAFETY: after flushing, the initialized regions are [0, write) and
+        // [read, end)
+        unsafe {
+            let base = vec.as_mut_ptr();
+            ptr::copy(base.add(self.read), base.add(self.write), len);
+            vec.set_len(self.write + len);
+        }
+        self.read = self.end;
+        self.write = 0;
+    }
+}
+
+/// A lender over the elements of a vector that can edit the vector.
+///
+/// This `struct` is created by the [`vec_cursor_mut()`] function.
+#[must_use = "lenders are lazy and do nothing unless consumed"]
+pub struct VecCursorMut<'a, T> {
+    vec: &'a mut Vec<T>,
+    gap: Gap<T>,
+}
+
+impl<T: fmt::Debug> fmt::Debug for VecCursorMut<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VecCursorMut")
+            .field("visited", &self.gap.visited(self.vec))
+            .field("current", &self.gap.current)
+            .field("remaining", &self.gap.remaining(self.vec))
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> Drop for VecCursorMut<'_, T> {
+    #[inline]
+    fn drop(&mut self) {
+        self.gap.finish(self.vec);
+    }
+}
+
+impl<'lend, T> Lending<'lend> for VecCursorMut<'_, T> {
+    type Lend = CursorItem<'lend, T>;
+}
+
+impl<T> Lender for VecCursorMut<'_, T> {
+    crate::check_covariance!();
+    #[inline]
+    fn next(&mut self) -> Option<Lend<'_, Self>> {
+        if !self.gap.advance(self.vec) {
+            return None;
+        }
+        Some(CursorItem {
+            vec: self.vec,
+            gap: &mut self.gap,
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.gap.end - self.gap.read;
+        (len, Some(len))
+    }
+}
+
+impl<T> ExactSizeLender for VecCursorMut<'_, T> {}
+
+impl<T> FusedLender for VecCursorMut<'_, T> {}
+
+/// A lender over the elements of a deque that can edit the deque.
+///
+/// This `struct` is created by the [`vec_deque_cursor_mut()`] function.
+#[must_use = "lenders are lazy and do nothing unless consumed"]
+pub struct VecDequeCursorMut<'a, T> {
+    deque: &'a mut VecDeque<T>,
+    // The elements of the deque while editing.
+    vec: Vec<T>,
+    gap: Gap<T>,
+}
+
+impl<T: fmt::Debug> fmt::Debug for VecDequeCursorMut<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VecDequeCursorMut")
+            .field("visited", &self.gap.visited(&self.vec))
+            .field("current", &self.gap.current)
+            .field("remaining", &self.gap.remaining(&self.vec))
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> Drop for VecDequeCursorMut<'_, T> {
+    #[inline]
+    fn drop(&mut self) {
+        self.gap.finish(&mut self.vec);
+        *self.deque = VecDeque::from(mem::take(&mut self.vec));
+    }
+}
+
+impl<'lend, T> Lending<'lend> for VecDequeCursorMut<'_, T> {
+    type Lend = CursorItem<'lend, T>;
+}
+
+impl<T> Lender for VecDequeCursorMut<'_, T> {
+    crate::check_covariance!();
+    #[inline]
+    fn next(&mut self) -> Option<Lend<'_, Self>> {
+        if !self.gap.advance(&mut self.vec) {
+            return None;
+        }
+        Some(CursorItem {
+            vec: &mut self.vec,
+            gap: &mut self.gap,
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.gap.end - self.gap.read;
+        (len, Some(len))
+    }
+}
+
+impl<T> ExactSizeLender for VecDequeCursorMut<'_, T> {}
+
+impl<T> FusedLender for VecDequeCursorMut<'_, T> {}
+
+/// A handle to the current element of a [`VecCursorMut`] or a
+/// [`VecDequeCursorMut`].
+///
+/// This `struct` is lent by [`VecCursorMut`] and [`VecDequeCursorMut`].
+pub struct CursorItem<'a, T> {
+    vec: &'a mut Vec<T>,
+    // The current element is always Some, as removing it consumes the handle.
+    gap: &'a mut Gap<T>,
+}
+
+impl<T: fmt::Debug> fmt::Debug for CursorItem<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("CursorItem").field(self.get()).finish()
+    }
+}
+
+impl<T> CursorItem<'_, T> {
+    /// Returns a reference to the current element.
+    #[inline]
+    pub fn get(&self) -> &T {
+        self.gap.current.as_ref().unwrap()
+    }
+
+    /// Returns a mutable reference to the current element.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut T {
+        self.gap.current.as_mut().unwrap()
+    }
+
+    /// Removes the current element from the vector and returns it.
+    #[inline]
+    pub fn remove(self) -> T {
+        self.gap.current.take().unwrap()
+    }
+
+    /// Inserts an element before the current one.
+    ///
+    /// The element becomes the one returned by
+    /// [`peek_prev`](CursorItem::peek_prev).
+    #[inline]
+    pub fn insert_before(&mut self, value: T) {
+        self.gap.insert_before(self.vec, value);
+    }
+
+    /// Inserts an element after the current one and after the elements
+    /// previously inserted after it.
+    ///
+    /// The element will not be visited by the lender.
+    #[inline]
+    pub fn insert_after(&mut self, value: T) {
+        self.gap.insert_after(self.vec, value);
+    }
+
+    /// Returns a reference to the element before the current one in the
+    /// final vector, if any.
+    #[inline]
+    pub fn peek_prev(&self) -> Option<&T> {
+        self.gap.visited(self.vec).last()
+    }
+
+    /// Returns a reference to the next element to be visited, if any.
+    #[inline]
+    pub fn peek_next(&self) -> Option<&T> {
+        self.gap.remaining(self.vec).first()
+    }
+}
@@ -0,0 +1,374 @@
+use core::fmt;
+
+use crate::{DoubleEndedLender, ExactSizeLender, FusedLender, Lend, Lender, Lending};
+
+/// The positions of the windows of a matrix that have not been returned yet,
+/// in row-major order.
+#[derive(Clone, Copy, Debug)]
+struct WindowGrid {
+    // The number of columns of the matrix.
+    cols: usize,
+    // The number of windows in each row of windows.
+    per_row: usize,
+    // The windows in front..back have not been returned yet.
+    front: usize,
+    back: usize,
+}
+
+impl WindowGrid {
+    #[inline]
+    fn new(len: usize, rows: usize, cols: usize, height: usize, width: usize) -> Self {
+        assert!(height != 0 && width != 0, "window size must be non-zero");
+        assert_eq!(
+            rows.checked_mul(cols),
+            Some(len),
+            "the slice length must be rows * cols"
+        );
+        let per_row = (cols + 1).saturating_sub(width);
+        let per_col = (rows + 1).saturating_sub(height);
+        Self {
+            cols,
+            per_row,
+            front: 0,
+            back: per_row * per_col,
+        }
+    }
+
+    /// Returns the offset in the matrix of the top-left corner of a window.
+    #[inline]
+    fn offset(&self, window: usize) -> usize {
+        (window / self.per_row) * self.cols + window % self.per_row
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<usize> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.front += 1;
+        Some(self.offset(self.front - 1))
+    }
+
+    #[inline]
+    fn next_back(&mut self) -> Option<usize> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.offset(self.back))
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.back - self.front
+    }
+}
+
+/// Creates a new lender that returns mutable views of the contiguous
+/// overlapping `height` × `width` windows of a row-major matrix.
+///
+/// The slice is interpreted as a matrix with `rows` rows and `cols` columns,
+/// and windows are returned in row-major order of their top-left corner. Each
+/// window is a [`Window2dMut`], which gives access to the rows of the window
+/// as mutable slices. The const generic equivalent is
+/// [`array_windows2d_mut`].
+///
+/// # Panics
+///
+/// Panics if `height` or `width` is zero, or if the length of the slice is
+/// not `rows * cols`.
+///
+/// # Examples
+/// ```rust
+/// # use lender::prelude::*;
+/// let mut m = [
+///     1, 2, 3,
+///     4, 5, 6,
+/// ];
+/// let mut lender = lender::windows2d_mut(&mut m, 2, 3, 2, 2);
+/// assert_eq!(lender.len(), 2);
+/// let mut w = lender.next().unwrap();
+/// assert_eq!(w.row(0), &[1, 2]);
+/// assert_eq!(w.row(1), &[4, 5]);
+/// *w.get_mut(1, 1).unwrap() = 0;
+/// let w = lender.next().unwrap();
+/// assert_eq!(w.row(0), &[2, 3]);
+/// assert_eq!(w.row(1), &[0, 6]);
+/// ```
+#[inline]
+pub fn windows2d_mut<T>(
+    slice: &mut [T],
+    rows: usize,
+    cols: usize,
+    height: usize,
+    width: usize,
+) -> Windows2dMut<'_, T> {
+    Windows2dMut {
+        grid: WindowGrid::new(slice.len(), rows, cols, height, width),
+        slice,
+        height,
+        width,
+    }
+}
+
+/// A lender over mutable views of the overlapping two-dimensional windows of
+/// a row-major matrix.
+///
+/// This `struct` is created by the [`windows2d_mut()`] function.
+#[must_use = "lenders are lazy and do nothing unless consumed"]
+pub struct Windows2dMut<'a, T> {
+    slice: &'a mut [T],
+    grid: WindowGrid,
+    height: usize,
+    width: usize,
+}
+
+impl<T: fmt::Debug> fmt::Debug for Windows2dMut<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Windows2dMut")
+            .field("slice", &self.slice)
+            .field("cols", &self.grid.cols)
+            .field("height", &self.height)
+            .field("width", &self.width)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> Windows2dMut<'_, T> {
+    #[inline]
+    fn window(&mut self, offset: usize) -> Window2dMut<'_, T> {
+        let end = offset + (self.height - 1) * self.grid.cols + self.width;
+        Window2dMut {
+            data: &mut self.slice[offset..end],
+            stride: self.grid.cols,
+            height: self.height,
+            width: self.width,
+        }
+    }
+}
+
+impl<'lend, T> Lending<'lend> for Windows2dMut<'_, T> {
+    type Lend = Window2dMut<'lend, T>;
+}
+
+impl<T> Lender for Windows2dMut<'_, T> {
+    crate::check_covariance!();
+    #[inline]
+    fn next(&mut self) -> Option<Lend<'_, Self>> {
+        let offset = self.grid.next()?;
+        Some(self.window(offset))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.grid.len();
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedLender for Windows2dMut<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Lend<'_, Self>> {
+        let offset = self.grid.next_back()?;
+        Some(self.window(offset))
+    }
+}
+
+impl<T> ExactSizeLender for Windows2dMut<'_, T> {}
+
+impl<T> FusedLender for Windows2dMut<'_, T> {}
+
+/// A mutable view of a two-dimensional window of a row-major matrix.
+///
+/// This `struct` is lent by [`Windows2dMut`].
+pub struct Window2dMut<'a, T> {
+    // From the top-left to the bottom-right corner of the window.
+    data: &'a mut [T],
+    // The number of columns of the matrix.
+    stride: usize,
+    height: usize,
+    width: usize,
+}
+
+impl<T: fmt::Debug> fmt::Debug for Window2dMut<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries((0..self.height).map(|r| self.row(r)))
+            .finish()
+    }
+}
+
+impl<T> Window2dMut<'_, T> {
+    /// Returns the number of rows of the window.
+    #[inline]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the number of columns of the window.
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns row `r` of the window.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `r` is not smaller than the height of the window.
+    #[inline]
+    pub fn row(&self, r: usize) -> &[T] {
+        assert!(r < self.height, "row index out of bounds");
+        &self.data[r * self.stride..][..self.width]
+    }
+
+    /// Returns row `r` of the window as a mutable slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `r` is not smaller than the height of the window.
+    #[inline]
+    pub fn row_mut(&mut self, r: usize) -> &mut [T] {
+        assert!(r < self.height, "row index out of bounds");
+        &mut self.data[r * self.stride..][..self.width]
+    }
+
+    /// Returns a reference to the element in row `r` and column `c` of the
+    /// window, or `None` if out of bounds.
+    #[inline]
+    pub fn get(&self, r: usize, c: usize) -> Option<&T> {
+        if r < self.height && c < self.width {
+            Some(&self.data[r * self.stride + c])
+        } else {
+            None
+        }
+    }
+
+    /// Returns a mutable reference to the element in row `r` and column `c`
+    /// of the window, or `None` if out of bounds.
+    #[inline]
+    pub fn get_mut(&mut self, r: usize, c: usize) -> Option<&mut T> {
+        if r < self.height && c < self.width {
+            Some(&mut self.data[r * self.stride + c])
+        } else {
+            None
+        }
+    }
+}
+
+/// Creates a new lender that returns the contiguous overlapping
+/// `HEIGHT` × `WIDTH` windows of a row-major matrix as arrays of mutable
+/// references to rows.
+///
+/// This is the const generic equivalent of [`windows2d_mut`]: each window is
+/// lent as a `[&mut [T; WIDTH]; HEIGHT]`, in the same way as
+/// [`array_windows_mut`](crate::array_windows_mut) lends `&mut [T; N]`.
+///
+/// # Panics
+///
+/// Panics if `HEIGHT` or `WIDTH` is zero, or if the length of the slice is
+/// not `rows * cols`.
+///
+/// # Examples
+/// ```rust
+/// # use lender::prelude::*;
+/// let mut m = [
+///     1, 2, 3,
+///     4, 5, 6,
+/// ];
+/// let mut lender = lender::array_windows2d_mut::<_, 2, 2>(&mut m, 2, 3);
+/// assert_eq!(lender.next_back(), Some([&mut [2, 3], &mut [5, 6]]));
+/// let [top, bottom] = lender.next().unwrap();
+/// top[0] += bottom[1];
+/// assert_eq!(lender.next(), None);
+/// assert_eq!(m, [6, 2, 3, 4, 5, 6]);
+/// ```
+#[inline]
+pub fn array_windows2d_mut<T, const HEIGHT: usize, const WIDTH: usize>(
+    slice: &mut [T],
+    rows: usize,
+    cols: usize,
+) -> ArrayWindows2dMut<'_, T, HEIGHT, WIDTH> {
+    ArrayWindows2dMut {
+        grid: WindowGrid::new(slice.len(), rows, cols, HEIGHT, WIDTH),
+        slice,
+    }
+}
+
+/// A lender over the overlapping two-dimensional windows of a row-major
+/// matrix as arrays of mutable references to rows.
+///
+/// This `struct` is created by the [`array_windows2d_mut()`] function.
+#[must_use = "lenders are lazy and do nothing unless consumed"]
+pub struct ArrayWindows2dMut<'a, T, const HEIGHT: usize, const WIDTH: usize> {
+    slice: &'a mut [T],
+    grid: WindowGrid,
+}
+
+impl<T: fmt::Debug, const HEIGHT: usize, const WIDTH: usize> fmt::Debug
+    for ArrayWindows2dMut<'_, T, HEIGHT, WIDTH>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArrayWindows2dMut")
+            .field("slice", &self.slice)
+            .field("cols", &self.grid.cols)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T, const HEIGHT: usize, const WIDTH: usize> ArrayWindows2dMut<'_, T, HEIGHT, WIDTH> {
+    #[inline]
+    fn window(&mut self, offset: usize) -> [&mut [T; WIDTH]; HEIGHT] {
+        let cols = self.grid.cols;
+        let mut rest = &mut self.slice[offset..];
+        core::array::from_fn(|_| {
+            let mid = cols.min(rest.len());
+            let (row, tail) = core::mem::take(&mut rest).split_at_mut(mid);
+            rest = tail;
+            row.first_chunk_mut().unwrap()
+        })
+    }
+}
+
+impl<'lend, T, const HEIGHT: usize, const WIDTH: usize> Lending<'lend>
+    for ArrayWindows2dMut<'_, T, HEIGHT, WIDTH>
+{
+    type Lend = [&'lend mut [T; WIDTH]; HEIGHT];
+}
+
+impl<T, const HEIGHT: usize, const WIDTH: usize> Lender
+    for ArrayWindows2dMut<'_, T, HEIGHT, WIDTH>
+{
+    crate::check_covariance!();
+    #[inline]
+    fn next(&mut self) -> Option<Lend<'_, Self>> {
+        let offset = self.grid.next()?;
+        Some(self.window(offset))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.grid.len();
+        (len, Some(len))
+    }
+}
+
+impl<T, const HEIGHT: usize, const WIDTH: usize> DoubleEndedLender
+    for ArrayWindows2dMut<'_, T, HEIGHT, WIDTH>
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Lend<'_, Self>> {
+        let offset = self.grid.next_back()?;
+        Some(self.window(offset))
+    }
+}
+
+impl<T, const HEIGHT: usize, const WIDTH: usize> ExactSizeLender
+    for ArrayWindows2dMut<'_, T, HEIGHT, WIDTH>
+{
+}
+
+impl<T, const HEIGHT: usize, const WIDTH: usize> FusedLender
+    for ArrayWindows2dMut<'_, T, HEIGHT, WIDTH>
+{
+}
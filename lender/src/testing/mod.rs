@@ -0,0 +1,900 @@
+//! # Compliance Tests for Lender Implementations
+//!
+//! This module, available only if the `testing` feature is enabled, provides
+//! functions checking that an implementation of [`Lender`] or
+//! [`FallibleLender`] honors the contracts of the trait and of its
+//! specialized methods, such as [`nth`](Lender::nth),
+//! [`advance_by`](Lender::advance_by) or [`try_fold`](Lender::try_fold),
+//! which are often overridden for efficiency:
+//!
+//! - [`assert_lender_laws`] and [`assert_fallible_lender_laws`] check that
+//!   [`size_hint`](Lender::size_hint) bounds the number of remaining lends,
+//!   and that all consuming methods agree with [`next`](Lender::next);
+//!
+//! - [`assert_double_ended_laws`] and [`assert_fallible_double_ended_laws`]
+//!   also check that the methods of [`DoubleEndedLender`] and
+//!   [`DoubleEndedFallibleLender`] agree with the forward lends;
+//!
+//! - [`assert_exact_size_laws`] and [`assert_fallible_exact_size_laws`] also
+//!   check that [`len`](ExactSizeLender::len) is exact;
+//!
+//! - [`assert_fused_laws`] and [`assert_fallible_fused_laws`] also check that
+//!   the lender keeps returning `None` once exhausted.
+//!
+//! All functions take a closure returning a new lender each time it is
+//! called, as the laws are checked on many lenders that must lend the same
+//! sequence. Lends are compared through their [`Debug`] representation, as
+//! lends of different lenders have different lifetimes. Lenders must be
+//! finite, and all functions panic with a descriptive message when a law is
+//! violated.
+//!
+//! The fallible variants check the laws on the lends preceding the first
+//! error, and check that consuming methods return the error when they reach
+//! it. Laws involving the back of the lender, the length of the lender, or
+//! what happens after its end, are checked only if the lender does not
+//! return an error.
+//!
+//! The `proptest` submodule, available only if the `proptest` feature is
+//! enabled, provides strategies generating arbitrary lenders and functions
+//! checking lenders against iterators.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use lender::prelude::*;
+//! use lender::testing::*;
+//!
+//! assert_exact_size_laws(|| lender::from_iter(0..5).skip(1).rev());
+//! assert_double_ended_laws(|| lender::from_iter(0..7).step_by(3));
+//! assert_fused_laws(|| lender::from_iter(0..3).filter(|x| x % 2 == 0));
+//! ```
+
+use alloc::{format, string::String, vec::Vec};
+use core::{fmt::Debug, num::NonZeroUsize};
+
+use crate::{
+    DoubleEndedFallibleLender, DoubleEndedLender, ExactSizeFallibleLender, ExactSizeLender,
+    FallibleLend, FallibleLender, FusedFallibleLender, FusedLender, Lend, Lender,
+};
+
+#[cfg(feature = "proptest")]
+pub mod proptest;
+
+/// Returns the [`Debug`] representation of a value.
+fn repr(x: impl Debug) -> String {
+    format!("{x:?}")
+}
+
+/// Returns the representations of the remaining lends of a lender.
+fn lends<L>(lender: &mut L) -> Vec<String>
+where
+    L: Lender,
+    for<'all> Lend<'all, L>: Debug,
+{
+    let mut lends = Vec::new();
+    while let Some(x) = lender.next() {
+        lends.push(repr(x));
+    }
+    lends
+}
+
+/// Returns the representations of the remaining lends of a lender, in
+/// reverse order, obtained from its back.
+fn back_lends<L>(lender: &mut L) -> Vec<String>
+where
+    L: DoubleEndedLender,
+    for<'all> Lend<'all, L>: Debug,
+{
+    let mut lends = Vec::new();
+    while let Some(x) = lender.next_back() {
+        lends.push(repr(x));
+    }
+    lends.reverse();
+    lends
+}
+
+/// Checks that a [`Lender`] honors the contracts of [`Lender`].
+///
+/// The following laws are checked on the lenders returned by `make_lender`:
+///
+/// - [`size_hint`](Lender::size_hint) bounds the number of remaining lends
+///   before and after each call to [`next`](Lender::next);
+///
+/// - [`count`](Lender::count), [`nth`](Lender::nth),
+///   [`advance_by`](Lender::advance_by), [`fold`](Lender::fold) and
+///   [`try_fold`](Lender::try_fold) (with and without short-circuiting)
+///   agree with [`next`](Lender::next), and leave the lender in the same
+///   state as the same number of calls to [`next`](Lender::next).
+///
+/// See the [module documentation](self) for more details.
+///
+/// # Panics
+///
+/// Panics if a law is violated.
+///
+/// # Examples
+///
+/// ```rust
+/// # use lender::prelude::*;
+/// lender::testing::assert_lender_laws(|| {
+///     lender::from_iter(0..10).filter(|x| x % 3 == 0).skip(1)
+/// });
+/// ```
+pub fn assert_lender_laws<L, F>(mut make_lender: F)
+where
+    F: FnMut() -> L,
+    L: Lender,
+    for<'all> Lend<'all, L>: Debug,
+{
+    let expected = lends(&mut make_lender());
+    let len = expected.len();
+
+    let mut lender = make_lender();
+    for remaining in (0..=len).rev() {
+        let (lower, upper) = lender.size_hint();
+        assert!(
+            lower <= remaining && upper.is_none_or(|upper| remaining <= upper),
+            "size_hint() returned {:?} with {remaining} remaining lends",
+            (lower, upper),
+        );
+        let next = lender.next().map(repr);
+        assert_eq!(
+            next.as_ref(),
+            expected.get(len - remaining),
+            "next() returned different lends on different lenders"
+        );
+    }
+
+    assert_eq!(
+        make_lender().count(),
+        len,
+        "count() differs from the number of lends"
+    );
+
+    for n in 0..=len {
+        let mut lender = make_lender();
+        assert_eq!(
+            lender.nth(n).map(repr).as_ref(),
+            expected.get(n),
+            "nth({n}) differs from the lend in position {n}"
+        );
+        if n < len {
+            assert_eq!(
+                lends(&mut lender),
+                expected[n + 1..],
+                "lends after nth({n}) differ from lends after position {n}"
+            );
+        }
+    }
+
+    for n in 0..=len + 1 {
+        let mut lender = make_lender();
+        assert_eq!(
+            lender.advance_by(n),
+            NonZeroUsize::new(n.saturating_sub(len)).map_or(Ok(()), Err),
+            "advance_by({n}) returned a wrong result with {len} lends"
+        );
+        if n <= len {
+            assert_eq!(
+                lends(&mut lender),
+                expected[n..],
+                "lends after advance_by({n}) differ from lends from position {n}"
+            );
+        }
+    }
+
+    let folded = make_lender().fold(Vec::new(), |mut lends, x| {
+        lends.push(repr(x));
+        lends
+    });
+    assert_eq!(folded, expected, "fold() differs from next()");
+
+    for k in 0..=len {
+        let mut lender = make_lender();
+        let mut seen = Vec::new();
+        let result = lender.try_fold((), |(), x| {
+            seen.push(repr(x));
+            (seen.len() <= k).then_some(())
+        });
+        if k < len {
+            assert!(
+                result.is_none(),
+                "try_fold() did not short-circuit after {} lends",
+                k + 1
+            );
+            assert_eq!(
+                seen,
+                expected[..=k],
+                "try_fold() differs from next() up to short-circuiting"
+            );
+            assert_eq!(
+                lends(&mut lender),
+                expected[k + 1..],
+                "lends after short-circuiting try_fold() differ from lends after position {k}"
+            );
+        } else {
+            assert!(result.is_some(), "try_fold() short-circuited spuriously");
+            assert_eq!(seen, expected, "try_fold() differs from next()");
+        }
+    }
+}
+
+/// Checks that a [`DoubleEndedLender`] honors the contracts of [`Lender`]
+/// and of [`DoubleEndedLender`].
+///
+/// In addition to the laws checked by [`assert_lender_laws`], the following
+/// laws are checked on the lenders returned by `make_lender`:
+///
+/// - [`next_back`](DoubleEndedLender::next_back) returns the lends returned
+///   by [`next`](Lender::next) in reverse order, also when calls to
+///   [`next`](Lender::next) and [`next_back`](DoubleEndedLender::next_back)
+///   are mixed;
+///
+/// - [`nth_back`](DoubleEndedLender::nth_back),
+///   [`advance_back_by`](DoubleEndedLender::advance_back_by),
+///   [`rfold`](DoubleEndedLender::rfold) and
+///   [`try_rfold`](DoubleEndedLender::try_rfold) agree with
+///   [`next_back`](DoubleEndedLender::next_back).
+///
+/// # Panics
+///
+/// Panics if a law is violated.
+///
+/// # Examples
+///
+/// ```rust
+/// # use lender::prelude::*;
+/// lender::testing::assert_double_ended_laws(|| lender::from_iter(0..10).step_by(4));
+/// ```
+pub fn assert_double_ended_laws<L, F>(mut make_lender: F)
+where
+    F: FnMut() -> L,
+    L: DoubleEndedLender,
+    for<'all> Lend<'all, L>: Debug,
+{
+    assert_lender_laws(&mut make_lender);
+    let expected = lends(&mut make_lender());
+    let len = expected.len();
+
+    assert_eq!(
+        back_lends(&mut make_lender()),
+        expected,
+        "next_back() differs from next() in reverse order"
+    );
+
+    for split in 0..=len {
+        let mut lender = make_lender();
+        for (i, expected) in expected[..split].iter().enumerate() {
+            assert_eq!(
+                lender.next().map(repr).as_ref(),
+                Some(expected),
+                "next() after mixed calls differs from the lend in position {i}"
+            );
+        }
+        assert_eq!(
+            back_lends(&mut lender),
+            expected[split..],
+            "next_back() after {split} calls to next() differs from next()"
+        );
+    }
+
+    for n in 0..=len {
+        let mut lender = make_lender();
+        assert_eq!(
+            lender.nth_back(n).map(repr).as_ref(),
+            len.checked_sub(n + 1).map(|i| &expected[i]),
+            "nth_back({n}) differs from the lend in position {n} from the back"
+        );
+        if n < len {
+            assert_eq!(
+                back_lends(&mut lender),
+                expected[..len - n - 1],
+                "lends before nth_back({n}) differ from next()"
+            );
+        }
+    }
+
+    for n in 0..=len + 1 {
+        let mut lender = make_lender();
+        assert_eq!(
+            lender.advance_back_by(n),
+            NonZeroUsize::new(n.saturating_sub(len)).map_or(Ok(()), Err),
+            "advance_back_by({n}) returned a wrong result with {len} lends"
+        );
+        if n <= len {
+            assert_eq!(
+                back_lends(&mut lender),
+                expected[..len - n],
+                "lends before advance_back_by({n}) differ from next()"
+            );
+        }
+    }
+
+    let mut folded = make_lender().rfold(Vec::new(), |mut lends, x| {
+        lends.push(repr(x));
+        lends
+    });
+    folded.reverse();
+    assert_eq!(
+        folded, expected,
+        "rfold() differs from next() in reverse order"
+    );
+
+    for k in 0..=len {
+        let mut lender = make_lender();
+        let mut seen = Vec::new();
+        let result = lender.try_rfold((), |(), x| {
+            seen.push(repr(x));
+            (seen.len() <= k).then_some(())
+        });
+        seen.reverse();
+        if k < len {
+            assert!(
+                result.is_none(),
+                "try_rfold() did not short-circuit after {} lends",
+                k + 1
+            );
+            assert_eq!(
+                seen,
+                expected[len - k - 1..],
+                "try_rfold() differs from next_back() up to short-circuiting"
+            );
+            assert_eq!(
+                back_lends(&mut lender),
+                expected[..len - k - 1],
+                "lends before short-circuiting try_rfold() differ from next()"
+            );
+        } else {
+            assert!(result.is_some(), "try_rfold() short-circuited spuriously");
+            assert_eq!(seen, expected, "try_rfold() differs from next_back()");
+        }
+    }
+}
+
+/// Checks that an [`ExactSizeLender`] honors the contracts of [`Lender`] and
+/// of [`ExactSizeLender`].
+///
+/// In addition to the laws checked by [`assert_lender_laws`], the lenders
+/// returned by `make_lender` are checked to return from
+/// [`len`](ExactSizeLender::len) the exact number of remaining lends, and a
+/// consistent [`is_empty`](ExactSizeLender::is_empty) and exact
+/// [`size_hint`](Lender::size_hint), before and after each call to
+/// [`next`](Lender::next).
+///
+/// # Panics
+///
+/// Panics if a law is violated.
+///
+/// # Examples
+///
+/// ```rust
+/// # use lender::prelude::*;
+/// lender::testing::assert_exact_size_laws(|| lender::from_iter(0..10).take(3).enumerate());
+/// ```
+pub fn assert_exact_size_laws<L, F>(mut make_lender: F)
+where
+    F: FnMut() -> L,
+    L: ExactSizeLender,
+    for<'all> Lend<'all, L>: Debug,
+{
+    assert_lender_laws(&mut make_lender);
+    let len = make_lender().count();
+
+    let mut lender = make_lender();
+    for remaining in (0..=len).rev() {
+        assert_eq!(
+            lender.len(),
+            remaining,
+            "len() differs from the number of remaining lends"
+        );
+        assert_eq!(
+            lender.is_empty(),
+            remaining == 0,
+            "is_empty() is inconsistent with {remaining} remaining lends"
+        );
+        assert_eq!(
+            lender.size_hint(),
+            (remaining, Some(remaining)),
+            "size_hint() is not exact"
+        );
+        lender.next();
+    }
+}
+
+/// Checks that a [`FusedLender`] honors the contracts of [`Lender`] and of
+/// [`FusedLender`].
+///
+/// In addition to the laws checked by [`assert_lender_laws`], the lenders
+/// returned by `make_lender` are checked to keep returning `None` from
+/// [`next`](Lender::next) once exhausted, and to have a
+/// [`size_hint`](Lender::size_hint) bounding zero lends afterwards.
+///
+/// # Panics
+///
+/// Panics if a law is violated.
+///
+/// # Examples
+///
+/// ```rust
+/// # use lender::prelude::*;
+/// lender::testing::assert_fused_laws(|| lender::from_iter(0..10).take_while(|x| *x < 5).fuse());
+/// ```
+pub fn assert_fused_laws<L, F>(mut make_lender: F)
+where
+    F: FnMut() -> L,
+    L: FusedLender,
+    for<'all> Lend<'all, L>: Debug,
+{
+    assert_lender_laws(&mut make_lender);
+
+    let mut lender = make_lender();
+    lends(&mut lender);
+    for _ in 0..3 {
+        assert_eq!(
+            lender.next().map(repr),
+            None,
+            "next() returned a lend after returning None"
+        );
+        assert_eq!(
+            lender.size_hint().0,
+            0,
+            "size_hint() has a positive lower bound after next() returned None"
+        );
+    }
+}
+
+/// The remaining lends of a fallible lender, and the error that ended them,
+/// if any.
+#[derive(Debug, PartialEq, Eq)]
+struct FallibleLends {
+    lends: Vec<String>,
+    error: Option<String>,
+}
+
+impl FallibleLends {
+    /// Returns the lends from position `start`, followed by the error.
+    fn from(&self, start: usize) -> Self {
+        Self {
+            lends: self.lends[start..].to_vec(),
+            error: self.error.clone(),
+        }
+    }
+}
+
+/// Returns the representations of the remaining lends of a fallible lender,
+/// and of the error that ended them, if any.
+fn fallible_lends<L>(lender: &mut L) -> FallibleLends
+where
+    L: FallibleLender,
+    L::Error: Debug,
+    for<'all> FallibleLend<'all, L>: Debug,
+{
+    let mut lends = Vec::new();
+    loop {
+        match lender.next() {
+            Ok(Some(x)) => lends.push(repr(x)),
+            Ok(None) => return FallibleLends { lends, error: None },
+            Err(e) => {
+                return FallibleLends {
+                    lends,
+                    error: Some(repr(e)),
+                };
+            }
+        }
+    }
+}
+
+/// Returns the representations of the remaining lends of a fallible lender,
+/// in reverse order, obtained from its back, and of the error that ended
+/// them, if any.
+fn fallible_back_lends<L>(lender: &mut L) -> FallibleLends
+where
+    L: DoubleEndedFallibleLender,
+    L::Error: Debug,
+    for<'all> FallibleLend<'all, L>: Debug,
+{
+    let mut lends = Vec::new();
+    let error = loop {
+        match lender.next_back() {
+            Ok(Some(x)) => lends.push(repr(x)),
+            Ok(None) => break None,
+            Err(e) => break Some(repr(e)),
+        }
+    };
+    lends.reverse();
+    FallibleLends { lends, error }
+}
+
+/// Returns the representation of a result, mapping the error to its
+/// representation.
+fn repr_result<T, E: Debug>(result: Result<T, E>) -> Result<T, String> {
+    result.map_err(repr)
+}
+
+/// Checks that a [`FallibleLender`] honors the contracts of
+/// [`FallibleLender`].
+///
+/// This is the fallible version of [`assert_lender_laws`]. If the lenders
+/// returned by `make_lender` return an error, the laws are checked on the
+/// lends preceding the error, and consuming methods reaching the error are
+/// checked to return it. In this case, the lower bound of
+/// [`size_hint`](FallibleLender::size_hint) is not checked.
+///
+/// # Panics
+///
+/// Panics if a law is violated.
+///
+/// # Examples
+///
+/// ```rust
+/// # use lender::prelude::*;
+/// lender::testing::assert_fallible_lender_laws(|| {
+///     lender::from_iter(0..10)
+///         .into_fallible()
+///         .map_err(|e| match e {})
+///         .chain(lender::fallible_once_err::<fallible_lend!(i32), _>("error"))
+/// });
+/// ```
+pub fn assert_fallible_lender_laws<L, F>(mut make_lender: F)
+where
+    F: FnMut() -> L,
+    L: FallibleLender,
+    L::Error: Debug,
+    for<'all> FallibleLend<'all, L>: Debug,
+{
+    let expected = fallible_lends(&mut make_lender());
+    let len = expected.lends.len();
+    let error = expected.error.clone();
+
+    let mut lender = make_lender();
+    for remaining in (0..=len).rev() {
+        let (lower, upper) = lender.size_hint();
+        assert!(
+            (error.is_some() || lower <= remaining) && upper.is_none_or(|upper| remaining <= upper),
+            "size_hint() returned {:?} with {remaining} remaining lends",
+            (lower, upper),
+        );
+        let next = repr_result(lender.next()).map(|x| x.map(repr));
+        let expected_next = match expected.lends.get(len - remaining) {
+            Some(x) => Ok(Some(x.clone())),
+            None => error.clone().map_or(Ok(None), Err),
+        };
+        assert_eq!(
+            next, expected_next,
+            "next() returned different results on different lenders"
+        );
+    }
+
+    assert_eq!(
+        repr_result(make_lender().count()),
+        error.clone().map_or(Ok(len), Err),
+        "count() differs from the number of lends, or did not return the error"
+    );
+
+    for n in 0..=len {
+        let mut lender = make_lender();
+        let expected_nth = match expected.lends.get(n) {
+            Some(x) => Ok(Some(x.clone())),
+            None => error.clone().map_or(Ok(None), Err),
+        };
+        assert_eq!(
+            repr_result(lender.nth(n)).map(|x| x.map(repr)),
+            expected_nth,
+            "nth({n}) differs from the result in position {n}"
+        );
+        if n < len {
+            assert_eq!(
+                fallible_lends(&mut lender),
+                expected.from(n + 1),
+                "results after nth({n}) differ from results after position {n}"
+            );
+        }
+    }
+
+    for n in 0..=len + 1 {
+        let mut lender = make_lender();
+        let expected_advance = match error.clone() {
+            Some(e) if n > len => Err(e),
+            _ => Ok(NonZeroUsize::new(n.saturating_sub(len)).map_or(Ok(()), Err)),
+        };
+        assert_eq!(
+            repr_result(lender.advance_by(n)),
+            expected_advance,
+            "advance_by({n}) returned a wrong result with {len} lends"
+        );
+        if n <= len {
+            assert_eq!(
+                fallible_lends(&mut lender),
+                expected.from(n),
+                "results after advance_by({n}) differ from results from position {n}"
+            );
+        }
+    }
+
+    let mut folded = Vec::new();
+    let result = make_lender().fold((), |(), x| {
+        folded.push(repr(x));
+        Ok(())
+    });
+    assert_eq!(
+        FallibleLends {
+            lends: folded,
+            error: repr_result(result).err(),
+        },
+        expected,
+        "fold() differs from next()"
+    );
+
+    for k in 0..=len {
+        let mut lender = make_lender();
+        let mut seen = Vec::new();
+        let result = lender.try_fold((), |(), x| {
+            seen.push(repr(x));
+            Ok((seen.len() <= k).then_some(()))
+        });
+        if k < len {
+            assert_eq!(
+                repr_result(result),
+                Ok(None),
+                "try_fold() did not short-circuit after {} lends",
+                k + 1
+            );
+            assert_eq!(
+                seen,
+                expected.lends[..=k],
+                "try_fold() differs from next() up to short-circuiting"
+            );
+            assert_eq!(
+                fallible_lends(&mut lender),
+                expected.from(k + 1),
+                "results after short-circuiting try_fold() differ from results after position {k}"
+            );
+        } else {
+            assert_eq!(
+                repr_result(result),
+                error.clone().map_or(Ok(Some(())), Err),
+                "try_fold() short-circuited spuriously, or did not return the error"
+            );
+            assert_eq!(seen, expected.lends, "try_fold() differs from next()");
+        }
+    }
+}
+
+/// Checks that a [`DoubleEndedFallibleLender`] honors the contracts of
+/// [`FallibleLender`] and of [`DoubleEndedFallibleLender`].
+///
+/// This is the fallible version of [`assert_double_ended_laws`]. The laws
+/// specific to [`DoubleEndedFallibleLender`] are checked only if the lenders
+/// returned by `make_lender` do not return an error.
+///
+/// # Panics
+///
+/// Panics if a law is violated.
+///
+/// # Examples
+///
+/// ```rust
+/// # use lender::prelude::*;
+/// lender::testing::assert_fallible_double_ended_laws(|| {
+///     lender::from_iter(0..10).into_fallible().rev().skip(2)
+/// });
+/// ```
+pub fn assert_fallible_double_ended_laws<L, F>(mut make_lender: F)
+where
+    F: FnMut() -> L,
+    L: DoubleEndedFallibleLender,
+    L::Error: Debug,
+    for<'all> FallibleLend<'all, L>: Debug,
+{
+    assert_fallible_lender_laws(&mut make_lender);
+    let expected = fallible_lends(&mut make_lender());
+    if expected.error.is_some() {
+        return;
+    }
+    let expected = expected.lends;
+    let len = expected.len();
+
+    assert_eq!(
+        fallible_back_lends(&mut make_lender()).lends,
+        expected,
+        "next_back() differs from next() in reverse order"
+    );
+
+    for split in 0..=len {
+        let mut lender = make_lender();
+        for (i, expected) in expected[..split].iter().enumerate() {
+            assert_eq!(
+                repr_result(lender.next()).map(|x| x.map(repr)),
+                Ok(Some(expected.clone())),
+                "next() after mixed calls differs from the lend in position {i}"
+            );
+        }
+        assert_eq!(
+            fallible_back_lends(&mut lender),
+            FallibleLends {
+                lends: expected[split..].to_vec(),
+                error: None
+            },
+            "next_back() after {split} calls to next() differs from next()"
+        );
+    }
+
+    for n in 0..=len {
+        let mut lender = make_lender();
+        assert_eq!(
+            repr_result(lender.nth_back(n)).map(|x| x.map(repr)),
+            Ok(len.checked_sub(n + 1).map(|i| expected[i].clone())),
+            "nth_back({n}) differs from the lend in position {n} from the back"
+        );
+        if n < len {
+            assert_eq!(
+                fallible_back_lends(&mut lender).lends,
+                expected[..len - n - 1],
+                "lends before nth_back({n}) differ from next()"
+            );
+        }
+    }
+
+    for n in 0..=len + 1 {
+        let mut lender = make_lender();
+        assert_eq!(
+            repr_result(lender.advance_back_by(n)),
+            Ok(NonZeroUsize::new(n.saturating_sub(len)).map_or(Ok(()), Err)),
+            "advance_back_by({n}) returned a wrong result with {len} lends"
+        );
+        if n <= len {
+            assert_eq!(
+                fallible_back_lends(&mut lender).lends,
+                expected[..len - n],
+                "lends before advance_back_by({n}) differ from next()"
+            );
+        }
+    }
+
+    let mut folded = Vec::new();
+    let result = make_lender().rfold((), |(), x| {
+        folded.push(repr(x));
+        Ok(())
+    });
+    folded.reverse();
+    assert_eq!(repr_result(result), Ok(()), "rfold() returned an error");
+    assert_eq!(
+        folded, expected,
+        "rfold() differs from next() in reverse order"
+    );
+
+    for k in 0..=len {
+        let mut lender = make_lender();
+        let mut seen = Vec::new();
+        let result = lender.try_rfold((), |(), x| {
+            seen.push(repr(x));
+            Ok((seen.len() <= k).then_some(()))
+        });
+        seen.reverse();
+        if k < len {
+            assert_eq!(
+                repr_result(result),
+                Ok(None),
+                "try_rfold() did not short-circuit after {} lends",
+                k + 1
+            );
+            assert_eq!(
+                seen,
+                expected[len - k - 1..],
+                "try_rfold() differs from next_back() up to short-circuiting"
+            );
+            assert_eq!(
+                fallible_back_lends(&mut lender).lends,
+                expected[..len - k - 1],
+                "lends before short-circuiting try_rfold() differ from next()"
+            );
+        } else {
+            assert_eq!(
+                repr_result(result),
+                Ok(Some(())),
+                "try_rfold() short-circuited spuriously"
+            );
+            assert_eq!(seen, expected, "try_rfold() differs from next_back()");
+        }
+    }
+}
+
+/// Checks that an [`ExactSizeFallibleLender`] honors the contracts of
+/// [`FallibleLender`] and of [`ExactSizeFallibleLender`].
+///
+/// This is the fallible version of [`assert_exact_size_laws`]. The laws
+/// specific to [`ExactSizeFallibleLender`] are checked only if the lenders
+/// returned by `make_lender` do not return an error.
+///
+/// # Panics
+///
+/// Panics if a law is violated.
+///
+/// # Examples
+///
+/// ```rust
+/// # use lender::prelude::*;
+/// lender::testing::assert_fallible_exact_size_laws(|| {
+///     lender::from_iter(0..10).into_fallible().step_by(3)
+/// });
+/// ```
+pub fn assert_fallible_exact_size_laws<L, F>(mut make_lender: F)
+where
+    F: FnMut() -> L,
+    L: ExactSizeFallibleLender,
+    L::Error: Debug,
+    for<'all> FallibleLend<'all, L>: Debug,
+{
+    assert_fallible_lender_laws(&mut make_lender);
+    let expected = fallible_lends(&mut make_lender());
+    if expected.error.is_some() {
+        return;
+    }
+    let len = expected.lends.len();
+
+    let mut lender = make_lender();
+    for remaining in (0..=len).rev() {
+        assert_eq!(
+            lender.len(),
+            remaining,
+            "len() differs from the number of remaining lends"
+        );
+        assert_eq!(
+            lender.is_empty(),
+            remaining == 0,
+            "is_empty() is inconsistent with {remaining} remaining lends"
+        );
+        assert_eq!(
+            lender.size_hint(),
+            (remaining, Some(remaining)),
+            "size_hint() is not exact"
+        );
+        let _ = lender.next();
+    }
+}
+
+/// Checks that a [`FusedFallibleLender`] honors the contracts of
+/// [`FallibleLender`] and of [`FusedFallibleLender`].
+///
+/// This is the fallible version of [`assert_fused_laws`]. The laws specific
+/// to [`FusedFallibleLender`] are checked only if the lenders returned by
+/// `make_lender` do not return an error.
+///
+/// # Panics
+///
+/// Panics if a law is violated.
+///
+/// # Examples
+///
+/// ```rust
+/// # use lender::prelude::*;
+/// lender::testing::assert_fallible_fused_laws(|| {
+///     lender::from_iter(0..10).into_fallible().filter(|x| Ok(x % 2 == 0))
+/// });
+/// ```
+pub fn assert_fallible_fused_laws<L, F>(mut make_lender: F)
+where
+    F: FnMut() -> L,
+    L: FusedFallibleLender,
+    L::Error: Debug,
+    for<'all> FallibleLend<'all, L>: Debug,
+{
+    assert_fallible_lender_laws(&mut make_lender);
+
+    let mut lender = make_lender();
+    if fallible_lends(&mut lender).error.is_some() {
+        return;
+    }
+    for _ in 0..3 {
+        assert_eq!(
+            repr_result(lender.next()).map(|x| x.map(repr)),
+            Ok(None),
+            "next() returned a lend or an error after returning None"
+        );
+        assert_eq!(
+            lender.size_hint().0,
+            0,
+            "size_hint() has a positive lower bound after next() returned None"
+        );
+    }
+}
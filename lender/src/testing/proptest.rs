@@ -0,0 +1,433 @@
+//! # Property-Based Testing with Proptest
+//!
+//! This module, available only if the `proptest` feature is enabled, provides
+//! [`proptest`](https://docs.rs/proptest) strategies generating arbitrary
+//! lenders, which can be used as sources for the adapters under test, and
+//! functions checking a lender pipeline against the equivalent iterator
+//! pipeline on owned values:
+//!
+//! - [`ArbitraryLender`] is a double-ended lender lending references to the
+//!   elements of a vector, with a [`size_hint`](Lender::size_hint) that might
+//!   not be exact; it is generated by [`arbitrary_lender`] or by
+//!   [`any`](::proptest::prelude::any);
+//!
+//! - [`ArbitraryFallibleLender`] is a double-ended fallible lender that also
+//!   returns errors at arbitrary positions; it is generated by
+//!   [`arbitrary_fallible_lender`] or by [`any`](::proptest::prelude::any);
+//!
+//! - [`assert_lender_eq_iter`] and [`assert_fallible_lender_eq_iter`] check
+//!   that a (fallible) lender returns the same sequence as an iterator.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use lender::prelude::*;
+//! use lender::testing::proptest::*;
+//! use proptest::prelude::*;
+//!
+//! proptest! {
+//!     fn filter_skip(lender in arbitrary_lender(any::<i32>(), 0..32), n in 0..8_usize) {
+//!         let expected = lender.items().to_vec();
+//!         assert_lender_eq_iter(
+//!             lender.filter(|x| **x % 2 == 0).skip(n),
+//!             expected.into_iter().filter(|x| x % 2 == 0).skip(n),
+//!             |x| *x,
+//!         );
+//!     }
+//! }
+//! # filter_skip();
+//! ```
+
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+use ::proptest::{
+    arbitrary::Arbitrary,
+    collection::{SizeRange, vec},
+    option,
+    prelude::{BoxedStrategy, Strategy},
+    result::maybe_err_weighted,
+};
+
+use crate::{
+    DoubleEndedFallibleLender, DoubleEndedLender, FallibleLend, FallibleLender, FallibleLending,
+    FusedFallibleLender, FusedLender, Lend, Lender, Lending,
+};
+
+/// The maximum slack of the bounds returned by the `size_hint` method of
+/// arbitrary lenders.
+const MAX_SLACK: usize = 3;
+
+/// The probability that an element of an [`ArbitraryFallibleLender`] is an
+/// error.
+const ERROR_PROBABILITY: f64 = 0.1;
+
+/// The default range of lengths of arbitrary lenders generated by
+/// [`any`](::proptest::prelude::any).
+const DEFAULT_LEN: core::ops::Range<usize> = 0..32;
+
+/// Returns a size hint for `len` remaining elements with the given slack.
+fn slack_size_hint(
+    len: usize,
+    lower_slack: usize,
+    upper_slack: Option<usize>,
+) -> (usize, Option<usize>) {
+    (
+        len.saturating_sub(lower_slack),
+        upper_slack.map(|slack| len + slack),
+    )
+}
+
+/// A double-ended lender lending references to the elements of a vector,
+/// whose [`size_hint`](Lender::size_hint) is correct but might not be exact.
+///
+/// This `struct` is usually generated by the [`arbitrary_lender`] strategy,
+/// or by [`any`](::proptest::prelude::any).
+#[derive(Clone, Debug)]
+#[must_use = "lenders are lazy and do nothing unless consumed"]
+pub struct ArbitraryLender<T> {
+    items: Vec<T>,
+    front: usize,
+    back: usize,
+    lower_slack: usize,
+    upper_slack: Option<usize>,
+}
+
+impl<T> ArbitraryLender<T> {
+    /// Creates a new lender over the given elements, with an exact
+    /// [`size_hint`](Lender::size_hint).
+    #[inline]
+    pub fn new(items: Vec<T>) -> Self {
+        Self::with_slack(items, 0, Some(0))
+    }
+
+    /// Creates a new lender over the given elements, whose
+    /// [`size_hint`](Lender::size_hint) underestimates the number of
+    /// remaining elements by `lower_slack` (saturating at zero), and
+    /// overestimates it by `upper_slack`, where `None` means no upper bound.
+    #[inline]
+    pub fn with_slack(items: Vec<T>, lower_slack: usize, upper_slack: Option<usize>) -> Self {
+        let back = items.len();
+        Self {
+            items,
+            front: 0,
+            back,
+            lower_slack,
+            upper_slack,
+        }
+    }
+
+    /// Returns the elements that have not been lent yet.
+    #[inline]
+    pub fn items(&self) -> &[T] {
+        &self.items[self.front..self.back]
+    }
+}
+
+impl<'lend, T> Lending<'lend> for ArbitraryLender<T> {
+    type Lend = &'lend T;
+}
+
+impl<T> Lender for ArbitraryLender<T> {
+    crate::check_covariance!();
+    #[inline]
+    fn next(&mut self) -> Option<Lend<'_, Self>> {
+        if self.front == self.back {
+            return None;
+        }
+        self.front += 1;
+        Some(&self.items[self.front - 1])
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        slack_size_hint(self.back - self.front, self.lower_slack, self.upper_slack)
+    }
+}
+
+impl<T> DoubleEndedLender for ArbitraryLender<T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Lend<'_, Self>> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(&self.items[self.back])
+    }
+}
+
+impl<T> FusedLender for ArbitraryLender<T> {}
+
+/// Returns a strategy generating [`ArbitraryLender`]s whose elements are
+/// generated by `element`, whose length is in `size`, and whose
+/// [`size_hint`](Lender::size_hint) has an arbitrary slack.
+pub fn arbitrary_lender<T>(
+    element: T,
+    size: impl Into<SizeRange>,
+) -> impl Strategy<Value = ArbitraryLender<T::Value>>
+where
+    T: Strategy,
+{
+    (vec(element, size), 0..=MAX_SLACK, option::of(0..=MAX_SLACK)).prop_map(
+        |(items, lower_slack, upper_slack)| {
+            ArbitraryLender::with_slack(items, lower_slack, upper_slack)
+        },
+    )
+}
+
+impl<T: Arbitrary + 'static> Arbitrary for ArbitraryLender<T> {
+    type Parameters = T::Parameters;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        arbitrary_lender(::proptest::arbitrary::any_with::<T>(args), DEFAULT_LEN).boxed()
+    }
+}
+
+/// A double-ended fallible lender lending references to the elements of a
+/// vector of results, and returning their errors, whose
+/// [`size_hint`](FallibleLender::size_hint) is correct but might not be
+/// exact.
+///
+/// Errors do not end the lender: after returning an error, the lender
+/// continues with the next element.
+///
+/// This `struct` is usually generated by the [`arbitrary_fallible_lender`]
+/// strategy, or by [`any`](::proptest::prelude::any).
+#[derive(Clone, Debug)]
+#[must_use = "lenders are lazy and do nothing unless consumed"]
+pub struct ArbitraryFallibleLender<T, E> {
+    items: Vec<Result<T, E>>,
+    front: usize,
+    back: usize,
+    lower_slack: usize,
+    upper_slack: Option<usize>,
+}
+
+impl<T, E> ArbitraryFallibleLender<T, E> {
+    /// Creates a new fallible lender over the given results, with an exact
+    /// [`size_hint`](FallibleLender::size_hint).
+    #[inline]
+    pub fn new(items: Vec<Result<T, E>>) -> Self {
+        Self::with_slack(items, 0, Some(0))
+    }
+
+    /// Creates a new fallible lender over the given results, whose
+    /// [`size_hint`](FallibleLender::size_hint) underestimates the number of
+    /// remaining successful elements by `lower_slack` (saturating at zero),
+    /// and overestimates the number of remaining results by `upper_slack`,
+    /// where `None` means no upper bound.
+    #[inline]
+    pub fn with_slack(
+        items: Vec<Result<T, E>>,
+        lower_slack: usize,
+        upper_slack: Option<usize>,
+    ) -> Self {
+        let back = items.len();
+        Self {
+            items,
+            front: 0,
+            back,
+            lower_slack,
+            upper_slack,
+        }
+    }
+
+    /// Returns the results that have not been returned yet.
+    #[inline]
+    pub fn items(&self) -> &[Result<T, E>] {
+        &self.items[self.front..self.back]
+    }
+}
+
+impl<'lend, T, E> FallibleLending<'lend> for ArbitraryFallibleLender<T, E> {
+    type Lend = &'lend T;
+}
+
+impl<T, E: Clone> FallibleLender for ArbitraryFallibleLender<T, E> {
+    type Error = E;
+    crate::check_covariance_fallible!();
+
+    #[inline]
+    fn next(&mut self) -> Result<Option<FallibleLend<'_, Self>>, Self::Error> {
+        if self.front == self.back {
+            return Ok(None);
+        }
+        self.front += 1;
+        match &self.items[self.front - 1] {
+            Ok(x) => Ok(Some(x)),
+            Err(e) => Err(e.clone()),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let items = self.items();
+        let (lower, _) = slack_size_hint(
+            items.iter().filter(|x| x.is_ok()).count(),
+            self.lower_slack,
+            self.upper_slack,
+        );
+        let (_, upper) = slack_size_hint(items.len(), self.lower_slack, self.upper_slack);
+        (lower, upper)
+    }
+}
+
+impl<T, E: Clone> DoubleEndedFallibleLender for ArbitraryFallibleLender<T, E> {
+    #[inline]
+    fn next_back(&mut self) -> Result<Option<FallibleLend<'_, Self>>, Self::Error> {
+        if self.front == self.back {
+            return Ok(None);
+        }
+        self.back -= 1;
+        match &self.items[self.back] {
+            Ok(x) => Ok(Some(x)),
+            Err(e) => Err(e.clone()),
+        }
+    }
+}
+
+impl<T, E: Clone> FusedFallibleLender for ArbitraryFallibleLender<T, E> {}
+
+/// Returns a strategy generating [`ArbitraryFallibleLender`]s whose elements
+/// are generated by `element`, whose errors are generated by `error`, whose
+/// length is in `size`, and whose [`size_hint`](FallibleLender::size_hint)
+/// has an arbitrary slack.
+///
+/// Each result is an error with probability 0.1.
+pub fn arbitrary_fallible_lender<T, E>(
+    element: T,
+    error: E,
+    size: impl Into<SizeRange>,
+) -> impl Strategy<Value = ArbitraryFallibleLender<T::Value, E::Value>>
+where
+    T: Strategy,
+    E: Strategy,
+{
+    (
+        vec(maybe_err_weighted(ERROR_PROBABILITY, element, error), size),
+        0..=MAX_SLACK,
+        option::of(0..=MAX_SLACK),
+    )
+        .prop_map(|(items, lower_slack, upper_slack)| {
+            ArbitraryFallibleLender::with_slack(items, lower_slack, upper_slack)
+        })
+}
+
+impl<T, E> Arbitrary for ArbitraryFallibleLender<T, E>
+where
+    T: Arbitrary + 'static,
+    E: Arbitrary + 'static,
+{
+    type Parameters = (T::Parameters, E::Parameters);
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((t, e): Self::Parameters) -> Self::Strategy {
+        arbitrary_fallible_lender(
+            ::proptest::arbitrary::any_with::<T>(t),
+            ::proptest::arbitrary::any_with::<E>(e),
+            DEFAULT_LEN,
+        )
+        .boxed()
+    }
+}
+
+/// Checks that a lender returns the same sequence as an iterator, after
+/// converting its lends into owned values using `to_owned`.
+///
+/// The [`size_hint`](Lender::size_hint) of the lender is also checked to
+/// bound the number of remaining lends before each call to
+/// [`next`](Lender::next).
+///
+/// # Panics
+///
+/// Panics if the sequences differ, or if the size hint is not correct.
+///
+/// # Examples
+///
+/// ```rust
+/// # use lender::prelude::*;
+/// use lender::testing::proptest::*;
+///
+/// let lender = ArbitraryLender::new(vec![1, 2, 3, 4]);
+/// assert_lender_eq_iter(lender.rev().step_by(2), [4, 2], |x| *x);
+/// ```
+pub fn assert_lender_eq_iter<L, I, F>(mut lender: L, iter: I, mut to_owned: F)
+where
+    L: Lender,
+    I: IntoIterator,
+    I::Item: PartialEq + Debug,
+    F: FnMut(Lend<'_, L>) -> I::Item,
+{
+    let expected = iter.into_iter().collect::<Vec<_>>();
+    let len = expected.len();
+    for (i, expected) in expected.into_iter().enumerate() {
+        let (lower, upper) = lender.size_hint();
+        assert!(
+            lower <= len - i && upper.is_none_or(|upper| len - i <= upper),
+            "size_hint() returned {:?} with {} remaining lends",
+            (lower, upper),
+            len - i,
+        );
+        assert_eq!(
+            lender.next().map(&mut to_owned),
+            Some(expected),
+            "the lend in position {i} differs from the item of the iterator"
+        );
+    }
+    let (lower, _) = lender.size_hint();
+    assert_eq!(
+        lower, 0,
+        "size_hint() has a positive lower bound with no remaining lends"
+    );
+    assert!(
+        lender.next().map(&mut to_owned).is_none(),
+        "the lender has more than {len} lends"
+    );
+}
+
+/// Checks that a fallible lender returns the same sequence as an iterator on
+/// results, after converting its lends into owned values using `to_owned`.
+///
+/// The lender and the iterator are consumed until the lender returns
+/// `Ok(None)`, so errors are compared at all positions.
+///
+/// # Panics
+///
+/// Panics if the sequences differ.
+///
+/// # Examples
+///
+/// ```rust
+/// # use lender::prelude::*;
+/// use lender::testing::proptest::*;
+///
+/// let lender = ArbitraryFallibleLender::new(vec![Ok(1), Err("error"), Ok(3)]);
+/// assert_fallible_lender_eq_iter(
+///     lender.map_err(|e| e.len()),
+///     [Ok(1), Err(5), Ok(3)],
+///     |x| *x,
+/// );
+/// ```
+pub fn assert_fallible_lender_eq_iter<L, I, T, F>(mut lender: L, iter: I, mut to_owned: F)
+where
+    L: FallibleLender,
+    L::Error: PartialEq + Debug,
+    I: IntoIterator<Item = Result<T, L::Error>>,
+    T: PartialEq + Debug,
+    F: FnMut(FallibleLend<'_, L>) -> T,
+{
+    let mut iter = iter.into_iter();
+    for i in 0.. {
+        let next = lender.next().map(|x| x.map(&mut to_owned));
+        let done = matches!(next, Ok(None));
+        assert_eq!(
+            next,
+            iter.next().transpose(),
+            "the result in position {i} differs from the item of the iterator"
+        );
+        if done {
+            break;
+        }
+    }
+}
@@ -128,3 +128,88 @@ impl<I: FallibleIterator> FallibleIteratorRefExt<I> for I {
         crate::from_fallible_iter_ref(self)
     }
 }
+
+/// Extension trait adding to [`StreamingIterator`] the method
+/// [`into_lender`](StreamingIteratorExt::into_lender), which turns a
+/// [`StreamingIterator`] into a [`Lender`](crate::Lender) lending references
+/// to its items.
+///
+/// This trait is available only if the `streaming-iterator` feature is
+/// enabled.
+///
+/// [`StreamingIterator`]: streaming_iterator::StreamingIterator
+#[cfg(feature = "streaming-iterator")]
+pub trait StreamingIteratorExt<I: streaming_iterator::StreamingIterator> {
+    /// Turn this [`StreamingIterator`](streaming_iterator::StreamingIterator)
+    /// into a [`Lender`](crate::Lender) without allocation.
+    ///
+    /// This method is a convenient entry point for
+    /// [`from_streaming_iter`](crate::from_streaming_iter).
+    fn into_lender(self) -> crate::FromStreamingIter<I>;
+}
+
+#[cfg(feature = "streaming-iterator")]
+impl<I: streaming_iterator::StreamingIterator> StreamingIteratorExt<I> for I {
+    #[inline]
+    fn into_lender(self) -> crate::FromStreamingIter<I> {
+        crate::from_streaming_iter(self)
+    }
+}
+
+/// Extension trait adding to [`StreamingIteratorMut`] the method
+/// [`into_mut_lender`](StreamingIteratorMutExt::into_mut_lender), which turns
+/// a [`StreamingIteratorMut`] into a [`Lender`](crate::Lender) lending mutable
+/// references to its items.
+///
+/// This trait is available only if the `streaming-iterator` feature is
+/// enabled.
+///
+/// [`StreamingIteratorMut`]: streaming_iterator::StreamingIteratorMut
+#[cfg(feature = "streaming-iterator")]
+pub trait StreamingIteratorMutExt<I: streaming_iterator::StreamingIteratorMut> {
+    /// Turn this
+    /// [`StreamingIteratorMut`](streaming_iterator::StreamingIteratorMut)
+    /// into a [`Lender`](crate::Lender) without allocation.
+    ///
+    /// This method is a convenient entry point for
+    /// [`from_streaming_iter_mut`](crate::from_streaming_iter_mut).
+    fn into_mut_lender(self) -> crate::FromStreamingIterMut<I>;
+}
+
+#[cfg(feature = "streaming-iterator")]
+impl<I: streaming_iterator::StreamingIteratorMut> StreamingIteratorMutExt<I> for I {
+    #[inline]
+    fn into_mut_lender(self) -> crate::FromStreamingIterMut<I> {
+        crate::from_streaming_iter_mut(self)
+    }
+}
+
+/// Extension trait adding to [`FallibleStreamingIterator`] the method
+/// [`into_fallible_lender`](FallibleStreamingIteratorExt::into_fallible_lender),
+/// which turns a [`FallibleStreamingIterator`] into a
+/// [`FallibleLender`](crate::FallibleLender) lending references to its items.
+///
+/// This trait is available only if the `streaming-iterator` feature is
+/// enabled.
+///
+/// [`FallibleStreamingIterator`]: fallible_streaming_iterator::FallibleStreamingIterator
+#[cfg(feature = "streaming-iterator")]
+pub trait FallibleStreamingIteratorExt<I: fallible_streaming_iterator::FallibleStreamingIterator> {
+    /// Turn this
+    /// [`FallibleStreamingIterator`](fallible_streaming_iterator::FallibleStreamingIterator)
+    /// into a [`FallibleLender`](crate::FallibleLender) without allocation.
+    ///
+    /// This method is a convenient entry point for
+    /// [`from_fallible_streaming_iter`](crate::from_fallible_streaming_iter).
+    fn into_fallible_lender(self) -> crate::FromFallibleStreamingIter<I>;
+}
+
+#[cfg(feature = "streaming-iterator")]
+impl<I: fallible_streaming_iterator::FallibleStreamingIterator> FallibleStreamingIteratorExt<I>
+    for I
+{
+    #[inline]
+    fn into_fallible_lender(self) -> crate::FromFallibleStreamingIter<I> {
+        crate::from_fallible_streaming_iter(self)
+    }
+}
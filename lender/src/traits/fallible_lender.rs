@@ -6,16 +6,20 @@ use stable_try_trait_v2::{
 };
 
 use crate::{
-    Chain, Chunk, Chunky, Cloned, Copied, Covar, Cycle, DoubleEndedFallibleLender, Enumerate,
+    Batching, BoxedFallibleLender, Chain, Chunk, Chunky, Cloned, Copied, Covar,
+    CovariantFallibleLending, Cycle, DoubleEndedFallibleLender, Either, Enumerate,
     ExactSizeFallibleLender, ExtendLender, FallibleFlatMap, FallibleFlatten, FallibleIntersperse,
-    FallibleIntersperseWith, FalliblePeekable, FallibleTryShuntAdapter, Filter, FilterMap,
-    FirstShunt, FromFallibleIterRef, FromLender, Fuse, ImplBound, Inspect, Iter, Map, MapErr,
-    MapIntoIter, MapWhile, Mutate, NonFallibleAdapter, Owned, ProductFallibleLender, Ref, Rev,
-    Scan, SecondShunt, Skip, SkipWhile, StepBy, SumFallibleLender, Take, TakeWhile, TupleLend, Zip,
+    FallibleIntersperseWith, FalliblePeekable, FallibleSplitInclusive, FallibleTryShuntAdapter,
+    Filter, FilterMap, FirstShunt, FoldWhile, FromFallibleIterRef, FromLender, Fuse, ImplBound,
+    Inspect, Interleave, InterleaveShortest, IntoResults, Iter, Map, MapErr, MapIntoIter, MapWhile,
+    MinMaxResult, Mutate, NonFallibleAdapter, Owned, OwnedResults, ProductFallibleLender, Ref, Rev,
+    Scan, SecondShunt, Skip, SkipWhile, Split, StepBy, SumFallibleLender, Take, TakeWhile,
+    TupleLend, Zip,
+    boxed::DynFallibleLender,
     fallible_unzip,
     higher_order::{FnMutHKARes, FnMutHKAResOpt},
     non_fallible_adapter,
-    traits::collect::IntoFallibleLender,
+    traits::{collect::IntoFallibleLender, minmax_impl},
 };
 
 /// The fallible counterpart of [`Lending`](crate::Lending). See its documentation
@@ -394,6 +398,76 @@ pub trait FallibleLender: for<'all /* where Self: 'all */> FallibleLending<'all>
         Zip::new_fallible(self, other)
     }
 
+    /// Alternates the lends of this lender with those of another lender with
+    /// the same lend type and error, starting with `self`.
+    ///
+    /// When one of the two lenders is exhausted, the remaining lends of the
+    /// other one are returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fallible_iterator::IteratorExt as _;
+    /// # use lender::prelude::*;
+    /// let a = lender::lend_fallible_iter::<fallible_lend!(&'lend i32), _>(
+    ///     [1, 2, 3].iter().into_fallible(),
+    /// );
+    /// let b = lender::lend_fallible_iter::<fallible_lend!(&'lend i32), _>(
+    ///     [4].iter().into_fallible(),
+    /// );
+    /// let mut interleaved = a.interleave(b);
+    /// assert_eq!(interleaved.next(), Ok(Some(&1)));
+    /// assert_eq!(interleaved.next(), Ok(Some(&4)));
+    /// assert_eq!(interleaved.next(), Ok(Some(&2)));
+    /// assert_eq!(interleaved.next(), Ok(Some(&3)));
+    /// assert_eq!(interleaved.next(), Ok(None));
+    /// ```
+    #[inline]
+    fn interleave<U>(self, other: U) -> Interleave<Self, <U as IntoFallibleLender>::FallibleLender>
+    where
+        Self: Sized,
+        U: IntoFallibleLender<Error = Self::Error>
+            + for<'all> FallibleLending<'all, Lend = FallibleLend<'all, Self>>,
+    {
+        let other = other.into_fallible_lender();
+        Interleave::new_fallible(self, other)
+    }
+
+    /// Alternates the lends of this lender with those of another lender with
+    /// the same lend type and error, starting with `self`, and stops as soon
+    /// as the lender whose turn it is is exhausted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fallible_iterator::IteratorExt as _;
+    /// # use lender::prelude::*;
+    /// let a = lender::lend_fallible_iter::<fallible_lend!(&'lend i32), _>(
+    ///     [1, 2, 3].iter().into_fallible(),
+    /// );
+    /// let b = lender::lend_fallible_iter::<fallible_lend!(&'lend i32), _>(
+    ///     [4].iter().into_fallible(),
+    /// );
+    /// let mut interleaved = a.interleave_shortest(b);
+    /// assert_eq!(interleaved.next(), Ok(Some(&1)));
+    /// assert_eq!(interleaved.next(), Ok(Some(&4)));
+    /// assert_eq!(interleaved.next(), Ok(Some(&2)));
+    /// assert_eq!(interleaved.next(), Ok(None));
+    /// ```
+    #[inline]
+    fn interleave_shortest<U>(
+        self,
+        other: U,
+    ) -> InterleaveShortest<Self, <U as IntoFallibleLender>::FallibleLender>
+    where
+        Self: Sized,
+        U: IntoFallibleLender<Error = Self::Error>
+            + for<'all> FallibleLending<'all, Lend = FallibleLend<'all, Self>>,
+    {
+        let other = other.into_fallible_lender();
+        InterleaveShortest::new_fallible(self, other)
+    }
+
     /// Intersperses each lend of this lender with the given separator.
     ///
     /// # Examples
@@ -1025,6 +1099,96 @@ pub trait FallibleLender: for<'all /* where Self: 'all */> FallibleLending<'all>
         self
     }
 
+    /// Boxes this [`FallibleLender`] into a type-erased
+    /// [`BoxedFallibleLender`] whose lend type is specified by `L`, usually
+    /// using [`fallible_lend!`](crate::fallible_lend!).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use lender::prelude::*;
+    /// let data = [1, 2];
+    /// let mut lenders: Vec<lender::BoxedFallibleLender<fallible_lend!(&'lend i32), ()>> = vec![
+    ///     lender::lend_iter::<lend!(&'lend i32), _>(data.iter())
+    ///         .into_fallible()
+    ///         .map_err(|e| match e {})
+    ///         .boxed(),
+    ///     lender::fallible_once_err::<fallible_lend!(&'lend i32), _>(()).boxed(),
+    /// ];
+    /// assert_eq!(lenders[0].next(), Ok(Some(&1)));
+    /// assert_eq!(lenders[1].next(), Err(()));
+    /// ```
+    #[inline]
+    fn boxed<'a, L>(self) -> BoxedFallibleLender<'a, L, Self::Error>
+    where
+        Self: Sized + DynFallibleLender<L, Self::Error> + 'a,
+        L: ?Sized + CovariantFallibleLending,
+    {
+        BoxedFallibleLender::new(self)
+    }
+
+    /// Wraps this [`FallibleLender`] into the [`Left`](Either::Left) variant
+    /// of an [`Either`], so that it can be returned from a branch of a
+    /// conditional whose other branch uses
+    /// [`right_lender`](FallibleLender::right_lender).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use lender::prelude::*;
+    /// let data = [1, 2, 3];
+    /// let mut lender = if data.len() > 2 {
+    ///     lender::lend_iter::<lend!(&'lend i32), _>(data.iter())
+    ///         .into_fallible()
+    ///         .skip(1)
+    ///         .left_lender()
+    /// } else {
+    ///     lender::lend_iter::<lend!(&'lend i32), _>(data.iter())
+    ///         .into_fallible()
+    ///         .right_lender()
+    /// };
+    /// assert_eq!(lender.next(), Ok(Some(&2)));
+    /// ```
+    #[inline]
+    fn left_lender<R>(self) -> Either<Self, R>
+    where
+        Self: Sized,
+    {
+        crate::__check_fallible_lender_covariance::<Self>();
+        Either::Left(self)
+    }
+
+    /// Wraps this [`FallibleLender`] into the [`Right`](Either::Right)
+    /// variant of an [`Either`], so that it can be returned from a branch of
+    /// a conditional whose other branch uses
+    /// [`left_lender`](FallibleLender::left_lender).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use lender::prelude::*;
+    /// let data = [1, 2, 3];
+    /// let mut lender = if data.len() > 3 {
+    ///     lender::lend_iter::<lend!(&'lend i32), _>(data.iter())
+    ///         .into_fallible()
+    ///         .skip(1)
+    ///         .left_lender()
+    /// } else {
+    ///     lender::lend_iter::<lend!(&'lend i32), _>(data.iter())
+    ///         .into_fallible()
+    ///         .right_lender()
+    /// };
+    /// assert_eq!(lender.next(), Ok(Some(&1)));
+    /// ```
+    #[inline]
+    fn right_lender<L>(self) -> Either<L, Self>
+    where
+        Self: Sized,
+    {
+        crate::__check_fallible_lender_covariance::<Self>();
+        Either::Right(self)
+    }
+
     /// Transforms the fallible lender into a collection.
     /// If any invocation of next returns Err, returns the collection built
     /// from values yielded successfully, together with the error.
@@ -1237,6 +1401,101 @@ pub trait FallibleLender: for<'all /* where Self: 'all */> FallibleLending<'all>
             .map(|res| res.0)
     }
 
+    /// Folds the lends of this lender until the closure returns
+    /// [`FoldWhile::Done`].
+    ///
+    /// The result is [`FoldWhile::Done`] if the fold was stopped early, and
+    /// [`FoldWhile::Continue`] if the lender was exhausted. Since this method
+    /// takes `self` by reference, the fold can be resumed after it stopped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fallible_iterator::IteratorExt as _;
+    /// # use lender::prelude::*;
+    /// # use lender::FoldWhile;
+    /// let mut lender =
+    ///     lender::lend_fallible_iter::<fallible_lend!(&'lend i32), _>(
+    ///         [1, 2, 3, 4].iter().into_fallible(),
+    ///     );
+    /// let result = lender.fold_while(0, |acc, &x| {
+    ///     Ok(if acc + x > 5 {
+    ///         FoldWhile::Done(acc)
+    ///     } else {
+    ///         FoldWhile::Continue(acc + x)
+    ///     })
+    /// });
+    /// assert_eq!(result, Ok(FoldWhile::Done(3)));
+    /// assert_eq!(lender.next(), Ok(Some(&4)));
+    /// ```
+    #[inline]
+    fn fold_while<B, F>(&mut self, init: B, mut f: F) -> Result<FoldWhile<B>, Self::Error>
+    where
+        F: FnMut(B, FallibleLend<'_, Self>) -> Result<FoldWhile<B>, Self::Error>,
+    {
+        Ok(
+            match self.try_fold(init, |acc, x| {
+                Ok(match f(acc, x)? {
+                    FoldWhile::Continue(acc) => ControlFlow::Continue(acc),
+                    FoldWhile::Done(acc) => ControlFlow::Break(acc),
+                })
+            })? {
+                ControlFlow::Continue(acc) => FoldWhile::Continue(acc),
+                ControlFlow::Break(acc) => FoldWhile::Done(acc),
+            },
+        )
+    }
+
+    /// Folds the lends of this lender until the closure breaks, returning
+    /// the lend on which it broke.
+    ///
+    /// This is the [`FallibleLender`] version of
+    /// [`Lender::try_fold_lend`](crate::Lender::try_fold_lend).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use core::ops::ControlFlow;
+    /// # use fallible_iterator::IteratorExt as _;
+    /// # use lender::prelude::*;
+    /// let mut lender =
+    ///     lender::lend_fallible_iter::<fallible_lend!(&'lend i32), _>(
+    ///         [1, 2, 3, 4].iter().into_fallible(),
+    ///     );
+    /// let result = lender.try_fold_lend(0, |acc, &&x| {
+    ///     let acc = acc + x;
+    ///     Ok(if acc > 5 { ControlFlow::Break(()) } else { ControlFlow::Continue(acc) })
+    /// });
+    /// assert_eq!(result, Ok(ControlFlow::Break(&3)));
+    /// ```
+    #[inline]
+    fn try_fold_lend<B, F>(
+        &mut self,
+        init: B,
+        mut f: F,
+    ) -> Result<ControlFlow<FallibleLend<'_, Self>, B>, Self::Error>
+    where
+        Self: Sized,
+        F: FnMut(B, &FallibleLend<'_, Self>) -> Result<ControlFlow<(), B>, Self::Error>,
+    {
+        let mut acc = init;
+        while let Some(x) = self.next()? {
+            acc = match f(acc, &x)? {
+                ControlFlow::Break(()) => {
+                    // SAFETY: polonius return
+                    return Ok(ControlFlow::Break(unsafe {
+                        core::mem::transmute::<
+                            FallibleLend<'_, Self>,
+                            FallibleLend<'_, Self>
+                        >(x)
+                    }));
+                }
+                ControlFlow::Continue(acc) => acc,
+            };
+        }
+        Ok(ControlFlow::Continue(acc))
+    }
+
     /// The [`FallibleLender`] version of [`Iterator::reduce`].
     ///
     /// # Error Handling
@@ -1689,6 +1948,96 @@ pub trait FallibleLender: for<'all /* where Self: 'all */> FallibleLending<'all>
         ))
     }
 
+    /// Returns the minimum and maximum lends of this lender, converted to
+    /// owned values, in a single pass.
+    ///
+    /// This is the [`FallibleLender`] version of
+    /// [`Lender::minmax`](crate::Lender::minmax).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use lender::prelude::*;
+    /// # use lender::MinMaxResult;
+    /// let lender = [3, 1, 4, 1, 5].into_iter().into_lender().into_fallible();
+    /// assert_eq!(lender.minmax(), Ok(MinMaxResult::MinMax(1, 5)));
+    /// ```
+    #[inline]
+    fn minmax<T: Ord>(self) -> Result<MinMaxResult<T>, Self::Error>
+    where
+        Self: Sized,
+        for<'all> FallibleLend<'all, Self>: ToOwned<Owned = T>,
+    {
+        self.minmax_by(|x: &T, y: &T| Ok(x.cmp(y)))
+    }
+
+    /// Returns the minimum and maximum lends of this lender, converted to
+    /// owned values, with respect to the specified comparison function, in a
+    /// single pass.
+    ///
+    /// This is the [`FallibleLender`] version of
+    /// [`Lender::minmax_by`](crate::Lender::minmax_by).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use lender::prelude::*;
+    /// # use lender::MinMaxResult;
+    /// let lender =
+    ///     ["a", "bbb", "cc"].into_iter().into_lender().into_fallible();
+    /// assert_eq!(
+    ///     lender.minmax_by(|x, y| Ok(x.len().cmp(&y.len()))),
+    ///     Ok(MinMaxResult::MinMax("a", "bbb"))
+    /// );
+    /// ```
+    #[inline]
+    fn minmax_by<T, F>(self, mut compare: F) -> Result<MinMaxResult<T>, Self::Error>
+    where
+        Self: Sized,
+        for<'all> FallibleLend<'all, Self>: ToOwned<Owned = T>,
+        F: FnMut(&T, &T) -> Result<Ordering, Self::Error>,
+    {
+        let mut owned = self.owned();
+        minmax_impl(
+            || fallible_iterator::FallibleIterator::next(&mut owned),
+            |_| Ok(()),
+            |x, y, (), ()| Ok(compare(x, y)? == Ordering::Less),
+        )
+    }
+
+    /// Returns the lends of this lender with the minimum and maximum key,
+    /// converted to owned values, in a single pass.
+    ///
+    /// This is the [`FallibleLender`] version of
+    /// [`Lender::minmax_by_key`](crate::Lender::minmax_by_key).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use lender::prelude::*;
+    /// # use lender::MinMaxResult;
+    /// let lender =
+    ///     [-3, 0, 1, 5, -10].into_iter().into_lender().into_fallible();
+    /// assert_eq!(
+    ///     lender.minmax_by_key(|x: &i32| Ok(x.abs())),
+    ///     Ok(MinMaxResult::MinMax(0, -10))
+    /// );
+    /// ```
+    #[inline]
+    fn minmax_by_key<B: Ord, T, F>(self, f: F) -> Result<MinMaxResult<T>, Self::Error>
+    where
+        Self: Sized,
+        for<'all> FallibleLend<'all, Self>: ToOwned<Owned = T>,
+        F: FnMut(&T) -> Result<B, Self::Error>,
+    {
+        let mut owned = self.owned();
+        minmax_impl(
+            || fallible_iterator::FallibleIterator::next(&mut owned),
+            f,
+            |_, _, xk, yk| Ok(xk < yk),
+        )
+    }
+
     /// The [`FallibleLender`] version of [`Iterator::rev`].
     ///
     /// # Examples
@@ -2300,6 +2649,101 @@ pub trait FallibleLender: for<'all /* where Self: 'all */> FallibleLending<'all>
         Chunky::new_fallible(self, chunk_size)
     }
 
+    /// The [`FallibleLender`] version of [`Lender::batching`](crate::Lender::batching).
+    ///
+    /// At each iteration, the given function receives a mutable reference to
+    /// this lender and may consume any number of lends to produce the next
+    /// lend, which may borrow from this lender. Iteration ends when the
+    /// function returns `Ok(None)`.
+    ///
+    /// Note that functions passed to this method must be built using the
+    /// [`covar!`](crate::covar) or [`covar_mut!`](crate::covar_mut) macros, which also
+    /// check for covariance of the returned type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use lender::prelude::*;
+    /// # use core::convert::Infallible;
+    /// type L = lender::IntoFallible<lender::FromIter<core::array::IntoIter<usize, 6>>>;
+    /// let lender = [2, 10, 20, 0, 1, 30].into_iter().into_lender().into_fallible();
+    /// let mut frames = lender.batching(covar_mut!(
+    ///     for<'lend> |l: &'lend mut L| -> Result<Option<lender::Chunk<'lend, L>>, Infallible> {
+    ///         let Some(len) = l.next()? else { return Ok(None) };
+    ///         Ok(Some(l.next_chunk(len)))
+    ///     }
+    /// ));
+    /// assert_eq!(frames.next().unwrap().unwrap().fold(0, |acc, x| Ok(acc + x)), Ok(30));
+    /// assert_eq!(frames.next().unwrap().unwrap().count(), Ok(0));
+    /// assert_eq!(frames.next().unwrap().unwrap().fold(0, |acc, x| Ok(acc + x)), Ok(30));
+    /// assert!(frames.next().unwrap().is_none());
+    /// ```
+    #[inline]
+    fn batching<F>(self, f: Covar<F>) -> Batching<Self, F>
+    where
+        Self: Sized,
+        F: for<'all> FnMutHKAResOpt<'all, &'all mut Self, Self::Error>,
+    {
+        Batching::new_fallible(self, f)
+    }
+
+    /// The [`FallibleLender`] version of [`Lender::split`](crate::Lender::split).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use lender::prelude::*;
+    /// # use std::convert::Infallible;
+    /// # fn main() -> Result<(), Infallible> {
+    /// let mut lender = [1, 2, 0, 3].iter().into_lender().into_fallible();
+    /// let mut split = lender.split(|&x| Ok(*x == 0));
+    /// let mut part = split.next()?.unwrap();
+    /// assert_eq!(part.next()?, Some(&1));
+    /// assert_eq!(part.next()?, Some(&2));
+    /// assert_eq!(part.next()?, None);
+    /// assert_eq!(split.next()?.unwrap().next()?, Some(&3));
+    /// assert!(split.next()?.is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn split<P>(self, predicate: P) -> Split<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(&FallibleLend<'_, Self>) -> Result<bool, Self::Error>,
+    {
+        Split::new_fallible(self, predicate)
+    }
+
+    /// The [`FallibleLender`] version of
+    /// [`Lender::split_inclusive`](crate::Lender::split_inclusive).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use lender::prelude::*;
+    /// # use std::convert::Infallible;
+    /// # fn main() -> Result<(), Infallible> {
+    /// let mut lender = [1, 0, 2].iter().into_lender().into_fallible();
+    /// let mut split = lender.split_inclusive(|&x| Ok(*x == 0));
+    /// let mut part = split.next()?.unwrap();
+    /// assert_eq!(part.next()?, Some(&1));
+    /// assert_eq!(part.next()?, Some(&0));
+    /// assert_eq!(part.next()?, None);
+    /// assert_eq!(split.next()?.unwrap().next()?, Some(&2));
+    /// assert!(split.next()?.is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    fn split_inclusive<'call, P>(self, predicate: P) -> FallibleSplitInclusive<'call, Self, P>
+    where
+        Self: Sized,
+        P: FnMut(&FallibleLend<'_, Self>) -> Result<bool, Self::Error>,
+    {
+        FallibleSplitInclusive::new(self, predicate)
+    }
+
     /// Turns this [`FallibleLender`] into a
     /// [`FallibleIterator`](fallible_iterator::FallibleIterator) where it has
     /// already fulfilled the requirements of the
@@ -2328,6 +2772,34 @@ pub trait FallibleLender: for<'all /* where Self: 'all */> FallibleLending<'all>
         Iter::new(self)
     }
 
+    /// Turns this [`FallibleLender`], whose lend is a reference, into a
+    /// [`FallibleStreamingIterator`](fallible_streaming_iterator::FallibleStreamingIterator).
+    ///
+    /// This method is available only if the `streaming-iterator` feature is
+    /// enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use lender::prelude::*;
+    /// use fallible_streaming_iterator::FallibleStreamingIterator;
+    /// let mut iter = lender::from_iter_ref([1, 2, 3].into_iter())
+    ///     .into_fallible()
+    ///     .into_fallible_streaming_iter();
+    /// assert_eq!(iter.next(), Ok(Some(&1)));
+    /// assert_eq!(iter.get(), Some(&1));
+    /// assert_eq!(iter.count(), Ok(2));
+    /// ```
+    #[cfg(feature = "streaming-iterator")]
+    #[inline]
+    fn into_fallible_streaming_iter<'this, T>(self) -> crate::FallibleStreamingIter<'this, Self, T>
+    where
+        Self: Sized + for<'all> FallibleLending<'all, Lend = &'all T>,
+        T: ?Sized + 'this,
+    {
+        crate::FallibleStreamingIter::new(self)
+    }
+
     /// Turns this [`FallibleLender`] into a new
     /// [`FallibleLender`] that lends references to the items
     /// of the original lender.
@@ -2363,6 +2835,80 @@ pub trait FallibleLender: for<'all /* where Self: 'all */> FallibleLending<'all>
     {
         crate::from_fallible_iter_ref(self.iter())
     }
+
+    /// Turns this [`FallibleLender`] into a [`Lender`](crate::Lender) lending
+    /// the results of [`next`](FallibleLender::next), that is,
+    /// `Result<FallibleLend<'_, Self>, Self::Error>`.
+    ///
+    /// The resulting lender ends after lending the first error, and it is
+    /// double-ended if this lender is. The converse operation is
+    /// [`Lender::convert`](crate::Lender::convert).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use lender::prelude::*;
+    /// let mut lender = [Ok(1), Ok(2), Err("error"), Ok(4)]
+    ///     .into_iter()
+    ///     .into_lender()
+    ///     .convert::<&str>()
+    ///     .into_results();
+    /// assert_eq!(lender.next(), Some(Ok(1)));
+    /// assert_eq!(lender.next(), Some(Ok(2)));
+    /// assert_eq!(lender.next(), Some(Err("error")));
+    /// assert_eq!(lender.next(), None);
+    /// ```
+    #[inline]
+    fn into_results(self) -> IntoResults<Self>
+    where
+        Self: Sized,
+    {
+        IntoResults::new(self)
+    }
+
+    /// Turns this [`FallibleLender`] into an [`Iterator`] on
+    /// `Result<T, Self::Error>`, converting each lend into an owned value
+    /// using [`ToOwned`].
+    ///
+    /// The resulting iterator ends after returning the first error, and it
+    /// is double-ended if this lender is. In this way, the standard
+    /// combinators on iterators of results can be used; for example,
+    /// [`collect::<Result<Vec<_>, _>>()`](Iterator::collect) collects the
+    /// owned lends, or returns the first error.
+    ///
+    /// Since the error can be returned by any call, the lower bound of the
+    /// [size hint](Iterator::size_hint) is at most one, whatever the lower
+    /// bound of this lender; the upper bound counts the error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use lender::prelude::*;
+    /// let v: Result<Vec<i32>, _> = [1, 2, 3]
+    ///     .into_iter()
+    ///     .into_lender()
+    ///     .into_fallible()
+    ///     .owned_results()
+    ///     .collect();
+    /// assert_eq!(v, Ok(vec![1, 2, 3]));
+    ///
+    /// let v: Result<Vec<i32>, _> = [Ok(1), Err("error"), Ok(3)]
+    ///     .into_iter()
+    ///     .into_lender()
+    ///     .convert::<&str>()
+    ///     .owned_results()
+    ///     .rev()
+    ///     .collect();
+    /// assert_eq!(v, Err("error"));
+    /// ```
+    #[inline]
+    fn owned_results(self) -> OwnedResults<Self>
+    where
+        Self: Sized,
+        for<'all> FallibleLend<'all, Self>: ToOwned,
+    {
+        OwnedResults::new(self)
+    }
 }
 
 #[inline]
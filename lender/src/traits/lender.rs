@@ -1,13 +1,17 @@
 use alloc::borrow::ToOwned;
-use core::{cmp::Ordering, num::NonZeroUsize, ops::ControlFlow};
+use core::{cmp::Ordering, convert::Infallible, num::NonZeroUsize, ops::ControlFlow};
 
 use crate::{
-    Chain, Chunk, Chunky, Cloned, Convert, Copied, Covar, Cycle, DoubleEndedLender, Enumerate,
-    ExactSizeLender, ExtendLender, Filter, FilterMap, FirstShunt, FlatMap, Flatten, FromIterRef,
-    FromLender, Fuse, ImplBound, Inspect, Intersperse, IntersperseWith, IntoFallible, IntoLender,
-    Iter, Map, MapIntoIter, MapWhile, Mutate, Owned, Peekable, ProductLender, Ref, Rev, Scan,
-    SecondShunt, Skip, SkipWhile, StepBy, SumLender, Take, TakeWhile, TryShunt, TupleLend, Zip,
+    Batching, BoxedLender, Chain, Chunk, Chunky, Cloned, Convert, Copied, Covar, CovariantLending,
+    Cycle, DoubleEndedLender, Either, Enumerate, ExactSizeLender, ExtendLender, Filter, FilterMap,
+    FirstShunt, FlatMap, Flatten, FoldWhile, FromIterRef, FromLender, Fuse, ImplBound, Inspect,
+    Interleave, InterleaveShortest, Intersperse, IntersperseWith, IntoFallible, IntoLender, Iter,
+    Map, MapIntoIter, MapWhile, MinMaxResult, Mutate, Owned, Peekable, ProductLender, Ref, Rev,
+    Scan, SecondShunt, Skip, SkipWhile, Split, SplitInclusive, StepBy, SumLender, Take, TakeWhile,
+    TryShunt, TupleLend, Zip,
+    boxed::DynLender,
     higher_order::{FnMutHKA, FnMutHKAOpt},
+    traits::minmax_impl,
     try_process,
     try_trait_v2::{ChangeOutputType, FromResidual, Residual, Try, internal::NeverShortCircuit},
     unzip,
@@ -340,6 +344,62 @@ pub trait Lender: for<'all /* where Self: 'all */> Lending<'all> {
         let other = other.into_lender();
         Zip::new(self, other)
     }
+    /// Alternates the lends of this lender with those of another lender with
+    /// the same lend type, starting with `self`.
+    ///
+    /// When one of the two lenders is exhausted, the remaining lends of the
+    /// other one are returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use lender::prelude::*;
+    /// let mut lender = [1, 2, 3].iter().into_lender();
+    /// let mut other = [4].iter().into_lender();
+    /// let mut interleaved = lender.interleave(other);
+    /// assert_eq!(interleaved.next(), Some(&1));
+    /// assert_eq!(interleaved.next(), Some(&4));
+    /// assert_eq!(interleaved.next(), Some(&2));
+    /// assert_eq!(interleaved.next(), Some(&3));
+    /// assert_eq!(interleaved.next(), None);
+    /// ```
+    #[inline]
+    fn interleave<U>(self, other: U) -> Interleave<Self, <U as IntoLender>::Lender>
+    where
+        Self: Sized,
+        for<'all> U: IntoLender + Lending<'all, Lend = Lend<'all, Self>>,
+    {
+        let other = other.into_lender();
+        Interleave::new(self, other)
+    }
+    /// Alternates the lends of this lender with those of another lender with
+    /// the same lend type, starting with `self`, and stops as soon as the
+    /// lender whose turn it is is exhausted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use lender::prelude::*;
+    /// let mut lender = [1, 2, 3].iter().into_lender();
+    /// let mut other = [4].iter().into_lender();
+    /// let mut interleaved = lender.interleave_shortest(other);
+    /// assert_eq!(interleaved.next(), Some(&1));
+    /// assert_eq!(interleaved.next(), Some(&4));
+    /// assert_eq!(interleaved.next(), Some(&2));
+    /// assert_eq!(interleaved.next(), None);
+    /// ```
+    #[inline]
+    fn interleave_shortest<U>(
+        self,
+        other: U,
+    ) -> InterleaveShortest<Self, <U as IntoLender>::Lender>
+    where
+        Self: Sized,
+        for<'all> U: IntoLender + Lending<'all, Lend = Lend<'all, Self>>,
+    {
+        let other = other.into_lender();
+        InterleaveShortest::new(self, other)
+    }
     /// Intersperses each lend of this lender with the given separator.
     ///
     /// # Examples
@@ -848,6 +908,81 @@ pub trait Lender: for<'all /* where Self: 'all */> Lending<'all> {
     {
         self
     }
+    /// Boxes this [`Lender`] into a type-erased [`BoxedLender`] whose lend
+    /// type is specified by `L`, usually using [`lend!`](crate::lend!).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use lender::prelude::*;
+    /// let mut data = [1, 2, 3];
+    /// let mut lenders: Vec<lender::BoxedLender<lend!(&'lend mut [i32])>> = vec![
+    ///     lender::windows_mut(&mut data, 2).boxed(),
+    ///     lender::empty::<lend!(&'lend mut [i32])>().boxed(),
+    /// ];
+    /// assert_eq!(lenders[0].next(), Some(&mut [1, 2][..]));
+    /// assert_eq!(lenders[1].next(), None);
+    /// ```
+    #[inline]
+    fn boxed<'a, L>(self) -> BoxedLender<'a, L>
+    where
+        Self: Sized + DynLender<L> + 'a,
+        L: ?Sized + CovariantLending,
+    {
+        BoxedLender::new(self)
+    }
+    /// Wraps this [`Lender`] into the [`Left`](Either::Left) variant of an
+    /// [`Either`], so that it can be returned from a branch of a conditional
+    /// whose other branch uses [`right_lender`](Lender::right_lender).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use lender::prelude::*;
+    /// let mut data = [1, 2, 3, 4];
+    /// let mut lender = if data.len() > 3 {
+    ///     lender::windows_mut(&mut data, 2).step_by(2).left_lender()
+    /// } else {
+    ///     lender::windows_mut(&mut data, 2).right_lender()
+    /// };
+    /// assert_eq!(lender.next(), Some(&mut [1, 2][..]));
+    /// assert_eq!(lender.next(), Some(&mut [3, 4][..]));
+    /// assert_eq!(lender.next(), None);
+    /// ```
+    #[inline]
+    fn left_lender<R>(self) -> Either<Self, R>
+    where
+        Self: Sized,
+    {
+        crate::__check_lender_covariance::<Self>();
+        Either::Left(self)
+    }
+    /// Wraps this [`Lender`] into the [`Right`](Either::Right) variant of an
+    /// [`Either`], so that it can be returned from a branch of a conditional
+    /// whose other branch uses [`left_lender`](Lender::left_lender).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use lender::prelude::*;
+    /// let mut data = [1, 2, 3];
+    /// let mut lender = if data.len() > 3 {
+    ///     lender::windows_mut(&mut data, 2).step_by(2).left_lender()
+    /// } else {
+    ///     lender::windows_mut(&mut data, 2).right_lender()
+    /// };
+    /// assert_eq!(lender.next(), Some(&mut [1, 2][..]));
+    /// assert_eq!(lender.next(), Some(&mut [2, 3][..]));
+    /// assert_eq!(lender.next(), None);
+    /// ```
+    #[inline]
+    fn right_lender<L>(self) -> Either<L, Self>
+    where
+        Self: Sized,
+    {
+        crate::__check_lender_covariance::<Self>();
+        Either::Right(self)
+    }
     /// The [`Lender`] version of [`Iterator::collect`].
     ///
     /// # Examples
@@ -1007,6 +1142,93 @@ pub trait Lender: for<'all /* where Self: 'all */> Lending<'all> {
     {
         self.try_fold(init, |acc, x| NeverShortCircuit(f(acc, x))).0
     }
+    /// Folds the lends of this lender until the closure returns
+    /// [`FoldWhile::Done`].
+    ///
+    /// The result is [`FoldWhile::Done`] if the fold was stopped early, and
+    /// [`FoldWhile::Continue`] if the lender was exhausted. Since this method
+    /// takes `self` by reference, the fold can be resumed after it stopped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use lender::prelude::*;
+    /// # use lender::FoldWhile;
+    /// let mut lender = [1, 2, 3, 4].iter().into_lender();
+    /// let result = lender.fold_while(0, |acc, &x| {
+    ///     if acc + x > 5 {
+    ///         FoldWhile::Done(acc)
+    ///     } else {
+    ///         FoldWhile::Continue(acc + x)
+    ///     }
+    /// });
+    /// assert_eq!(result, FoldWhile::Done(3));
+    /// assert_eq!(lender.next(), Some(&4));
+    /// ```
+    #[inline]
+    fn fold_while<B, F>(&mut self, init: B, mut f: F) -> FoldWhile<B>
+    where
+        F: FnMut(B, Lend<'_, Self>) -> FoldWhile<B>,
+    {
+        match self.try_fold(init, |acc, x| match f(acc, x) {
+            FoldWhile::Continue(acc) => ControlFlow::Continue(acc),
+            FoldWhile::Done(acc) => ControlFlow::Break(acc),
+        }) {
+            ControlFlow::Continue(acc) => FoldWhile::Continue(acc),
+            ControlFlow::Break(acc) => FoldWhile::Done(acc),
+        }
+    }
+    /// Folds the lends of this lender until the closure breaks, returning
+    /// the lend on which it broke.
+    ///
+    /// Unlike [`try_fold()`](Lender::try_fold), whose residual cannot depend
+    /// on the lend, this method returns [`ControlFlow::Break`] with the
+    /// current lend when the closure returns [`ControlFlow::Break`], and
+    /// [`ControlFlow::Continue`] with the final accumulator if the lender is
+    /// exhausted. The closure receives a reference to the lend, like the
+    /// predicate of [`find()`](Lender::find); the accumulator is dropped when
+    /// the closure breaks.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use core::ops::ControlFlow;
+    /// # use lender::prelude::*;
+    /// let mut data = [1, 2, 3, 4];
+    /// let mut lender = lender::windows_mut(&mut data, 2);
+    /// // Find the first window whose running sum of first elements exceeds 2
+    /// match lender.try_fold_lend(0, |acc, w| {
+    ///     let acc = acc + w[0];
+    ///     if acc > 2 { ControlFlow::Break(()) } else { ControlFlow::Continue(acc) }
+    /// }) {
+    ///     ControlFlow::Break(w) => w[1] = 0,
+    ///     ControlFlow::Continue(_) => unreachable!(),
+    /// }
+    /// assert_eq!(data, [1, 2, 0, 4]);
+    /// ```
+    #[inline]
+    fn try_fold_lend<B, F>(&mut self, init: B, mut f: F) -> ControlFlow<Lend<'_, Self>, B>
+    where
+        Self: Sized,
+        F: FnMut(B, &Lend<'_, Self>) -> ControlFlow<(), B>,
+    {
+        let mut acc = init;
+        while let Some(x) = self.next() {
+            acc = match f(acc, &x) {
+                ControlFlow::Break(()) => {
+                    // SAFETY: polonius return
+                    return ControlFlow::Break(unsafe {
+                        core::mem::transmute::<
+                            Lend<'_, Self>,
+                            Lend<'_, Self>
+                        >(x)
+                    });
+                }
+                ControlFlow::Continue(acc) => acc,
+            };
+        }
+        ControlFlow::Continue(acc)
+    }
     /// The [`Lender`] version of [`Iterator::reduce`].
     ///
     /// # Examples
@@ -1399,6 +1621,102 @@ pub trait Lender: for<'all /* where Self: 'all */> Lending<'all> {
             }
         })
     }
+    /// Returns the minimum and maximum lends of this lender, converted to
+    /// owned values, in a single pass.
+    ///
+    /// Each lend is converted to an owned value exactly once, and about
+    /// 3/2 comparisons per lend are performed. As for [`min()`](Lender::min)
+    /// and [`max()`](Lender::max), the minimum is the first minimal lend and
+    /// the maximum is the last maximal lend.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use lender::prelude::*;
+    /// # use lender::MinMaxResult;
+    /// let lender = [3, 1, 4, 1, 5].into_iter().into_lender();
+    /// assert_eq!(lender.minmax(), MinMaxResult::MinMax(1, 5));
+    /// let lender = [3].into_iter().into_lender();
+    /// assert_eq!(lender.minmax(), MinMaxResult::OneElement(3));
+    /// ```
+    #[inline]
+    fn minmax<T: Ord>(self) -> MinMaxResult<T>
+    where
+        Self: Sized,
+        for<'all> Lend<'all, Self>: ToOwned<Owned = T>,
+    {
+        self.minmax_by(T::cmp)
+    }
+    /// Returns the minimum and maximum lends of this lender, converted to
+    /// owned values, with respect to the specified comparison function, in a
+    /// single pass.
+    ///
+    /// See [`minmax()`](Lender::minmax) for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use lender::prelude::*;
+    /// # use lender::MinMaxResult;
+    /// let lender = [-3, 0, 1, 5, -10].into_iter().into_lender();
+    /// assert_eq!(
+    ///     lender.minmax_by(|a: &i32, b: &i32| a.abs().cmp(&b.abs())),
+    ///     MinMaxResult::MinMax(0, -10)
+    /// );
+    /// ```
+    #[inline]
+    fn minmax_by<T, F>(self, mut compare: F) -> MinMaxResult<T>
+    where
+        Self: Sized,
+        for<'all> Lend<'all, Self>: ToOwned<Owned = T>,
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut owned = self.owned();
+        let result = minmax_impl(
+            || Ok::<_, Infallible>(owned.next()),
+            |_| Ok(()),
+            |x, y, (), ()| Ok(compare(x, y) == Ordering::Less),
+        );
+        match result {
+            Ok(result) => result,
+            Err(never) => match never {},
+        }
+    }
+    /// Returns the lends of this lender with the minimum and maximum key,
+    /// converted to owned values, in a single pass.
+    ///
+    /// The key of each lend is computed exactly once. See
+    /// [`minmax()`](Lender::minmax) for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use lender::prelude::*;
+    /// # use lender::MinMaxResult;
+    /// let lender = [-3, 0, 1, 5, -10].into_iter().into_lender();
+    /// assert_eq!(
+    ///     lender.minmax_by_key(|x: &i32| x.abs()),
+    ///     MinMaxResult::MinMax(0, -10)
+    /// );
+    /// ```
+    #[inline]
+    fn minmax_by_key<B: Ord, T, F>(self, mut f: F) -> MinMaxResult<T>
+    where
+        Self: Sized,
+        for<'all> Lend<'all, Self>: ToOwned<Owned = T>,
+        F: FnMut(&T) -> B,
+    {
+        let mut owned = self.owned();
+        let result = minmax_impl(
+            || Ok::<_, Infallible>(owned.next()),
+            |x| Ok(f(x)),
+            |_, _, xk, yk| Ok(xk < yk),
+        );
+        match result {
+            Ok(result) => result,
+            Err(never) => match never {},
+        }
+    }
     /// The [`Lender`] version of [`Iterator::rev`].
     ///
     /// # Examples
@@ -1866,6 +2184,137 @@ pub trait Lender: for<'all /* where Self: 'all */> Lending<'all> {
         Iter::new(self)
     }
 
+    /// Turns this [`Lender`], whose lend is a reference, into a
+    /// [`StreamingIterator`](streaming_iterator::StreamingIterator).
+    ///
+    /// This method is available only if the `streaming-iterator` feature is
+    /// enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use lender::prelude::*;
+    /// use streaming_iterator::StreamingIterator;
+    /// let mut iter = lender::from_iter_ref([1, 2, 3].into_iter()).into_streaming_iter();
+    /// assert_eq!(iter.next(), Some(&1));
+    /// assert_eq!(iter.get(), Some(&1));
+    /// assert_eq!(iter.count(), 2);
+    /// ```
+    #[cfg(feature = "streaming-iterator")]
+    #[inline]
+    fn into_streaming_iter<'this, T>(self) -> crate::StreamingIter<'this, Self, T>
+    where
+        Self: Sized + for<'all> Lending<'all, Lend = &'all T>,
+        T: ?Sized + 'this,
+    {
+        crate::StreamingIter::new(self)
+    }
+
+    /// Turns this [`Lender`] into a [`LendingIterator`](crate::gat::LendingIterator)
+    /// based on a generic associated type.
+    ///
+    /// This method is available only if the `gat` feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use lender::prelude::*;
+    /// use lender::gat::LendingIterator;
+    /// let mut data = [1, 2, 3];
+    /// let mut iter = lender::windows_mut(&mut data, 2).into_lending_iter();
+    /// assert_eq!(LendingIterator::next(&mut iter), Some(&mut [1, 2][..]));
+    /// assert_eq!(LendingIterator::size_hint(&iter), (1, Some(1)));
+    /// ```
+    #[cfg(feature = "gat")]
+    #[inline]
+    fn into_lending_iter(self) -> crate::gat::LendingIter<Self>
+    where
+        Self: Sized,
+    {
+        crate::gat::LendingIter::new(self)
+    }
+
+    /// Calls a closure in parallel on owned copies of the lends of this
+    /// [`Lender`], using [`rayon`].
+    ///
+    /// The lender is consumed on the calling thread, which converts lends
+    /// using [`ToOwned`] into batches of `batch_size` elements, and dispatches
+    /// each batch to the current [`rayon`] thread pool. The order in which
+    /// elements are processed is unspecified. To bound memory usage, at most
+    /// twice as many batches as threads in the pool are in flight at any time.
+    ///
+    /// As in the case of [`owned`](Lender::owned), the owned type must not
+    /// depend on the lifetime of the lend: lends that are references, such as
+    /// those of [`windows_mut`](crate::windows_mut), must be first mapped to
+    /// owned values, or copied with [`copied`](Lender::copied).
+    ///
+    /// This method is available only if the `rayon` feature is enabled.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `batch_size` is zero, or if `f` panics (after all batches
+    /// that have been dispatched have been processed).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use lender::prelude::*;
+    /// use std::sync::atomic::{AtomicI32, Ordering};
+    /// let sum = AtomicI32::new(0);
+    /// [1, 2, 3, 4].into_iter().into_lender().par_for_each_owned(2, |x: i32| {
+    ///     sum.fetch_add(x * x, Ordering::Relaxed);
+    /// });
+    /// assert_eq!(sum.into_inner(), 1 + 4 + 9 + 16);
+    /// ```
+    #[cfg(feature = "rayon")]
+    #[inline]
+    fn par_for_each_owned<T, F>(self, batch_size: usize, f: F)
+    where
+        Self: Sized,
+        for<'all> Lend<'all, Self>: ToOwned<Owned = T>,
+        T: Send,
+        F: Fn(T) + Sync,
+    {
+        crate::par::for_each_batch(self, batch_size, |_, batch| batch.into_iter().for_each(&f))
+    }
+
+    /// Maps in parallel owned copies of the lends of this [`Lender`] using
+    /// [`rayon`], and collects the results in the order of the lends.
+    ///
+    /// Lends are converted and dispatched in batches as in
+    /// [`par_for_each_owned`](Lender::par_for_each_owned); the results of each
+    /// batch are then reordered and collected into `B`.
+    ///
+    /// This method is available only if the `rayon` feature is enabled.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `batch_size` is zero, or if `f` panics.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use lender::prelude::*;
+    /// let mut data = [1, 2, 3, 4, 5];
+    /// let products: Vec<i32> = lender::windows_mut(&mut data, 2)
+    ///     .map(covar_mut!(for<'lend> |w: &'lend mut [i32]| -> [i32; 2] { [w[0], w[1]] }))
+    ///     .par_map_collect(2, |[a, b]: [i32; 2]| a * b);
+    /// assert_eq!(products, [2, 6, 12, 20]);
+    /// ```
+    #[cfg(feature = "rayon")]
+    #[inline]
+    fn par_map_collect<T, R, B, F>(self, batch_size: usize, f: F) -> B
+    where
+        Self: Sized,
+        for<'all> Lend<'all, Self>: ToOwned<Owned = T>,
+        T: Send,
+        R: Send,
+        B: FromIterator<R>,
+        F: Fn(T) -> R + Sync,
+    {
+        crate::par::map_collect(self, batch_size, f)
+    }
+
     /// Turns this [`Lender`] into a new [`Lender`] that lends
     /// references to the items of the original lender.
     ///
@@ -1922,6 +2371,117 @@ pub trait Lender: for<'all /* where Self: 'all */> Lending<'all> {
         Chunky::new(self, chunk_size)
     }
 
+    /// The [`Lender`] version of [`Itertools::batching`][1].
+    ///
+    /// At each iteration, the given function receives a mutable reference to
+    /// this lender and may consume any number of lends to produce the next
+    /// lend, which may borrow from this lender. Iteration ends when the
+    /// function returns [`None`]. This generalizes
+    /// [`next_chunk`](Lender::next_chunk) and [`chunky`](Lender::chunky) to
+    /// variable-length groups.
+    ///
+    /// Note that functions passed to this method must be built using the
+    /// [`covar!`](crate::covar) or [`covar_mut!`](crate::covar_mut) macros, which also
+    /// check for covariance of the returned type.
+    ///
+    /// [1]: https://docs.rs/itertools/latest/itertools/trait.Itertools.html#method.batching
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use lender::prelude::*;
+    /// // Each frame is a header giving the number of lends that follow.
+    /// type L = lender::FromIter<core::array::IntoIter<usize, 6>>;
+    /// let lender = [2, 10, 20, 0, 1, 30].into_iter().into_lender();
+    /// let mut frames = lender.batching(covar_mut!(
+    ///     for<'lend> |l: &'lend mut L| -> Option<lender::Chunk<'lend, L>> {
+    ///         let len = l.next()?;
+    ///         Some(l.next_chunk(len))
+    ///     }
+    /// ));
+    /// assert_eq!(frames.next().unwrap().fold(0, |acc, x| acc + x), 30);
+    /// assert_eq!(frames.next().unwrap().count(), 0);
+    /// assert_eq!(frames.next().unwrap().fold(0, |acc, x| acc + x), 30);
+    /// assert!(frames.next().is_none());
+    /// ```
+    #[inline]
+    fn batching<F>(self, f: Covar<F>) -> Batching<Self, F>
+    where
+        Self: Sized,
+        F: for<'all> FnMutHKAOpt<'all, &'all mut Self>,
+    {
+        Batching::new(self, f)
+    }
+
+    /// A lending version of [`slice::split`]: yields sub-lenders
+    /// ([`SplitPart`](crate::SplitPart)s) returning the lends between two
+    /// consecutive lends satisfying the given predicate. The separators are
+    /// not returned.
+    ///
+    /// As with [`slice::split`], `n` separators delimit `n + 1` sub-lenders,
+    /// some of which may be empty. Each sub-lender borrows this lender
+    /// mutably; if it is dropped before reaching its separator, the remaining
+    /// lends are skipped when the next sub-lender is requested.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use lender::prelude::*;
+    /// let mut lender = [1, 2, 0, 3, 0, 0].iter().into_lender();
+    /// let mut split = lender.split(|&x| *x == 0);
+    /// let mut part = split.next().unwrap();
+    /// assert_eq!(part.next(), Some(&1));
+    /// assert_eq!(part.next(), Some(&2));
+    /// assert_eq!(part.next(), None);
+    /// assert_eq!(split.next().unwrap().next(), Some(&3));
+    /// assert_eq!(split.next().unwrap().next(), None);
+    /// assert_eq!(split.next().unwrap().next(), None);
+    /// assert!(split.next().is_none());
+    /// ```
+    #[inline]
+    fn split<P>(self, predicate: P) -> Split<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(&Lend<'_, Self>) -> bool,
+    {
+        Split::new(self, predicate)
+    }
+
+    /// A lending version of [`slice::split_inclusive`]: yields sub-lenders
+    /// ([`SplitPart`](crate::SplitPart)s) returning lends up to and including
+    /// the next lend satisfying the given predicate.
+    ///
+    /// As with [`slice::split_inclusive`], if this lender ends with a
+    /// separator no empty sub-lender is yielded after it. Each sub-lender
+    /// borrows this lender mutably; if it is dropped before reaching its
+    /// separator, the remaining lends are skipped when the next sub-lender is
+    /// requested.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use lender::prelude::*;
+    /// let mut lender = [1, 0, 2, 3, 0].iter().into_lender();
+    /// let mut split = lender.split_inclusive(|&x| *x == 0);
+    /// let mut part = split.next().unwrap();
+    /// assert_eq!(part.next(), Some(&1));
+    /// assert_eq!(part.next(), Some(&0));
+    /// assert_eq!(part.next(), None);
+    /// let mut part = split.next().unwrap();
+    /// assert_eq!(part.next(), Some(&2));
+    /// // The rest of the part is skipped
+    /// drop(part);
+    /// assert!(split.next().is_none());
+    /// ```
+    #[inline]
+    fn split_inclusive<'call, P>(self, predicate: P) -> SplitInclusive<'call, Self, P>
+    where
+        Self: Sized,
+        P: FnMut(&Lend<'_, Self>) -> bool,
+    {
+        SplitInclusive::new(self, predicate)
+    }
+
     /// Converts a [`Lender`] whose lend type is `Result<T, E>` into a
     /// [`FallibleLender`](crate::FallibleLender) with error type `E` and lend type `T`.
     ///
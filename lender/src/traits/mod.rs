@@ -24,6 +24,9 @@ pub use self::{
     marker::{FusedFallibleLender, FusedLender},
 };
 
+#[cfg(feature = "streaming-iterator")]
+pub use self::ext::{FallibleStreamingIteratorExt, StreamingIteratorExt, StreamingIteratorMutExt};
+
 /// Trait for lend types that can be destructured into two components, used
 /// by [`Lender::unzip()`] and
 /// [`FallibleLender::unzip()`](crate::FallibleLender::unzip).
@@ -62,6 +65,133 @@ impl<'a, A, B> TupleLend<'a> for &'a mut (A, B) {
     }
 }
 
+/// The result of [`Lender::fold_while()`] and
+/// [`FallibleLender::fold_while()`](crate::FallibleLender::fold_while),
+/// telling whether the fold ran to completion or was stopped early.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FoldWhile<B> {
+    /// Continue folding with this value.
+    Continue(B),
+    /// Stop folding and return this value.
+    Done(B),
+}
+
+impl<B> FoldWhile<B> {
+    /// Returns the inner value.
+    #[inline]
+    pub fn into_inner(self) -> B {
+        match self {
+            FoldWhile::Continue(x) | FoldWhile::Done(x) => x,
+        }
+    }
+
+    /// Returns true if the fold was stopped early.
+    #[inline]
+    pub fn is_done(&self) -> bool {
+        matches!(self, FoldWhile::Done(_))
+    }
+}
+
+/// The result of [`Lender::minmax()`] and similar methods, holding the
+/// minimum and maximum of a lender in a single pass.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MinMaxResult<T> {
+    /// The lender was empty.
+    NoElements,
+    /// The lender had exactly one lend, which is both the minimum and the
+    /// maximum.
+    OneElement(T),
+    /// The lender had at least two lends; the first value is the minimum and
+    /// the second is the maximum.
+    MinMax(T, T),
+}
+
+impl<T: Clone> MinMaxResult<T> {
+    /// Returns the minimum and maximum as an [`Option`], cloning the
+    /// only element if there is exactly one.
+    #[inline]
+    pub fn into_option(self) -> Option<(T, T)> {
+        match self {
+            MinMaxResult::NoElements => None,
+            MinMaxResult::OneElement(x) => Some((x.clone(), x)),
+            MinMaxResult::MinMax(min, max) => Some((min, max)),
+        }
+    }
+}
+
+/// Computes the minimum and maximum of the values returned by `next` using
+/// about 3/2 comparisons per value, as in
+/// [`Itertools::minmax`](https://docs.rs/itertools/latest/itertools/trait.Itertools.html#method.minmax).
+///
+/// The key of each value is computed exactly once; `lt` receives two values
+/// and their keys. As for [`Iterator::min`] and [`Iterator::max`], the
+/// minimum is the first minimal value and the maximum is the last maximal
+/// value.
+pub(crate) fn minmax_impl<T, K, E, N, F, C>(
+    mut next: N,
+    mut key: F,
+    mut lt: C,
+) -> Result<MinMaxResult<T>, E>
+where
+    N: FnMut() -> Result<Option<T>, E>,
+    F: FnMut(&T) -> Result<K, E>,
+    C: FnMut(&T, &T, &K, &K) -> Result<bool, E>,
+{
+    let (mut min, mut max, mut min_key, mut max_key) = match next()? {
+        None => return Ok(MinMaxResult::NoElements),
+        Some(x) => match next()? {
+            None => return Ok(MinMaxResult::OneElement(x)),
+            Some(y) => {
+                let (xk, yk) = (key(&x)?, key(&y)?);
+                if lt(&y, &x, &yk, &xk)? {
+                    (y, x, yk, xk)
+                } else {
+                    (x, y, xk, yk)
+                }
+            }
+        },
+    };
+
+    loop {
+        // Values are processed in pairs: the smaller one is compared with
+        // the current minimum, and the larger one with the current maximum.
+        let Some(first) = next()? else {
+            break;
+        };
+        let first_key = key(&first)?;
+        let Some(second) = next()? else {
+            if lt(&first, &min, &first_key, &min_key)? {
+                min = first;
+            } else if !lt(&first, &max, &first_key, &max_key)? {
+                max = first;
+            }
+            break;
+        };
+        let second_key = key(&second)?;
+        if lt(&second, &first, &second_key, &first_key)? {
+            if lt(&second, &min, &second_key, &min_key)? {
+                min = second;
+                min_key = second_key;
+            }
+            if !lt(&first, &max, &first_key, &max_key)? {
+                max = first;
+                max_key = first_key;
+            }
+        } else {
+            if lt(&first, &min, &first_key, &min_key)? {
+                min = first;
+                min_key = first_key;
+            }
+            if !lt(&second, &max, &second_key, &max_key)? {
+                max = second;
+                max_key = second_key;
+            }
+        }
+    }
+
+    Ok(MinMaxResult::MinMax(min, max))
+}
+
 /// A zero-sized type covariant in `L` whose private field prevents construction
 /// outside this crate, making it impossible to implement
 /// [`Lender::__check_covariance`], [`CovariantLending::__check_covariance`],
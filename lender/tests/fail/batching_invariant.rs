@@ -0,0 +1,36 @@
+// Test that Batching with an invariant lend type fails covariance check.
+
+use std::cell::Cell;
+
+use lender::{FallibleLend, FallibleLender, FallibleLending, Lend, Lender, Lending};
+
+struct InvariantBatching<L, F>(L, F);
+
+impl<'lend, L, F> Lending<'lend> for InvariantBatching<L, F> {
+    type Lend = &'lend Cell<Option<&'lend String>>;
+}
+
+impl<L, F> Lender for InvariantBatching<L, F> {
+    lender::check_covariance!();
+
+    fn next(&mut self) -> Option<Lend<'_, Self>> {
+        None
+    }
+}
+
+struct InvariantFallibleBatching<L, F, E>(L, F, std::marker::PhantomData<E>);
+
+impl<'lend, L, F, E> FallibleLending<'lend> for InvariantFallibleBatching<L, F, E> {
+    type Lend = &'lend Cell<Option<&'lend String>>;
+}
+
+impl<L, F, E> FallibleLender for InvariantFallibleBatching<L, F, E> {
+    type Error = E;
+    lender::check_covariance_fallible!();
+
+    fn next(&mut self) -> Result<Option<FallibleLend<'_, Self>>, Self::Error> {
+        Ok(None)
+    }
+}
+
+fn main() {}
@@ -0,0 +1,36 @@
+// Test that Interleave with an invariant lend type fails covariance check.
+
+use std::cell::Cell;
+
+use lender::{FallibleLend, FallibleLender, FallibleLending, Lend, Lender, Lending};
+
+struct InvariantInterleave<L, F>(L, F);
+
+impl<'lend, L, F> Lending<'lend> for InvariantInterleave<L, F> {
+    type Lend = &'lend Cell<Option<&'lend String>>;
+}
+
+impl<L, F> Lender for InvariantInterleave<L, F> {
+    lender::check_covariance!();
+
+    fn next(&mut self) -> Option<Lend<'_, Self>> {
+        None
+    }
+}
+
+struct InvariantFallibleInterleave<L, F, E>(L, F, std::marker::PhantomData<E>);
+
+impl<'lend, L, F, E> FallibleLending<'lend> for InvariantFallibleInterleave<L, F, E> {
+    type Lend = &'lend Cell<Option<&'lend String>>;
+}
+
+impl<L, F, E> FallibleLender for InvariantFallibleInterleave<L, F, E> {
+    type Error = E;
+    lender::check_covariance_fallible!();
+
+    fn next(&mut self) -> Result<Option<FallibleLend<'_, Self>>, Self::Error> {
+        Ok(None)
+    }
+}
+
+fn main() {}
@@ -1,4 +1,4 @@
-//! Tests for chunking adapters: Peekable, Chunk, Chunky
+//! Tests for chunking adapters: Peekable, Chunk, Chunky, Batching, Split, SplitInclusive
 
 #![allow(clippy::unnecessary_fold)]
 
@@ -429,3 +429,181 @@ fn test_chunky_into_inner() {
 fn test_chunky_zero_panics() {
     let _ = VecLender::new(vec![1, 2, 3]).chunky(0);
 }
+
+// ============================================================================
+// Batching adapter tests (Lender)
+// Semantics: the closure receives &mut L and may consume any number of lends
+// to produce each lend, which can borrow from the underlying lender.
+// ============================================================================
+
+#[test]
+fn test_batching_variable_length_frames() {
+    // Each frame is a header giving the number of lends that follow.
+    let mut frames = VecLender::new(vec![2, 1, 2, 0, 3, 4, 5, 6]).batching(covar_mut!(
+        for<'lend> |l: &'lend mut VecLender| -> Option<lender::Chunk<'lend, VecLender>> {
+            let len = *l.next()? as usize;
+            Some(l.next_chunk(len))
+        }
+    ));
+    let mut frame = frames.next().unwrap();
+    assert_eq!(frame.next(), Some(&1));
+    assert_eq!(frame.next(), Some(&2));
+    assert_eq!(frame.next(), None);
+    assert_eq!(frames.next().unwrap().count(), 0);
+    assert_eq!(frames.next().unwrap().fold(0, |acc, x| acc + x), 15);
+    assert!(frames.next().is_none());
+}
+
+#[test]
+fn test_batching_pairs() {
+    let mut pairs = VecLender::new(vec![1, 2, 3, 4, 5]).batching(covar_mut!(
+        for<'lend> |l: &'lend mut VecLender| -> Option<(i32, i32)> {
+            let a = *l.next()?;
+            let b = *l.next()?;
+            Some((a, b))
+        }
+    ));
+    assert_eq!(pairs.next(), Some((1, 2)));
+    assert_eq!(pairs.next(), Some((3, 4)));
+    // The trailing odd lend is consumed but produces no pair
+    assert_eq!(pairs.next(), None);
+    assert_eq!(pairs.size_hint(), (0, None));
+}
+
+#[test]
+fn test_batching_consumes_nothing() {
+    // The closure may return lends without touching the underlying lender
+    let mut count = 0;
+    let batching = VecLender::new(vec![1, 2, 3]).batching(covar_mut!(
+        for<'lend> |_l: &'lend mut VecLender| -> Option<i32> {
+            count += 1;
+            (count <= 2).then_some(count)
+        }
+    ));
+    let lender = batching.into_inner();
+    assert_eq!(lender.count(), 3);
+}
+
+#[test]
+fn test_batching_into_parts() {
+    let batching = VecLender::new(vec![1, 2, 3]).batching(covar_mut!(
+        for<'lend> |l: &'lend mut VecLender| -> Option<&'lend i32> { l.next() }
+    ));
+    let (mut lender, _f) = batching.into_parts();
+    assert_eq!(lender.next(), Some(&1));
+}
+
+// ============================================================================
+// Split adapter tests (Lender)
+// Semantics: like slice::split, n separators delimit n + 1 possibly empty
+// parts; a partially consumed part is skipped when the next one is requested.
+// ============================================================================
+
+// Collects the parts of a split lender of &i32 into vectors.
+macro_rules! collect_parts {
+    ($split:expr) => {{
+        let mut split = $split;
+        let mut parts = Vec::new();
+        while let Some(mut part) = split.next() {
+            let mut v = Vec::new();
+            while let Some(&x) = part.next() {
+                v.push(x);
+            }
+            parts.push(v);
+        }
+        parts
+    }};
+}
+
+#[test]
+fn test_split_basic() {
+    let split = VecLender::new(vec![1, 2, 0, 3, 0, 4]).split(|&&x| x == 0);
+    assert_eq!(collect_parts!(split), vec![vec![1, 2], vec![3], vec![4]]);
+}
+
+#[test]
+fn test_split_matches_slice_split() {
+    let cases: [&[i32]; 6] = [&[], &[0], &[0, 0], &[1, 0], &[0, 1], &[1, 0, 0, 2]];
+    for case in cases {
+        let expected: Vec<Vec<i32>> = case.split(|&x| x == 0).map(<[i32]>::to_vec).collect();
+        let split = VecLender::new(case.to_vec()).split(|&&x| x == 0);
+        assert_eq!(collect_parts!(split), expected, "input: {case:?}");
+    }
+}
+
+#[test]
+fn test_split_partial_part_skipped() {
+    let mut split = VecLender::new(vec![1, 2, 3, 0, 4, 5, 0, 6]).split(|&&x| x == 0);
+    let mut part = split.next().unwrap();
+    assert_eq!(part.next(), Some(&1));
+    drop(part);
+    // Never touched at all
+    let _ = split.next().unwrap();
+    let mut part = split.next().unwrap();
+    assert_eq!(part.next(), Some(&6));
+    assert_eq!(part.next(), None);
+    assert!(split.next().is_none());
+    assert!(split.next().is_none());
+}
+
+#[test]
+fn test_split_size_hint() {
+    let mut split = VecLender::new(vec![1, 0, 2]).split(|&&x| x == 0);
+    assert_eq!(split.size_hint(), (1, Some(4)));
+    let part = split.next().unwrap();
+    assert_eq!(part.size_hint(), (0, Some(3)));
+    assert_eq!(part.count(), 1);
+    assert_eq!(split.size_hint(), (1, Some(2)));
+    assert_eq!(split.next().unwrap().count(), 1);
+    assert_eq!(split.size_hint(), (0, Some(0)));
+}
+
+#[test]
+fn test_split_into_inner() {
+    let mut split = VecLender::new(vec![1, 0, 2]).split(|&&x| x == 0);
+    assert_eq!(split.next().unwrap().count(), 1);
+    let mut lender = split.into_inner();
+    assert_eq!(lender.next(), Some(&2));
+}
+
+#[test]
+fn test_split_inclusive_basic() {
+    let split = VecLender::new(vec![1, 2, 0, 3, 0, 4]).split_inclusive(|&&x| x == 0);
+    assert_eq!(
+        collect_parts!(split),
+        vec![vec![1, 2, 0], vec![3, 0], vec![4]]
+    );
+}
+
+#[test]
+fn test_split_inclusive_matches_slice_split_inclusive() {
+    let cases: [&[i32]; 6] = [&[], &[0], &[0, 0], &[1, 0], &[0, 1], &[1, 0, 0, 2]];
+    for case in cases {
+        let expected: Vec<Vec<i32>> = case
+            .split_inclusive(|&x| x == 0)
+            .map(<[i32]>::to_vec)
+            .collect();
+        let split = VecLender::new(case.to_vec()).split_inclusive(|&&x| x == 0);
+        assert_eq!(collect_parts!(split), expected, "input: {case:?}");
+    }
+}
+
+#[test]
+fn test_split_inclusive_partial_part_skipped() {
+    let mut split = VecLender::new(vec![1, 2, 0, 3, 4]).split_inclusive(|&&x| x == 0);
+    assert_eq!(split.next().unwrap().next(), Some(&1));
+    let mut part = split.next().unwrap();
+    assert_eq!(part.size_hint(), (1, Some(2)));
+    assert_eq!(part.next(), Some(&3));
+    assert_eq!(part.next(), Some(&4));
+    assert_eq!(part.next(), None);
+    assert!(split.next().is_none());
+}
+
+#[test]
+fn test_split_inclusive_into_parts() {
+    let split = VecLender::new(vec![1, 0, 2]).split_inclusive(|&&x| x == 0);
+    let (lender, mut predicate) = split.into_parts();
+    assert!(predicate(&&0));
+    assert_eq!(lender.count(), 3);
+}
@@ -1,4 +1,4 @@
-//! Tests for combination adapters: Zip, Intersperse, Flatten, FlatMap
+//! Tests for combination adapters: Zip, Interleave, InterleaveShortest, Intersperse, Flatten, FlatMap
 
 #![allow(clippy::unnecessary_fold)]
 
@@ -99,6 +99,172 @@ fn test_zip_rfold() {
     assert_eq!(values, vec![(3, 30), (2, 20), (1, 10)]);
 }
 
+// ============================================================================
+// Interleave adapter tests
+// ============================================================================
+
+#[test]
+fn test_interleave_basic() {
+    let mut interleaved =
+        VecLender::new(vec![1, 2, 3]).interleave(VecLender::new(vec![10, 20, 30]));
+    assert_eq!(interleaved.len(), 6);
+    assert_eq!(interleaved.next(), Some(&1));
+    assert_eq!(interleaved.next(), Some(&10));
+    assert_eq!(interleaved.len(), 4);
+    assert_eq!(interleaved.next(), Some(&2));
+    assert_eq!(interleaved.next(), Some(&20));
+    assert_eq!(interleaved.next(), Some(&3));
+    assert_eq!(interleaved.next(), Some(&30));
+    assert_eq!(interleaved.next(), None);
+    assert_eq!(interleaved.next(), None);
+}
+
+#[test]
+fn test_interleave_different_lengths() {
+    // The remaining lends of the longer lender are returned
+    let mut items = Vec::new();
+    VecLender::new(vec![1, 2, 3, 4])
+        .interleave(VecLender::new(vec![10]))
+        .for_each(|x| items.push(*x));
+    assert_eq!(items, vec![1, 10, 2, 3, 4]);
+
+    let mut items = Vec::new();
+    VecLender::new(vec![1])
+        .interleave(VecLender::new(vec![10, 20, 30]))
+        .for_each(|x| items.push(*x));
+    assert_eq!(items, vec![1, 10, 20, 30]);
+}
+
+#[test]
+fn test_interleave_empty() {
+    let mut interleaved = VecLender::new(vec![]).interleave(VecLender::new(vec![10, 20]));
+    assert_eq!(interleaved.size_hint(), (2, Some(2)));
+    assert_eq!(interleaved.next(), Some(&10));
+    assert_eq!(interleaved.next(), Some(&20));
+    assert_eq!(interleaved.next(), None);
+    assert_eq!(interleaved.size_hint(), (0, Some(0)));
+}
+
+#[test]
+fn test_interleave_fused() {
+    // Interleave fuses both inner lenders, so it is fused even when they are not
+    fn assert_fused<L: FusedLender>(_: &L) {}
+    let mut a = [1, 2];
+    let mut b = [10];
+    let mut interleaved = WindowsMut {
+        slice: &mut a,
+        begin: 0,
+        len: 1,
+    }
+    .interleave(WindowsMut {
+        slice: &mut b,
+        begin: 0,
+        len: 1,
+    });
+    assert_fused(&interleaved);
+    assert_eq!(interleaved.next(), Some(&mut [1][..]));
+    assert_eq!(interleaved.next(), Some(&mut [10][..]));
+    assert_eq!(interleaved.next(), Some(&mut [2][..]));
+    assert_eq!(interleaved.next(), None);
+    assert_eq!(interleaved.next(), None);
+}
+
+#[test]
+fn test_interleave_matches_len() {
+    for (a, b) in [(0, 0), (0, 3), (3, 0), (2, 5), (5, 2), (4, 4)] {
+        let mut interleaved =
+            VecLender::new((0..a).collect()).interleave(VecLender::new((100..100 + b).collect()));
+        let mut count = 0;
+        loop {
+            assert_eq!(interleaved.len(), (a + b) as usize - count);
+            if interleaved.next().is_none() {
+                break;
+            }
+            count += 1;
+        }
+        assert_eq!(count, (a + b) as usize);
+    }
+}
+
+#[test]
+fn test_interleave_shortest_basic() {
+    let mut interleaved =
+        VecLender::new(vec![1, 2, 3]).interleave_shortest(VecLender::new(vec![10, 20, 30]));
+    assert_eq!(interleaved.len(), 6);
+    assert_eq!(interleaved.next(), Some(&1));
+    assert_eq!(interleaved.next(), Some(&10));
+    assert_eq!(interleaved.next(), Some(&2));
+    assert_eq!(interleaved.next(), Some(&20));
+    assert_eq!(interleaved.next(), Some(&3));
+    assert_eq!(interleaved.next(), Some(&30));
+    assert_eq!(interleaved.next(), None);
+}
+
+#[test]
+fn test_interleave_shortest_different_lengths() {
+    // Stops when the lender whose turn it is is exhausted
+    let mut items = Vec::new();
+    VecLender::new(vec![1, 2, 3, 4])
+        .interleave_shortest(VecLender::new(vec![10]))
+        .for_each(|x| items.push(*x));
+    assert_eq!(items, vec![1, 10, 2]);
+
+    let mut items = Vec::new();
+    VecLender::new(vec![1])
+        .interleave_shortest(VecLender::new(vec![10, 20, 30]))
+        .for_each(|x| items.push(*x));
+    assert_eq!(items, vec![1, 10]);
+
+    let mut items = Vec::new();
+    VecLender::new(vec![])
+        .interleave_shortest(VecLender::new(vec![10, 20, 30]))
+        .for_each(|x| items.push(*x));
+    assert!(items.is_empty());
+}
+
+#[test]
+fn test_interleave_shortest_matches_len() {
+    for (a, b) in [
+        (0, 0),
+        (0, 3),
+        (3, 0),
+        (2, 5),
+        (5, 2),
+        (4, 4),
+        (3, 2),
+        (2, 3),
+    ] {
+        let mut interleaved = VecLender::new((0..a).collect())
+            .interleave_shortest(VecLender::new((100..100 + b).collect()));
+        let mut count = 0;
+        let total = interleaved.len();
+        loop {
+            assert_eq!(interleaved.len(), total - count);
+            assert_eq!(
+                interleaved.size_hint(),
+                (total - count, Some(total - count))
+            );
+            if interleaved.next().is_none() {
+                break;
+            }
+            count += 1;
+        }
+        assert_eq!(count, total);
+    }
+}
+
+#[test]
+fn test_interleave_shortest_size_hint_unbounded() {
+    let interleaved = (0..)
+        .into_lender()
+        .interleave_shortest((0..3).into_lender());
+    assert_eq!(interleaved.size_hint(), (7, Some(7)));
+    let interleaved = (0..3)
+        .into_lender()
+        .interleave_shortest((0..).into_lender());
+    assert_eq!(interleaved.size_hint(), (6, Some(6)));
+}
+
 // ============================================================================
 // Intersperse adapter tests (Lender)
 // Semantics: insert separator between elements
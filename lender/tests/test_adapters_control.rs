@@ -1,4 +1,4 @@
-//! Tests for control flow adapters: Chain, Fuse, Cycle, Rev
+//! Tests for control flow adapters: Chain, Fuse, Cycle, Rev, Either
 
 #![allow(clippy::unnecessary_fold)]
 
@@ -701,3 +701,107 @@ fn test_rev_try_rfold_additional() {
         .try_rfold(0, |acc, x| Some(acc + *x));
     assert_eq!(result, Some(6));
 }
+
+// ============================================================================
+// Either adapter tests
+// Semantics: Either delegates to whichever of two lenders with the same lend
+// type it contains
+// ============================================================================
+
+fn either_lender(data: Vec<i32>, rev: bool) -> lender::Either<lender::Rev<VecLender>, VecLender> {
+    if rev {
+        VecLender::new(data).rev().left_lender()
+    } else {
+        VecLender::new(data).right_lender()
+    }
+}
+
+#[test]
+fn test_either_basic() {
+    let mut lender = either_lender(vec![1, 2, 3], true);
+    assert!(lender.is_left());
+    assert_eq!(lender.next(), Some(&3));
+    assert_eq!(lender.next(), Some(&2));
+
+    let mut lender = either_lender(vec![1, 2, 3], false);
+    assert!(lender.is_right());
+    assert_eq!(lender.next(), Some(&1));
+    assert_eq!(lender.next(), Some(&2));
+}
+
+// The branches need not have related types
+fn either_windows(
+    data: &mut [i32],
+    even: bool,
+) -> impl Lender + for<'lend> Lending<'lend, Lend = &'lend mut [i32]> + '_ {
+    if even {
+        lender::windows_mut(data, 2)
+            .filter(|w| w[0] % 2 == 0)
+            .left_lender()
+    } else {
+        lender::windows_mut(data, 2).right_lender()
+    }
+}
+
+#[test]
+fn test_either_different_adapters() {
+    let mut data = [1, 2, 3, 4, 5];
+    assert_eq!(either_windows(&mut data, true).count(), 2);
+    assert_eq!(either_windows(&mut data, false).count(), 4);
+    either_windows(&mut data, true).for_each(|w| w[1] += 10);
+    assert_eq!(data, [1, 2, 13, 4, 15]);
+}
+
+#[test]
+fn test_either_size_hint_len() {
+    let lender = either_lender(vec![1, 2, 3], true);
+    assert_eq!(lender.size_hint(), (3, Some(3)));
+    assert_eq!(lender.len(), 3);
+    let mut lender = either_lender(vec![], false);
+    assert!(lender.is_empty());
+    assert_eq!(lender.next(), None);
+}
+
+#[test]
+fn test_either_double_ended() {
+    let mut lender = either_lender(vec![1, 2, 3, 4, 5], true);
+    assert_eq!(lender.next_back(), Some(&1));
+    assert_eq!(lender.nth_back(1), Some(&3));
+    let mut order = Vec::new();
+    lender.rfold((), |(), x| order.push(*x));
+    assert_eq!(order, vec![4, 5]);
+
+    let mut lender = either_lender(vec![1, 2, 3, 4, 5], false);
+    assert_eq!(lender.advance_back_by(2), Ok(()));
+    assert_eq!(
+        lender.try_rfold(0, |acc, x| Some(acc + *x)),
+        Some(1 + 2 + 3)
+    );
+}
+
+#[test]
+fn test_either_overrides() {
+    use core::num::NonZeroUsize;
+    let mut lender = either_lender(vec![1, 2, 3, 4, 5], false);
+    assert_eq!(lender.nth(1), Some(&2));
+    assert_eq!(lender.advance_by(1), Ok(()));
+    assert_eq!(lender.try_fold(0, |acc, x| Some(acc + *x)), Some(4 + 5));
+
+    let lender = either_lender(vec![1, 2, 3], true);
+    assert_eq!(lender.fold(0, |acc, x| acc * 10 + *x), 321);
+    assert_eq!(either_lender(vec![1, 2, 3], true).count(), 3);
+    assert_eq!(
+        either_lender(vec![1, 2], false).advance_by(3),
+        Err(NonZeroUsize::new(1).unwrap())
+    );
+}
+
+#[test]
+fn test_either_fused() {
+    fn assert_fused<L: FusedLender>(_: &L) {}
+    let mut lender = either_lender(vec![1], true);
+    assert_fused(&lender);
+    assert_eq!(lender.next(), Some(&1));
+    assert_eq!(lender.next(), None);
+    assert_eq!(lender.next(), None);
+}
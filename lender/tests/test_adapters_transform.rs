@@ -1,4 +1,4 @@
-//! Tests for element transformation adapters: Map, Enumerate, Inspect, Mutate, Scan, MapWhile, Cloned, Copied, Owned
+//! Tests for element transformation adapters: Map, Enumerate, Inspect, Mutate, Scan, MapWhile, Cloned, Copied, Owned, Boxed
 
 #![allow(clippy::unnecessary_fold)]
 
@@ -863,3 +863,92 @@ fn test_owned_nth_out_of_bounds() {
     let mut iter = lender.owned();
     assert_eq!(iter.nth(100), None);
 }
+
+// ============================================================================
+// Boxed adapter tests
+// Semantics: boxed() erases the type of a lender, keeping its lend type
+// ============================================================================
+
+// The lend of windows on slices of i32
+type WindowsLend = lend!(&'lend mut [i32]);
+
+// Returns different lender pipelines with the same lend type.
+fn boxed_windows(data: &mut [i32], kind: usize) -> lender::BoxedLender<'_, WindowsLend> {
+    match kind {
+        0 => lender::windows_mut(data, 2).boxed(),
+        1 => lender::windows_mut(data, 2).skip(1).boxed(),
+        2 => lender::windows_mut(data, 2).rev().step_by(2).boxed(),
+        _ => lender::empty::<WindowsLend>().boxed(),
+    }
+}
+
+#[test]
+fn test_boxed_heterogeneous() {
+    let mut data = [1, 2, 3, 4];
+    let mut lender = boxed_windows(&mut data, 0);
+    assert_eq!(lender.size_hint(), (3, Some(3)));
+    assert_eq!(lender.next(), Some(&mut [1, 2][..]));
+    assert_eq!(lender.size_hint(), (2, Some(2)));
+    drop(lender);
+
+    let mut lender = boxed_windows(&mut data, 1);
+    assert_eq!(lender.next(), Some(&mut [2, 3][..]));
+    drop(lender);
+
+    let mut lender = boxed_windows(&mut data, 2);
+    assert_eq!(lender.next(), Some(&mut [3, 4][..]));
+    assert_eq!(lender.next(), Some(&mut [1, 2][..]));
+    assert_eq!(lender.next(), None);
+    drop(lender);
+
+    let mut lender = boxed_windows(&mut data, 3);
+    assert_eq!(lender.size_hint(), (0, Some(0)));
+    assert_eq!(lender.next(), None);
+}
+
+#[test]
+fn test_boxed_adapters() {
+    // Boxed lenders are lenders, so all adapters are available
+    let mut data = [0, 1, 0, 0, 0, 0];
+    boxed_windows(&mut data, 0)
+        .skip(1)
+        .for_each(|w| w[1] = w[0] + 1);
+    assert_eq!(data, [0, 1, 2, 3, 4, 5]);
+
+    let lenders = [0, 1, 2, 3].map(|kind| {
+        let mut data = [1, 2, 3, 4, 5];
+        boxed_windows(&mut data, kind).count()
+    });
+    assert_eq!(lenders, [4, 3, 2, 0]);
+
+    // Boxing twice
+    let mut data = [1, 2, 3];
+    let lender: lender::BoxedLender<WindowsLend> = lender::windows_mut(&mut data, 2).boxed();
+    let mut lender: lender::BoxedLender<WindowsLend> = lender.boxed();
+    assert_eq!(lender.next(), Some(&mut [1, 2][..]));
+}
+
+#[test]
+fn test_boxed_covariant_lend() {
+    covariant_lend!(RefVec = &'lend Vec<i32>);
+    let data = [vec![1], vec![2, 3]];
+    let mut lender: lender::BoxedLender<RefVec> =
+        lender::lend_iter::<RefVec, _>(data.iter()).boxed();
+    assert_eq!(lender.next(), Some(&vec![1]));
+    let mut lender = lender::BoxedLender::<RefVec>::new(lender);
+    assert_eq!(lender.next(), Some(&vec![2, 3]));
+    assert_eq!(lender.next(), None);
+}
+
+#[test]
+fn test_dyn_lender() {
+    // DynLender is dyn-compatible
+    let mut data = [1, 2, 3];
+    let mut lender: Box<dyn lender::boxed::DynLender<WindowsLend>> =
+        Box::new(lender::windows_mut(&mut data, 2));
+    assert_eq!(lender.size_hint(), (2, Some(2)));
+    assert_eq!(lender.next(), Some(&mut [1, 2][..]));
+    let lender: &mut dyn lender::boxed::DynLender<WindowsLend> = &mut *lender;
+    assert_eq!(lender.next(), Some(&mut [2, 3][..]));
+    assert_eq!(lender.next(), None);
+}
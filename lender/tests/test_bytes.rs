@@ -0,0 +1,310 @@
+#![cfg(feature = "bytes")]
+//! Tests for the decoding of frames from bytes buffers
+
+use ::lender::prelude::*;
+use bytes::{Buf, Bytes, BytesMut};
+use lender::{ByteDelimited, Frame, FrameDecoder, FrameError, LengthPrefixed};
+
+/// Returns the next frame as an owned vector.
+fn next_vec<L>(lender: &mut L) -> Result<Option<Vec<u8>>, L::Error>
+where
+    L: FallibleLender + for<'lend> FallibleLending<'lend, Lend = &'lend mut [u8]>,
+{
+    Ok(lender.next()?.map(|frame| frame.to_vec()))
+}
+
+// ============================================================================
+// LengthPrefixed
+// ============================================================================
+
+#[test]
+fn test_length_prefixed() {
+    let data = [0_u8, 0, 0, 3, 1, 2, 3, 0, 0, 0, 0, 0, 0, 0, 1, 4];
+    let mut lender = lender::frames(&data[..], LengthPrefixed::new(4));
+    assert_eq!(next_vec(&mut lender), Ok(Some(vec![1, 2, 3])));
+    assert_eq!(next_vec(&mut lender), Ok(Some(vec![])));
+    assert_eq!(next_vec(&mut lender), Ok(Some(vec![4])));
+    assert_eq!(next_vec(&mut lender), Ok(None));
+    assert_eq!(next_vec(&mut lender), Ok(None));
+}
+
+#[test]
+fn test_length_prefixed_little_endian() {
+    let data = [2_u8, 0, 1, 2, 1, 0, 3];
+    let mut lender = lender::frames(&data[..], LengthPrefixed::new(2).little_endian());
+    assert_eq!(next_vec(&mut lender), Ok(Some(vec![1, 2])));
+    assert_eq!(next_vec(&mut lender), Ok(Some(vec![3])));
+    assert_eq!(next_vec(&mut lender), Ok(None));
+
+    // Eight-byte prefixes
+    let mut data = 2_u64.to_le_bytes().to_vec();
+    data.extend([5, 6]);
+    let mut lender = lender::frames(&data[..], LengthPrefixed::new(8).little_endian());
+    assert_eq!(next_vec(&mut lender), Ok(Some(vec![5, 6])));
+    assert_eq!(next_vec(&mut lender), Ok(None));
+}
+
+#[test]
+fn test_length_prefixed_truncated() {
+    let mut lender = lender::frames(&[1_u8, 7, 3, 8, 9][..], LengthPrefixed::new(1));
+    assert_eq!(next_vec(&mut lender), Ok(Some(vec![7])));
+    assert_eq!(next_vec(&mut lender), Err(FrameError::Truncated { len: 3 }));
+    // The lender ends after an error
+    assert_eq!(next_vec(&mut lender), Ok(None));
+
+    // A truncated prefix
+    let mut lender = lender::frames(&[0_u8][..], LengthPrefixed::new(2));
+    assert_eq!(next_vec(&mut lender), Err(FrameError::Truncated { len: 1 }));
+}
+
+#[test]
+fn test_length_prefixed_too_long() {
+    let data = [2_u8, 1, 2, 3, 1, 2, 3];
+    let mut lender = lender::frames(&data[..], LengthPrefixed::new(1).max_len(2));
+    assert_eq!(next_vec(&mut lender), Ok(Some(vec![1, 2])));
+    assert_eq!(
+        next_vec(&mut lender),
+        Err(FrameError::TooLong { len: 3, max_len: 2 })
+    );
+    assert_eq!(next_vec(&mut lender), Ok(None));
+}
+
+#[test]
+fn test_length_prefixed_overflow() {
+    // A hostile prefix whose frame end does not fit in a usize
+    let data = [0xff_u8; 16];
+    let mut lender = lender::frames(&data[..], LengthPrefixed::new(8).max_len(usize::MAX));
+    assert_eq!(
+        next_vec(&mut lender),
+        Err(FrameError::TooLong {
+            len: usize::MAX,
+            max_len: usize::MAX
+        })
+    );
+    assert_eq!(next_vec(&mut lender), Ok(None));
+}
+
+#[test]
+#[should_panic(expected = "the length of the prefix must be between one and eight bytes")]
+fn test_length_prefixed_invalid_prefix() {
+    let _ = LengthPrefixed::new(9);
+}
+
+// ============================================================================
+// ByteDelimited
+// ============================================================================
+
+#[test]
+fn test_byte_delimited() {
+    let mut lender = lender::frames("one\ntwo\n\nthree", ByteDelimited::new(b'\n'));
+    assert_eq!(next_vec(&mut lender), Ok(Some(b"one".to_vec())));
+    assert_eq!(next_vec(&mut lender), Ok(Some(b"two".to_vec())));
+    assert_eq!(next_vec(&mut lender), Ok(Some(vec![])));
+    // The last frame has no delimiter
+    assert_eq!(next_vec(&mut lender), Ok(Some(b"three".to_vec())));
+    assert_eq!(next_vec(&mut lender), Ok(None));
+
+    let mut lender = lender::frames("a\n", ByteDelimited::new(b'\n'));
+    assert_eq!(next_vec(&mut lender), Ok(Some(b"a".to_vec())));
+    assert_eq!(next_vec(&mut lender), Ok(None));
+}
+
+#[test]
+fn test_byte_delimited_too_long() {
+    let mut lender = lender::frames("abc,abcd,", ByteDelimited::new(b',').max_len(3));
+    assert_eq!(next_vec(&mut lender), Ok(Some(b"abc".to_vec())));
+    assert_eq!(
+        next_vec(&mut lender),
+        Err(FrameError::TooLong { len: 5, max_len: 3 })
+    );
+    assert_eq!(next_vec(&mut lender), Ok(None));
+
+    // The last frame, without delimiter, is too long
+    let mut lender = lender::frames("abcd", ByteDelimited::new(b',').max_len(3));
+    assert_eq!(
+        next_vec(&mut lender),
+        Err(FrameError::TooLong { len: 4, max_len: 3 })
+    );
+}
+
+#[test]
+fn test_byte_delimited_incremental() {
+    let mut lender = lender::frames(BytesMut::new(), ByteDelimited::new(b'\n'));
+    lender.set_eof(false);
+    assert!(!lender.is_eof());
+    assert_eq!(next_vec(&mut lender), Ok(None));
+    lender.extend_from_buf(&b"hel"[..]);
+    assert_eq!(next_vec(&mut lender), Ok(None));
+    lender.extend_from_buf(&b"lo\nwor"[..]);
+    assert_eq!(next_vec(&mut lender), Ok(Some(b"hello".to_vec())));
+    assert_eq!(next_vec(&mut lender), Ok(None));
+    assert_eq!(lender.buffer(), b"wor");
+    lender.buffer_mut().extend_from_slice(b"ld\n!");
+    assert_eq!(next_vec(&mut lender), Ok(Some(b"world".to_vec())));
+    assert_eq!(next_vec(&mut lender), Ok(None));
+    lender.set_eof(true);
+    assert_eq!(next_vec(&mut lender), Ok(Some(b"!".to_vec())));
+    assert_eq!(next_vec(&mut lender), Ok(None));
+}
+
+// ============================================================================
+// Frames
+// ============================================================================
+
+#[test]
+fn test_frames_zero_copy() {
+    let buf = BytesMut::from(&b"ab,cd"[..]);
+    let range = buf.as_ptr_range();
+    let mut lender = lender::frames(buf, ByteDelimited::new(b','));
+    while let Some(frame) = lender.next().unwrap() {
+        // Frames are slices of the original buffer
+        assert!(range.contains(&frame.as_ptr()));
+        frame.make_ascii_uppercase();
+    }
+    assert!(lender.into_inner().is_empty());
+}
+
+#[test]
+fn test_frames_mutation() {
+    let mut lender = lender::frames(&b"ab,cd,ef"[..], ByteDelimited::new(b','));
+    let frame = lender.next().unwrap().unwrap();
+    frame.reverse();
+    assert_eq!(frame, b"ba");
+    // The unconsumed bytes include the last frame until the next call
+    assert_eq!(lender.buffer(), b"cd,ef");
+    lender.next().unwrap();
+    assert_eq!(lender.into_inner(), &b"ef"[..]);
+}
+
+#[test]
+fn test_frames_from_bytes() {
+    let bytes = Bytes::from_static(b"\x02hi\x01!");
+    let lender = lender::frames(bytes, LengthPrefixed::new(1));
+    assert_eq!(lender.count(), Ok(2));
+}
+
+#[test]
+fn test_frames_extend_from_buf() {
+    let mut lender = lender::frames(BytesMut::new(), LengthPrefixed::new(1));
+    // A non-contiguous Buf
+    lender.extend_from_buf((&[3_u8, 1][..]).chain(&[2_u8, 3, 0][..]));
+    assert_eq!(next_vec(&mut lender), Ok(Some(vec![1, 2, 3])));
+    assert_eq!(next_vec(&mut lender), Ok(Some(vec![])));
+    assert_eq!(next_vec(&mut lender), Ok(None));
+    assert_eq!(
+        lender.decoder().clone().decode(&[1, 9]),
+        Ok(Some(Frame { data: 1..2, len: 2 }))
+    );
+}
+
+#[test]
+fn test_frames_adapters() {
+    let lender = lender::frames("3,14,15,92,65", ByteDelimited::new(b','));
+    let sum = lender
+        .filter(|frame| Ok(frame.len() == 2))
+        .fold(0, |acc, frame| {
+            Ok(acc + core::str::from_utf8(frame).unwrap().parse::<u32>().unwrap())
+        });
+    assert_eq!(sum, Ok(14 + 15 + 92 + 65));
+}
+
+// ============================================================================
+// Custom decoders
+// ============================================================================
+
+/// The error of [`Tagged`].
+#[derive(Debug, PartialEq)]
+enum TaggedError {
+    Frame(FrameError),
+    UnknownTag(u8),
+}
+
+impl From<FrameError> for TaggedError {
+    fn from(e: FrameError) -> Self {
+        TaggedError::Frame(e)
+    }
+}
+
+/// Frames made of a tag byte followed by a payload whose length depends on
+/// the tag; the tag is not lent.
+struct Tagged;
+
+impl FrameDecoder for Tagged {
+    type Error = TaggedError;
+
+    fn decode(&mut self, buf: &[u8]) -> Result<Option<Frame>, Self::Error> {
+        let len = match buf.first() {
+            None => return Ok(None),
+            Some(b'a') => 1,
+            Some(b'b') => 2,
+            Some(&tag) => return Err(TaggedError::UnknownTag(tag)),
+        };
+        Ok((buf.len() > len).then(|| Frame {
+            data: 1..len + 1,
+            len: len + 1,
+        }))
+    }
+}
+
+#[test]
+fn test_custom_decoder() {
+    let mut lender = lender::frames(&b"a1b23a4c5"[..], Tagged);
+    assert_eq!(next_vec(&mut lender), Ok(Some(b"1".to_vec())));
+    assert_eq!(next_vec(&mut lender), Ok(Some(b"23".to_vec())));
+    assert_eq!(next_vec(&mut lender), Ok(Some(b"4".to_vec())));
+    assert_eq!(next_vec(&mut lender), Err(TaggedError::UnknownTag(b'c')));
+    assert_eq!(next_vec(&mut lender), Ok(None));
+
+    // The default decode_eof reports truncated frames
+    let mut lender = lender::frames(&b"a1b2"[..], Tagged);
+    assert_eq!(next_vec(&mut lender), Ok(Some(b"1".to_vec())));
+    assert_eq!(
+        next_vec(&mut lender),
+        Err(TaggedError::Frame(FrameError::Truncated { len: 2 }))
+    );
+}
+
+#[test]
+fn test_decoder_by_mut_ref() {
+    let mut decoder = ByteDelimited::new(b';');
+    let mut lender = lender::frames(&b"x;y"[..], &mut decoder);
+    assert_eq!(next_vec(&mut lender), Ok(Some(b"x".to_vec())));
+    drop(lender);
+    assert_eq!(
+        decoder.decode(b"z;"),
+        Ok(Some(Frame { data: 0..1, len: 2 }))
+    );
+}
+
+/// A decoder returning a frame longer than the buffer.
+struct Invalid;
+
+impl FrameDecoder for Invalid {
+    type Error = FrameError;
+
+    fn decode(&mut self, buf: &[u8]) -> Result<Option<Frame>, Self::Error> {
+        Ok(Some(Frame {
+            data: 0..buf.len(),
+            len: buf.len() + 1,
+        }))
+    }
+}
+
+#[test]
+#[should_panic(expected = "the decoder returned an invalid frame")]
+fn test_invalid_decoder() {
+    let mut lender = lender::frames(&b"abc"[..], Invalid);
+    let _ = lender.next();
+}
+
+#[test]
+fn test_frame_error_display() {
+    assert_eq!(
+        FrameError::Truncated { len: 3 }.to_string(),
+        "truncated frame of 3 bytes at end of input"
+    );
+    assert_eq!(
+        FrameError::TooLong { len: 9, max_len: 4 }.to_string(),
+        "frame of 9 bytes exceeds maximum length 4"
+    );
+}
@@ -575,6 +575,146 @@ fn test_fallible_trait_adapters_fuse() {
     assert_fused(&fused);
 }
 
+#[test]
+fn test_fallible_batching() {
+    let mut frames = VecFallibleLender::new(vec![2, 1, 2, 1, 3]).batching(covar_mut!(
+        for<'lend> |l: &'lend mut VecFallibleLender| -> Result<
+            Option<lender::Chunk<'lend, VecFallibleLender>>,
+            std::convert::Infallible,
+        > {
+            let Some(&len) = l.next()? else {
+                return Ok(None);
+            };
+            Ok(Some(l.next_chunk(len as usize)))
+        }
+    ));
+    let mut frame = frames.next().unwrap().unwrap();
+    assert_eq!(frame.next(), Ok(Some(&1)));
+    assert_eq!(frame.next(), Ok(Some(&2)));
+    assert_eq!(frame.next(), Ok(None));
+    assert_eq!(frames.next().unwrap().unwrap().count(), Ok(1));
+    assert!(frames.next().unwrap().is_none());
+}
+
+#[test]
+fn test_fallible_batching_error() {
+    let mut sums = ErrorAtLender::new(vec![1, 2, 3, 4], 2).batching(covar_mut!(
+        for<'lend> |l: &'lend mut ErrorAtLender| -> Result<Option<i32>, String> {
+            let Some(&a) = l.next()? else {
+                return Ok(None);
+            };
+            let b = l.next()?.copied().unwrap_or(0);
+            Ok(Some(a + b))
+        }
+    ));
+    assert_eq!(sums.next(), Ok(Some(3)));
+    assert_eq!(sums.next(), Err("error at index 2".to_string()));
+    // The error is not terminal for ErrorAtLender
+    assert_eq!(sums.next(), Ok(Some(4)));
+    assert_eq!(sums.next(), Ok(None));
+}
+
+#[test]
+fn test_fallible_split() -> Result<(), std::convert::Infallible> {
+    let mut split = VecFallibleLender::new(vec![1, 2, 0, 3, 0]).split(|&&x| Ok(x == 0));
+    let mut part = split.next()?.unwrap();
+    assert_eq!(part.next()?, Some(&1));
+    // The rest of the part is skipped
+    drop(part);
+    assert_eq!(split.next()?.unwrap().count()?, 1);
+    assert_eq!(split.next()?.unwrap().next()?, None);
+    assert!(split.next()?.is_none());
+    assert!(split.next()?.is_none());
+    Ok(())
+}
+
+#[test]
+fn test_fallible_split_error() {
+    let mut split = ErrorAtLender::new(vec![1, 0, 2, 3, 0, 4], 2).split(|&&x| Ok(x == 0));
+    assert_eq!(split.next().unwrap().unwrap().count(), Ok(1));
+    let mut part = split.next().unwrap().unwrap();
+    assert_eq!(part.next(), Err("error at index 2".to_string()));
+    assert_eq!(part.next(), Ok(Some(&3)));
+    assert_eq!(part.next(), Ok(None));
+    assert_eq!(split.next().unwrap().unwrap().next(), Ok(Some(&4)));
+    assert!(split.next().unwrap().is_none());
+}
+
+#[test]
+fn test_fallible_split_inclusive() -> Result<(), std::convert::Infallible> {
+    let mut split = VecFallibleLender::new(vec![1, 0, 2, 0]).split_inclusive(|&&x| Ok(x == 0));
+    let mut part = split.next()?.unwrap();
+    assert_eq!(part.next()?, Some(&1));
+    assert_eq!(part.next()?, Some(&0));
+    assert_eq!(part.next()?, None);
+    assert_eq!(split.next()?.unwrap().count()?, 2);
+    assert!(split.next()?.is_none());
+    Ok(())
+}
+
+#[test]
+fn test_fallible_split_predicate_error() {
+    let mut split = VecFallibleLender::new(vec![1, 2, 3])
+        .map_err(|_| "never")
+        .split(|&&x| if x == 2 { Err("bad") } else { Ok(false) });
+    let mut part = split.next().unwrap().unwrap();
+    assert_eq!(part.next(), Ok(Some(&1)));
+    assert_eq!(part.next(), Err("bad"));
+    assert_eq!(part.next(), Ok(Some(&3)));
+}
+
+#[test]
+fn test_fallible_interleave() {
+    let mut interleaved =
+        VecFallibleLender::new(vec![1, 2, 3]).interleave(VecFallibleLender::new(vec![10]));
+    assert_eq!(interleaved.len(), 4);
+    assert_eq!(interleaved.next(), Ok(Some(&1)));
+    assert_eq!(interleaved.next(), Ok(Some(&10)));
+    assert_eq!(interleaved.next(), Ok(Some(&2)));
+    assert_eq!(interleaved.len(), 1);
+    assert_eq!(interleaved.next(), Ok(Some(&3)));
+    assert_eq!(interleaved.next(), Ok(None));
+    assert_eq!(interleaved.next(), Ok(None));
+}
+
+#[test]
+fn test_fallible_interleave_error() {
+    let mut interleaved =
+        ErrorAtLender::new(vec![1, 2, 3], 1).interleave(ErrorAtLender::new(vec![10, 20], 5));
+    assert_eq!(interleaved.next(), Ok(Some(&1)));
+    assert_eq!(interleaved.next(), Ok(Some(&10)));
+    assert_eq!(interleaved.next(), Err("error at index 1".to_string()));
+    // The turn passes to the other lender after an error
+    assert_eq!(interleaved.next(), Ok(Some(&20)));
+    assert_eq!(interleaved.next(), Ok(Some(&3)));
+    assert_eq!(interleaved.next(), Ok(None));
+}
+
+#[test]
+fn test_fallible_interleave_shortest() {
+    let mut interleaved =
+        VecFallibleLender::new(vec![1, 2, 3]).interleave_shortest(VecFallibleLender::new(vec![10]));
+    assert_eq!(interleaved.len(), 3);
+    assert_eq!(interleaved.size_hint(), (3, Some(3)));
+    assert_eq!(interleaved.next(), Ok(Some(&1)));
+    assert_eq!(interleaved.next(), Ok(Some(&10)));
+    assert_eq!(interleaved.next(), Ok(Some(&2)));
+    assert_eq!(interleaved.len(), 0);
+    assert_eq!(interleaved.next(), Ok(None));
+}
+
+#[test]
+fn test_fallible_interleave_shortest_error() {
+    let mut interleaved =
+        ErrorAtLender::new(vec![1, 2], 5).interleave_shortest(ErrorAtLender::new(vec![10, 20], 0));
+    assert_eq!(interleaved.next(), Ok(Some(&1)));
+    assert_eq!(interleaved.next(), Err("error at index 0".to_string()));
+    // The turn does not pass to the other lender after an error
+    assert_eq!(interleaved.next(), Ok(Some(&20)));
+    assert_eq!(interleaved.next(), Ok(Some(&2)));
+    assert_eq!(interleaved.next(), Ok(None));
+}
+
 // ============================================================================
 // Fallible max_by/min_by
 // ============================================================================
@@ -1279,3 +1419,238 @@ fn test_fallible_convert_size_hint() {
 }
 
 // ============================================================================
+
+// ============================================================================
+// BoxedFallibleLender
+// ============================================================================
+
+fn boxed_fallible(
+    data: &[i32],
+    fail: bool,
+) -> lender::BoxedFallibleLender<'_, fallible_lend!(&'lend i32), String> {
+    let lender = lender::lend_iter::<lend!(&'lend i32), _>(data.iter()).into_fallible();
+    if fail {
+        lender
+            .map_err(|e| match e {})
+            .chain(lender::fallible_once_err::<fallible_lend!(&'lend i32), _>(
+                "failure".to_string(),
+            ))
+            .boxed()
+    } else {
+        lender.map_err(|e| match e {}).boxed()
+    }
+}
+
+#[test]
+fn test_boxed_fallible_lender() {
+    let data = [1, 2];
+    let mut lender = boxed_fallible(&data, false);
+    assert_eq!(lender.size_hint(), (2, Some(2)));
+    assert_eq!(lender.next(), Ok(Some(&1)));
+    assert_eq!(lender.next(), Ok(Some(&2)));
+    assert_eq!(lender.next(), Ok(None));
+
+    let mut lender = boxed_fallible(&data, true);
+    assert_eq!(lender.next(), Ok(Some(&1)));
+    assert_eq!(lender.next(), Ok(Some(&2)));
+    assert_eq!(lender.next(), Err("failure".to_string()));
+
+    // Adapters on boxed fallible lenders
+    assert_eq!(boxed_fallible(&data, false).count(), Ok(2));
+    assert_eq!(
+        boxed_fallible(&data, true).count(),
+        Err("failure".to_string())
+    );
+    let mut lender: lender::BoxedFallibleLender<fallible_lend!(i32), String> =
+        lender::BoxedFallibleLender::new(boxed_fallible(&data, false).map(covar_mut!(
+            for<'lend> |x: &'lend i32| -> Result<i32, String> { Ok(x + 1) }
+        )));
+    assert_eq!(lender.next(), Ok(Some(2)));
+}
+
+#[test]
+fn test_dyn_fallible_lender() {
+    type DynRefLender<'a> =
+        dyn lender::boxed::DynFallibleLender<fallible_lend!(&'lend i32), String> + 'a;
+    let data = [1, 2];
+    let mut lender: Box<DynRefLender> = Box::new(boxed_fallible(&data, true));
+    assert_eq!(lender.size_hint(), (2, Some(2)));
+    assert_eq!(lender.next(), Ok(Some(&1)));
+    assert_eq!(lender.next(), Ok(Some(&2)));
+    assert_eq!(lender.next(), Err("failure".to_string()));
+}
+
+// ============================================================================
+// Either
+// ============================================================================
+
+#[test]
+fn test_either_fallible() {
+    let either = |data: Vec<i32>, error_at: Option<usize>| match error_at {
+        Some(error_at) => ErrorAtLender::new(data, error_at).left_lender(),
+        None => VecFallibleLender::new(data)
+            .map_err(|e| match e {})
+            .right_lender(),
+    };
+
+    let mut lender = either(vec![1, 2, 3], Some(1));
+    assert!(lender.is_left());
+    assert_eq!(lender.next(), Ok(Some(&1)));
+    assert_eq!(lender.next(), Err("error at index 1".to_string()));
+    assert_eq!(lender.next(), Ok(Some(&3)));
+    assert_eq!(
+        either(vec![1, 2, 3], Some(2)).count(),
+        Err("error at index 2".to_string())
+    );
+
+    let mut lender = either(vec![1, 2, 3, 4], None);
+    assert!(lender.is_right());
+    assert_eq!(lender.size_hint(), (4, Some(4)));
+    assert_eq!(lender.nth(1), Ok(Some(&2)));
+    assert_eq!(lender.fold(0, |acc, x| Ok(acc + *x)), Ok(7));
+}
+
+#[test]
+fn test_either_fallible_double_ended() {
+    let either = |data: Vec<i32>, rev: bool| {
+        if rev {
+            VecFallibleLender::new(data).rev().left_lender()
+        } else {
+            VecFallibleLender::new(data).right_lender()
+        }
+    };
+
+    let mut lender = either(vec![1, 2, 3, 4, 5], true);
+    assert_eq!(lender.next(), Ok(Some(&5)));
+    assert_eq!(lender.next_back(), Ok(Some(&1)));
+    assert_eq!(lender.nth_back(1), Ok(Some(&3)));
+    assert_eq!(lender.rfold(0, |acc, x| Ok(acc * 10 + *x)), Ok(4));
+
+    let mut lender = either(vec![1, 2, 3, 4, 5], false);
+    assert_eq!(lender.advance_back_by(1), Ok(Ok(())));
+    assert_eq!(
+        lender.try_rfold(0, |acc, x| Ok(Some(acc + *x))),
+        Ok(Some(1 + 2 + 3 + 4))
+    );
+    assert!(lender.is_empty());
+}
+
+// ============================================================================
+// IntoResults and OwnedResults
+// ============================================================================
+
+#[test]
+fn test_into_results() {
+    let mut lender = ErrorAtLender::new(vec![1, 2, 3, 4], 2).into_results();
+    assert_eq!(lender.size_hint(), (0, Some(5)));
+    assert_eq!(lender.next(), Some(Ok(&1)));
+    assert_eq!(lender.next(), Some(Ok(&2)));
+    assert_eq!(lender.next(), Some(Err("error at index 2".to_string())));
+    // Ends after the first error
+    assert_eq!(lender.size_hint(), (0, Some(0)));
+    assert_eq!(lender.next(), None);
+    assert_eq!(lender.next(), None);
+
+    // The lower bound is at most one, as the next call may be an error
+    let lender = VecFallibleLender::new(vec![1, 2, 3]).into_results();
+    assert_eq!(lender.size_hint(), (1, Some(4)));
+    assert_eq!(lender.count(), 3);
+
+    let mut lender = [Err("error"), Ok(1), Ok(2)]
+        .into_iter()
+        .into_lender()
+        .convert::<&str>()
+        .into_results();
+    assert_eq!(lender.size_hint(), (1, Some(4)));
+    assert_eq!(lender.next(), Some(Err("error")));
+    assert_eq!(lender.size_hint(), (0, Some(0)));
+    assert_eq!(lender.next(), None);
+}
+
+#[test]
+fn test_into_results_double_ended() {
+    let mut lender = VecFallibleLender::new(vec![1, 2, 3]).into_results();
+    assert_eq!(lender.next_back(), Some(Ok(&3)));
+    assert_eq!(lender.next(), Some(Ok(&1)));
+    assert_eq!(lender.next_back(), Some(Ok(&2)));
+    assert_eq!(lender.next_back(), None);
+
+    let mut lender = [Ok(1), Err("error"), Ok(3)]
+        .into_iter()
+        .into_lender()
+        .convert::<&str>()
+        .into_results();
+    assert_eq!(lender.next_back(), Some(Ok(3)));
+    assert_eq!(lender.next_back(), Some(Err("error")));
+    assert_eq!(lender.next(), None);
+    assert_eq!(lender.next_back(), None);
+}
+
+#[test]
+fn test_into_results_round_trip() {
+    let data = [Ok(1), Ok(2), Err("error"), Ok(4)];
+    let mut lender = data
+        .into_iter()
+        .into_lender()
+        .convert::<&str>()
+        .into_results()
+        .convert::<&str>();
+    assert_eq!(lender.next(), Ok(Some(1)));
+    assert_eq!(lender.next(), Ok(Some(2)));
+    assert_eq!(lender.next(), Err("error"));
+    assert_eq!(lender.next(), Ok(None));
+    assert_eq!(lender.into_inner().into_inner().into_inner().count(), 1);
+}
+
+#[test]
+fn test_owned_results() {
+    let v: Result<Vec<i32>, &str> = [1, 2, 3]
+        .into_iter()
+        .into_lender()
+        .into_fallible()
+        .map_err(|e| match e {})
+        .owned_results()
+        .collect();
+    assert_eq!(v, Ok(vec![1, 2, 3]));
+
+    let data = [Ok(1), Err("error"), Ok(3)];
+    let v: Result<Vec<i32>, &str> = data
+        .into_iter()
+        .into_lender()
+        .convert()
+        .owned_results()
+        .collect();
+    assert_eq!(v, Err("error"));
+
+    let mut iter = data
+        .into_iter()
+        .into_lender()
+        .convert::<&str>()
+        .owned_results();
+    assert_eq!(iter.size_hint(), (1, Some(4)));
+    assert_eq!(iter.next(), Some(Ok(1)));
+    assert_eq!(iter.next(), Some(Err("error")));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+}
+
+#[test]
+fn test_owned_results_double_ended() {
+    let iter = [1, 2, 3]
+        .into_iter()
+        .into_lender()
+        .into_fallible()
+        .owned_results();
+    assert_eq!(iter.rev().collect::<Vec<_>>(), vec![Ok(3), Ok(2), Ok(1)]);
+
+    let mut iter = [Ok(1), Err("error"), Ok(3)]
+        .into_iter()
+        .into_lender()
+        .convert::<&str>()
+        .owned_results();
+    assert_eq!(iter.next_back(), Some(Ok(3)));
+    assert_eq!(iter.next_back(), Some(Err("error")));
+    assert_eq!(iter.next(), None);
+    let lender = iter.into_inner();
+    assert_eq!(lender.count(), Ok(1));
+}
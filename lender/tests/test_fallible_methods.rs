@@ -941,6 +941,78 @@ fn test_fallible_lender_is_sorted_by_key() {
     );
 }
 
+// ============================================================================
+// FallibleLender minmax tests
+// ============================================================================
+
+#[test]
+fn test_fallible_lender_minmax() {
+    use lender::MinMaxResult;
+
+    assert_eq!(
+        Vec::<i32>::new()
+            .into_iter()
+            .into_lender()
+            .into_fallible()
+            .minmax(),
+        Ok(MinMaxResult::NoElements)
+    );
+    assert_eq!(
+        vec![7].into_iter().into_lender().into_fallible().minmax(),
+        Ok(MinMaxResult::OneElement(7))
+    );
+    assert_eq!(
+        vec![3, 1, 5, 2, 4]
+            .into_iter()
+            .into_lender()
+            .into_fallible()
+            .minmax(),
+        Ok(MinMaxResult::MinMax(1, 5))
+    );
+}
+
+#[test]
+fn test_fallible_lender_minmax_by() {
+    assert_eq!(
+        vec![-3, 1, 3, -1]
+            .into_iter()
+            .into_lender()
+            .into_fallible()
+            .minmax_by(|a: &i32, b: &i32| Ok(a.abs().cmp(&b.abs()))),
+        Ok(lender::MinMaxResult::MinMax(1, 3))
+    );
+}
+
+#[test]
+fn test_fallible_lender_minmax_by_key() {
+    assert_eq!(
+        vec![-3, 0, 1, 5, -10]
+            .into_iter()
+            .into_lender()
+            .into_fallible()
+            .minmax_by_key(|x: &i32| Ok(x.abs())),
+        Ok(lender::MinMaxResult::MinMax(0, -10))
+    );
+}
+
+#[test]
+fn test_fallible_lender_minmax_error() {
+    // Errors from the underlying lender are propagated
+    let result = ErrorAtLender::new(vec![1, 2, 3], 1)
+        .map(covar_mut!(
+            for<'lend> |x: &'lend i32| -> Result<i32, String> { Ok(*x) }
+        ))
+        .minmax();
+    assert_eq!(result, Err("error at index 1".to_string()));
+    // Errors from the comparison function are propagated
+    let result = ErrorAtLender::new(vec![1, 2, 3], 5)
+        .map(covar_mut!(
+            for<'lend> |x: &'lend i32| -> Result<i32, String> { Ok(*x) }
+        ))
+        .minmax_by(|_: &i32, _: &i32| Err("cmp".to_string()));
+    assert_eq!(result, Err("cmp".to_string()));
+}
+
 // ============================================================================
 // FallibleLender by_ref test
 // ============================================================================
@@ -983,6 +1055,54 @@ fn test_fallible_lender_find_map() {
     assert_eq!(result, Ok(Some(40)));
 }
 
+// ============================================================================
+// FallibleLender fold_while and try_fold_lend tests
+// ============================================================================
+
+#[test]
+fn test_fallible_lender_fold_while() {
+    let mut fallible = VecFallibleLender::new(vec![1, 2, 3, 4, 5]);
+    let result = fallible.fold_while(0, |acc, x| {
+        Ok(if *x == 3 {
+            lender::FoldWhile::Done(acc)
+        } else {
+            lender::FoldWhile::Continue(acc + *x)
+        })
+    });
+    assert_eq!(result, Ok(lender::FoldWhile::Done(3)));
+    let result = fallible.fold_while(0, |acc, x| Ok(lender::FoldWhile::Continue(acc + *x)));
+    assert_eq!(result, Ok(lender::FoldWhile::Continue(9)));
+}
+
+#[test]
+fn test_fallible_lender_fold_while_error() {
+    let mut fallible = ErrorAtLender::new(vec![1, 2, 3], 1);
+    let result = fallible.fold_while(0, |acc, x| Ok(lender::FoldWhile::Continue(acc + *x)));
+    assert_eq!(result, Err("error at index 1".to_string()));
+}
+
+#[test]
+fn test_fallible_lender_try_fold_lend() {
+    use core::ops::ControlFlow;
+
+    let mut fallible = VecFallibleLender::new(vec![1, 2, 3, 4, 5]);
+    let result = fallible.try_fold_lend(0, |acc, x| {
+        let acc = acc + **x;
+        Ok(if acc > 5 {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(acc)
+        })
+    });
+    assert_eq!(result, Ok(ControlFlow::Break(&3)));
+    let result = fallible.try_fold_lend(0, |acc, x| Ok(ControlFlow::Continue(acc + **x)));
+    assert_eq!(result, Ok(ControlFlow::Continue(9)));
+
+    let mut fallible = ErrorAtLender::new(vec![1, 2, 3], 2);
+    let result = fallible.try_fold_lend(0, |acc, x| Ok(ControlFlow::Continue(acc + **x)));
+    assert_eq!(result, Err("error at index 2".to_string()));
+}
+
 // ============================================================================
 // FallibleLender partition tests
 // ============================================================================
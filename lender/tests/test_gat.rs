@@ -0,0 +1,277 @@
+#![cfg(feature = "gat")]
+//! Tests for the interoperability with GAT-based lending iterators
+
+use ::lender::gat::{self, LendingIterator};
+use ::lender::prelude::*;
+
+/// A GAT-based windows_mut, written as in a GAT-style lending-iterator crate.
+struct GatWindowsMut<'a, T> {
+    slice: &'a mut [T],
+    size: usize,
+    start: usize,
+}
+
+impl<'a, T> GatWindowsMut<'a, T> {
+    fn new(slice: &'a mut [T], size: usize) -> Self {
+        Self {
+            slice,
+            size,
+            start: 0,
+        }
+    }
+}
+
+impl<T> LendingIterator for GatWindowsMut<'_, T> {
+    type Item<'a>
+        = &'a mut [T]
+    where
+        Self: 'a;
+
+    fn next(&mut self) -> Option<&mut [T]> {
+        let window = self.slice.get_mut(self.start..self.start + self.size)?;
+        self.start += 1;
+        Some(window)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.slice.len() + 1).saturating_sub(self.start + self.size);
+        (len, Some(len))
+    }
+}
+
+fn fibonacci<L>(mut lender: L)
+where
+    L: Lender + for<'all> Lending<'all, Lend = &'all mut [u32]>,
+{
+    while let Some(w) = lender.next() {
+        w[2] = w[0] + w[1];
+    }
+}
+
+// A generic function bounded by `for<'all> LendingIterator<Item<'all> = ...>`
+// would require the iterator to be 'static, due to the `where Self: 'a` clause
+// on the GAT, so we use a macro.
+macro_rules! gat_fibonacci {
+    ($iter:expr) => {{
+        let mut iter = $iter;
+        while let Some(w) = LendingIterator::next(&mut iter) {
+            w[2] = w[0] + w[1];
+        }
+    }};
+}
+
+// ============================================================================
+// Lender to LendingIterator
+// ============================================================================
+
+#[test]
+fn test_lender_into_lending_iter() {
+    let mut data = [0, 1, 0, 0, 0, 0, 0];
+    let mut iter = lender::windows_mut(&mut data, 3).into_lending_iter();
+    assert_eq!(LendingIterator::size_hint(&iter), (5, Some(5)));
+    assert_eq!(LendingIterator::next(&mut iter), Some(&mut [0, 1, 0][..]));
+    assert_eq!(LendingIterator::size_hint(&iter), (4, Some(4)));
+    gat_fibonacci!(&mut iter);
+    assert_eq!(LendingIterator::next(&mut iter), None);
+    assert_eq!(data, [0, 1, 0, 1, 1, 2, 3]);
+
+    // Adapters are preserved
+    let mut data = [1, 2, 3, 4];
+    let mut iter = lender::windows_mut(&mut data, 2)
+        .skip(1)
+        .map(covar_mut!(
+            for<'lend> |w: &'lend mut [i32]| -> &'lend mut i32 { &mut w[1] }
+        ))
+        .into_lending_iter();
+    while let Some(x) = LendingIterator::next(&mut iter) {
+        *x *= 10;
+    }
+    assert_eq!(data, [1, 2, 30, 40]);
+}
+
+// ============================================================================
+// LendingIterator to Lender
+// ============================================================================
+
+#[test]
+fn test_from_lending_iter() {
+    let mut data = [0, 1, 0, 0, 0, 0, 0];
+    // SAFETY: &'a mut [u32] is covariant in 'a
+    let mut lender = unsafe { gat::from_lending_iter(GatWindowsMut::new(&mut data, 3)) };
+    assert_eq!(lender.size_hint(), (5, Some(5)));
+    assert_eq!(lender.next(), Some(&mut [0, 1, 0][..]));
+    assert_eq!(lender.size_hint(), (4, Some(4)));
+    while let Some(w) = lender.next() {
+        w[2] = w[0] + w[1];
+    }
+    assert_eq!(data, [0, 1, 0, 1, 1, 2, 3]);
+
+    // Empty
+    let mut data: [u32; 1] = [0];
+    // SAFETY: &'a mut [u32] is covariant in 'a
+    let mut lender = unsafe { gat::from_lending_iter(GatWindowsMut::new(&mut data, 2)) };
+    assert_eq!(lender.size_hint(), (0, Some(0)));
+    assert_eq!(lender.next(), None);
+}
+
+#[test]
+fn test_from_lending_iter_adapters() {
+    // Lender adapters taking closures need a 'static iterator (see the
+    // documentation of the gat module)
+    struct GatOwnedWindows(Vec<i32>, usize);
+
+    impl LendingIterator for GatOwnedWindows {
+        type Item<'a>
+            = &'a mut [i32]
+        where
+            Self: 'a;
+
+        fn next(&mut self) -> Option<&mut [i32]> {
+            let start = self.1;
+            self.1 += 1;
+            self.0.get_mut(start..start + 2)
+        }
+    }
+
+    // SAFETY: &'a mut [i32] is covariant in 'a
+    let lender = unsafe { gat::from_lending_iter(GatOwnedWindows(vec![1, 2, 3, 4, 5], 0)) };
+    let mut sums = vec![];
+    lender
+        .step_by(2)
+        .for_each(|w: &mut [i32]| sums.push(w.iter().sum::<i32>()));
+    assert_eq!(sums, [3, 7]);
+
+    // SAFETY: &'a mut [i32] is covariant in 'a
+    let lender = unsafe { gat::from_lending_iter(GatOwnedWindows(vec![0, 1, 0, 0, 0], 0)) };
+    let mut lender = lender.mutate(|w| w[1] += w[0]);
+    assert_eq!(lender.next(), Some(&mut [0, 1][..]));
+    assert_eq!(lender.next(), Some(&mut [1, 1][..]));
+    let iter = lender.into_inner().into_inner();
+    assert_eq!(iter.0, [0, 1, 1, 0, 0]);
+}
+
+// ============================================================================
+// Round trips
+// ============================================================================
+
+#[test]
+fn test_windows_mut_round_trip_through_gat() {
+    // Lender -> LendingIterator -> Lender
+    let mut data = [0, 1, 0, 0, 0, 0, 0];
+    let iter = lender::windows_mut(&mut data, 3).into_lending_iter();
+    // SAFETY: the items are the lends of WindowsMut, which are covariant
+    let mut lender = unsafe { gat::from_lending_iter(iter) };
+    assert_eq!(lender.size_hint(), (5, Some(5)));
+    while let Some(w) = lender.next() {
+        w[2] = w[0] + w[1];
+    }
+    assert_eq!(data, [0, 1, 1, 2, 3, 5, 8]);
+
+    // The original lender can be recovered without unsafe code
+    let mut data = [0, 1, 0, 0, 0, 0, 0];
+    let mut iter = lender::windows_mut(&mut data, 3).into_lending_iter();
+    assert_eq!(LendingIterator::next(&mut iter), Some(&mut [0, 1, 0][..]));
+    let mut lender = iter.into_inner();
+    assert_eq!(lender.next_back(), Some(&mut [0, 0, 0][..]));
+    fibonacci(lender);
+    assert_eq!(data, [0, 1, 0, 1, 1, 2, 0]);
+}
+
+#[test]
+fn test_windows_mut_round_trip_through_lender() {
+    // LendingIterator -> Lender -> LendingIterator
+    let mut data = [0, 1, 0, 0, 0, 0, 0];
+    // SAFETY: &'a mut [u32] is covariant in 'a
+    let lender = unsafe { gat::from_lending_iter(GatWindowsMut::new(&mut data, 3)) };
+    gat_fibonacci!(lender.into_lending_iter());
+    assert_eq!(data, [0, 1, 1, 2, 3, 5, 8]);
+
+    // Both encodings lend the same windows
+    let mut a = [1, 2, 3, 4, 5];
+    let mut b = a;
+    let mut gat = GatWindowsMut::new(&mut a, 2);
+    let mut lender = lender::windows_mut(&mut b, 2);
+    loop {
+        match (gat.next(), lender.next()) {
+            (Some(x), Some(y)) => {
+                assert_eq!(x, y);
+                x.swap(0, 1);
+                y.swap(0, 1);
+            }
+            (None, None) => break,
+            (x, y) => panic!("length mismatch: {x:?} vs {y:?}"),
+        }
+    }
+    assert_eq!(a, b);
+    assert_eq!(a, [2, 3, 4, 5, 1]);
+}
+
+// ============================================================================
+// gat-lending-iterator and lending-iterator crates
+// ============================================================================
+
+#[cfg(feature = "gat-lending-iterator")]
+#[test]
+fn test_windows_mut_round_trip_through_gat_lending_iterator() {
+    use ::gat_lending_iterator::ToLendingIterator;
+    use ::lender::gat::gat_lending_iterator::from_lending_iter;
+
+    // Lender -> gat_lending_iterator::LendingIterator -> Lender
+    let mut data = [0, 1, 0, 0, 0, 0, 0];
+    let mut iter = lender::windows_mut(&mut data, 3).into_lending_iter();
+    assert_eq!(
+        ::gat_lending_iterator::LendingIterator::size_hint(&iter),
+        (5, Some(5))
+    );
+    assert_eq!(
+        ::gat_lending_iterator::LendingIterator::next(&mut iter),
+        Some(&mut [0, 1, 0][..])
+    );
+    // SAFETY: the items are the lends of WindowsMut, which are covariant
+    let mut lender = unsafe { from_lending_iter(iter) };
+    assert_eq!(lender.size_hint(), (4, Some(4)));
+    // Generic functions would require a 'static iterator, as for gat::LendingIterator
+    while let Some(w) = lender.next() {
+        w[2] = w[0] + w[1];
+    }
+    assert_eq!(data, [0, 1, 0, 1, 1, 2, 3]);
+
+    // The windows_mut of gat-lending-iterator lends the same windows
+    let mut data: Vec<u32> = (0..6).collect();
+    // SAFETY: &'a mut [u32] is covariant in 'a
+    let lender = unsafe { from_lending_iter(data.clone().into_iter().windows_mut(3)) };
+    let mut windows = vec![];
+    lender.for_each(|w: &mut [u32]| windows.push(w.to_vec()));
+    let mut expected = vec![];
+    lender::windows_mut(&mut data, 3).for_each(|w| expected.push(w.to_vec()));
+    assert_eq!(windows, expected);
+}
+
+#[cfg(feature = "lending-iterator")]
+#[test]
+fn test_windows_mut_round_trip_through_lending_iterator() {
+    use ::lender::gat::lending_iterator::from_lending_iter;
+
+    // Lender -> lending_iterator::LendingIterator -> Lender
+    let mut data = [0, 1, 0, 0, 0, 0, 0];
+    let mut iter = lender::windows_mut(&mut data, 3).into_lending_iter();
+    assert_eq!(
+        ::lending_iterator::prelude::LendingIterator::next(&mut iter),
+        Some(&mut [0, 1, 0][..])
+    );
+    // SAFETY: the items are the lends of WindowsMut, which are covariant
+    let lender = unsafe { from_lending_iter(iter) };
+    // The crate emulates GATs with higher-rank trait bounds, so generic
+    // functions work even if the iterator borrows data
+    fibonacci(lender);
+    assert_eq!(data, [0, 1, 0, 1, 1, 2, 3]);
+
+    // The windows_mut of lending-iterator lends the same windows
+    let mut a = [0, 1, 0, 0, 0, 0, 0];
+    // SAFETY: &'a mut [u32; 3] is covariant in 'a
+    let mut lender = unsafe { from_lending_iter(::lending_iterator::windows_mut::<_, 3>(&mut a)) };
+    while let Some(w) = lender.next() {
+        w[2] = w[0] + w[1];
+    }
+    assert_eq!(a, [0, 1, 1, 2, 3, 5, 8]);
+}
@@ -0,0 +1,274 @@
+#![cfg(feature = "std")]
+//! Tests for the sources reading from `std::io::Read` and `std::io::BufRead`
+
+use ::lender::prelude::*;
+
+// ============================================================================
+// Records, ArrayRecords and RecordsBuffered source tests
+// ============================================================================
+
+/// A reader returning at most `chunk` bytes per read, interrupted before
+/// each successful read, and optionally failing at the end.
+struct ChunkedReader<'a> {
+    data: &'a [u8],
+    chunk: usize,
+    interrupt: bool,
+    fail_at_end: bool,
+    reads: usize,
+}
+
+impl<'a> ChunkedReader<'a> {
+    fn new(data: &'a [u8], chunk: usize) -> Self {
+        Self {
+            data,
+            chunk,
+            interrupt: false,
+            fail_at_end: false,
+            reads: 0,
+        }
+    }
+}
+
+impl std::io::Read for ChunkedReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.interrupt = !self.interrupt;
+        if self.interrupt {
+            return Err(std::io::ErrorKind::Interrupted.into());
+        }
+        if self.data.is_empty() && self.fail_at_end {
+            return Err(std::io::Error::other("failure"));
+        }
+        self.reads += 1;
+        let n = self.chunk.min(buf.len()).min(self.data.len());
+        buf[..n].copy_from_slice(&self.data[..n]);
+        self.data = &self.data[n..];
+        Ok(n)
+    }
+}
+
+fn partial_len(err: &std::io::Error) -> Option<usize> {
+    assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+    err.get_ref()?
+        .downcast_ref::<lender::PartialRecordError>()
+        .map(|e| e.len)
+}
+
+#[test]
+fn test_records() {
+    let data: Vec<u8> = (0..9).collect();
+    let mut lender = lender::records(ChunkedReader::new(&data, 2), 3);
+    let mut result = vec![];
+    while let Some(record) = lender.next().unwrap() {
+        record[0] = 10;
+        result.push(record.to_vec());
+    }
+    assert_eq!(result, [[10, 1, 2], [10, 4, 5], [10, 7, 8]]);
+    assert!(lender.next().unwrap().is_none());
+
+    // Trailing partial record
+    let mut lender = lender::records(&data[..8], 3);
+    assert_eq!(lender.next().unwrap(), Some(&mut [0, 1, 2][..]));
+    assert_eq!(lender.next().unwrap(), Some(&mut [3, 4, 5][..]));
+    assert_eq!(partial_len(&lender.next().unwrap_err()), Some(2));
+    assert!(lender.next().unwrap().is_none());
+
+    // I/O errors are not partial records
+    let mut reader = ChunkedReader::new(&data[..3], 3);
+    reader.fail_at_end = true;
+    let mut lender = lender::records(reader, 3);
+    assert!(lender.next().unwrap().is_some());
+    let err = lender.next().unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::Other);
+    assert!(lender.next().unwrap().is_none());
+}
+
+#[test]
+fn test_array_records() {
+    let data = [1_u8, 2, 3, 4, 5];
+    let mut lender = lender::array_records::<2, _>(ChunkedReader::new(&data, 1));
+    assert_eq!(lender.next().unwrap(), Some(&mut [1, 2]));
+    assert_eq!(lender.next().unwrap(), Some(&mut [3, 4]));
+    assert_eq!(partial_len(&lender.next().unwrap_err()), Some(1));
+    assert!(lender.next().unwrap().is_none());
+    assert!(lender.into_inner().data.is_empty());
+}
+
+#[test]
+fn test_records_buffered() {
+    let data: Vec<u8> = (0..100).collect();
+    for (record_len, per_read, chunk) in [(1, 1, 1), (3, 4, 5), (4, 5, 100), (7, 2, 3)] {
+        let full = data.len() / record_len * record_len;
+        let mut lender = lender::records_buffered(
+            ChunkedReader::new(&data[..full], chunk),
+            record_len,
+            per_read,
+        );
+        let mut result = vec![];
+        while let Some(record) = lender.next().unwrap() {
+            assert_eq!(record.len(), record_len);
+            result.extend_from_slice(record);
+        }
+        assert_eq!(result, &data[..full]);
+        assert!(lender.next().unwrap().is_none());
+
+        let mut lender =
+            lender::records_buffered(ChunkedReader::new(&data, chunk), record_len, per_read);
+        let mut count = 0;
+        let err = loop {
+            match lender.next() {
+                Ok(Some(_)) => count += 1,
+                Ok(None) => break None,
+                Err(e) => break Some(e),
+            }
+        };
+        assert_eq!(count, data.len() / record_len);
+        assert_eq!(
+            err.as_ref().and_then(partial_len),
+            Some(data.len() % record_len).filter(|&n| n != 0)
+        );
+    }
+
+    // Many records per read
+    let mut lender = lender::records_buffered(ChunkedReader::new(&data, 100), 5, 10);
+    let mut count = 0;
+    while lender.next().unwrap().is_some() {
+        count += 1;
+    }
+    assert_eq!(count, 20);
+    // Two reads for the records, one to detect the end of input
+    assert_eq!(lender.into_inner().reads, 3);
+}
+
+// ============================================================================
+// DelimitedRecords source tests
+// ============================================================================
+
+fn parse_all(data: &str, delim: u8) -> (Vec<Vec<String>>, Option<lender::DelimitedError>) {
+    let mut lender = lender::delimited_records(data.as_bytes(), delim);
+    let mut records = vec![];
+    loop {
+        match lender.next() {
+            Ok(Some(record)) => records.push(
+                record
+                    .str_fields()
+                    .unwrap()
+                    .iter()
+                    .map(|f| f.to_string())
+                    .collect(),
+            ),
+            Ok(None) => return (records, None),
+            Err(e) => {
+                assert!(lender.next().unwrap().is_none());
+                return (records, Some(e));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_delimited_records() {
+    let (records, err) = parse_all("a,b,c\n\n,x,\r\n\"q,1\",\"\",\"a\"\"b\"\nlast", b',');
+    assert!(err.is_none());
+    assert_eq!(
+        records,
+        [
+            vec!["a", "b", "c"],
+            vec![""],
+            vec!["", "x", ""],
+            vec!["q,1", "", "a\"b"],
+            vec!["last"],
+        ]
+    );
+
+    // Quoted fields spanning lines, and unquoted quotes
+    let (records, err) = parse_all("\"x\ny\"\tz\r\na\"b\t\"\"\r\n", b'\t');
+    assert!(err.is_none());
+    assert_eq!(records, [vec!["x\ny", "z"], vec!["a\"b", ""]]);
+
+    assert_eq!(parse_all("", b',').0.len(), 0);
+}
+
+#[test]
+fn test_delimited_records_positions() {
+    let data = "a,b\n\"multi\nline\",c\nd\n";
+    let mut lender = lender::delimited_records(data.as_bytes(), b',');
+    let mut positions = vec![];
+    while let Some(record) = lender.next().unwrap() {
+        assert_eq!(record.get(record.len()), None);
+        positions.push((
+            record.get(0).unwrap().to_vec(),
+            record.line(),
+            record.offset(),
+        ));
+    }
+    assert_eq!(
+        positions,
+        [
+            (b"a".to_vec(), 1, 0),
+            (b"multi\nline".to_vec(), 2, 4),
+            (b"d".to_vec(), 4, 19),
+        ]
+    );
+}
+
+#[test]
+fn test_delimited_records_errors() {
+    let (records, err) = parse_all("a\n\"ab\"\"c\"d,e\n", b',');
+    assert_eq!(records, [vec!["a"]]);
+    let err = err.unwrap();
+    assert!(matches!(
+        err.kind(),
+        lender::DelimitedErrorKind::TrailingCharacters
+    ));
+    assert_eq!((err.line(), err.offset()), (2, 9));
+    assert_eq!(
+        err.to_string(),
+        "unexpected characters after closing quote at line 2, byte 9"
+    );
+
+    let (records, err) = parse_all("a\nb,\"x\n\ny", b',');
+    assert_eq!(records, [vec!["a"]]);
+    let err = err.unwrap();
+    assert!(matches!(
+        err.into_kind(),
+        lender::DelimitedErrorKind::UnterminatedQuote
+    ));
+
+    let mut reader = ChunkedReader::new(b"a,b\n", 100);
+    reader.fail_at_end = true;
+    let mut lender = lender::delimited_records(std::io::BufReader::new(reader), b',');
+    assert!(lender.next().unwrap().is_some());
+    let err = lender.next().unwrap_err();
+    assert_eq!((err.line(), err.offset()), (2, 4));
+    assert!(std::error::Error::source(&err).is_some());
+}
+
+#[test]
+fn test_delimited_records_fields() {
+    let data = b"k=v,\xff,x\na,b\n";
+    let mut lender = lender::delimited_records(&data[..], b',');
+    let record = lender.next().unwrap().unwrap();
+    let [key, invalid, _] = record.fields() else {
+        panic!("expected three fields");
+    };
+    assert_eq!((*key, *invalid), (&b"k=v"[..], &b"\xff"[..]));
+    let err = record.str_fields().unwrap_err();
+    assert_eq!(err.valid_up_to(), 0);
+    assert_eq!(record.get_str(2).unwrap(), Ok("x"));
+    let record = lender.next().unwrap().unwrap();
+    assert_eq!(record.str_fields(), Ok(&["a", "b"][..]));
+    assert_eq!(record.fields()[1], b"b");
+}
+
+#[test]
+fn test_delimited_records_quote() {
+    let data = "'a,b',\"c\"\n";
+    let mut lender = lender::delimited_records(data.as_bytes(), b',').quote(Some(b'\''));
+    let record = lender.next().unwrap().unwrap();
+    assert_eq!(record.fields(), [&b"a,b"[..], b"\"c\""]);
+    let mut lender = lender::delimited_records(data.as_bytes(), b',').quote(None);
+    let record = lender.next().unwrap().unwrap();
+    assert_eq!(record.len(), 3);
+    assert_eq!(record.get_str(2).unwrap(), Ok("\"c\""));
+    assert!(record.get_str(3).is_none());
+}
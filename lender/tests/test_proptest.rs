@@ -0,0 +1,159 @@
+#![cfg(feature = "proptest")]
+//! Property-based tests of adapters against the equivalent iterator adapters
+
+use ::lender::prelude::*;
+use ::lender::testing::proptest::*;
+use ::lender::testing::*;
+use ::proptest::prelude::*;
+
+// ============================================================================
+// Arbitrary lenders
+// ============================================================================
+
+proptest! {
+    #[test]
+    fn test_arbitrary_lender_laws(lender in any::<ArbitraryLender<u8>>()) {
+        assert_double_ended_laws(|| lender.clone());
+        assert_fused_laws(|| lender.clone());
+    }
+
+    #[test]
+    fn test_arbitrary_fallible_lender_laws(
+        lender in any::<ArbitraryFallibleLender<u8, bool>>()
+    ) {
+        assert_fallible_double_ended_laws(|| lender.clone());
+        assert_fallible_fused_laws(|| lender.clone());
+    }
+
+    #[test]
+    fn test_arbitrary_lender_items(lender in arbitrary_lender(any::<i32>(), 0..16)) {
+        let expected = lender.items().to_vec();
+        assert_lender_eq_iter(lender, expected, |x| *x);
+    }
+
+    #[test]
+    fn test_arbitrary_fallible_lender_items(
+        lender in arbitrary_fallible_lender(any::<i32>(), any::<u8>(), 0..16)
+    ) {
+        let expected = lender.items().to_vec();
+        assert_fallible_lender_eq_iter(lender, expected, |x| *x);
+    }
+}
+
+#[test]
+fn test_arbitrary_lender_exact_size_hint() {
+    let mut lender = ArbitraryLender::new(vec![1, 2, 3]);
+    assert_eq!(lender.size_hint(), (3, Some(3)));
+    lender.next_back();
+    assert_eq!(lender.items(), &[1, 2]);
+    assert_eq!(lender.size_hint(), (2, Some(2)));
+}
+
+#[test]
+fn test_arbitrary_lender_slack() {
+    let lender = ArbitraryLender::with_slack(vec![1, 2, 3], 5, None);
+    assert_eq!(lender.size_hint(), (0, None));
+    let lender = ArbitraryLender::with_slack(vec![1, 2, 3], 1, Some(2));
+    assert_eq!(lender.size_hint(), (2, Some(5)));
+    let lender = ArbitraryFallibleLender::with_slack(vec![Ok(1), Err(()), Ok(3)], 1, Some(1));
+    assert_eq!(lender.size_hint(), (1, Some(4)));
+}
+
+// ============================================================================
+// Differential helpers
+// ============================================================================
+
+#[test]
+#[should_panic(expected = "the lend in position 1 differs from the item of the iterator")]
+fn test_assert_lender_eq_iter_differs() {
+    assert_lender_eq_iter(ArbitraryLender::new(vec![1, 2, 3]), [1, 3, 3], |x| *x);
+}
+
+#[test]
+#[should_panic(expected = "the lender has more than 2 lends")]
+fn test_assert_lender_eq_iter_longer() {
+    let lender = ArbitraryLender::with_slack(vec![1, 2, 3], 3, None);
+    assert_lender_eq_iter(lender, [1, 2], |x| *x);
+}
+
+#[test]
+#[should_panic(expected = "the result in position 1 differs from the item of the iterator")]
+fn test_assert_fallible_lender_eq_iter_differs() {
+    assert_fallible_lender_eq_iter(
+        ArbitraryFallibleLender::new(vec![Ok(1), Err(2), Ok(3)]),
+        [Ok(1), Ok(2), Ok(3)],
+        |x| *x,
+    );
+}
+
+// ============================================================================
+// Adapters against iterators
+// ============================================================================
+
+proptest! {
+    #[test]
+    fn test_filter_map_skip(lender in any::<ArbitraryLender<i32>>(), n in 0..8_usize) {
+        let expected = lender.items().to_vec();
+        assert_lender_eq_iter(
+            lender
+                .filter(|x| **x % 3 != 0)
+                .map(covar_mut!(for<'all> |x: &'all i32| -> i32 { x.wrapping_mul(2) }))
+                .skip(n),
+            expected.into_iter().filter(|x| x % 3 != 0).map(|x| x.wrapping_mul(2)).skip(n),
+            |x| x,
+        );
+    }
+
+    #[test]
+    fn test_rev_step_by_take(
+        lender in any::<ArbitraryLender<i32>>(),
+        step in 1..4_usize,
+        n in 0..16_usize,
+    ) {
+        let expected = lender.items().to_vec();
+        assert_lender_eq_iter(
+            lender.rev().step_by(step).take(n),
+            expected.into_iter().rev().step_by(step).take(n),
+            |x| *x,
+        );
+    }
+
+    #[test]
+    fn test_chain_enumerate(a in any::<ArbitraryLender<u8>>(), b in any::<ArbitraryLender<u8>>()) {
+        let expected = a.items().iter().chain(b.items()).copied().collect::<Vec<_>>();
+        assert_lender_eq_iter(
+            a.chain(b).enumerate(),
+            expected.into_iter().enumerate(),
+            |(i, x)| (i, *x),
+        );
+    }
+
+    #[test]
+    fn test_skip_while_take_while(lender in any::<ArbitraryLender<u8>>(), a in any::<u8>(), b in any::<u8>()) {
+        let expected = lender.items().to_vec();
+        assert_lender_eq_iter(
+            lender.skip_while(|x| **x < a).take_while(|x| **x > b),
+            expected.into_iter().skip_while(|x| *x < a).take_while(|x| *x > b),
+            |x| *x,
+        );
+    }
+
+    #[test]
+    fn test_fallible_filter_map_err(lender in any::<ArbitraryFallibleLender<i32, u8>>()) {
+        let expected = lender.items().to_vec();
+        assert_fallible_lender_eq_iter(
+            lender.filter(|x| Ok(**x % 2 == 0)).map_err(u32::from),
+            expected
+                .into_iter()
+                .filter(|x| !matches!(x, Ok(x) if x % 2 != 0))
+                .map(|x| x.map_err(u32::from)),
+            |x| *x,
+        );
+    }
+
+    #[test]
+    fn test_fallible_rev(lender in any::<ArbitraryFallibleLender<i32, u8>>()) {
+        let expected = lender.items().to_vec();
+        assert_fallible_lender_eq_iter(lender.rev(), expected.into_iter().rev(), |x| *x);
+    }
+}
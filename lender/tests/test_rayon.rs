@@ -0,0 +1,187 @@
+#![cfg(feature = "rayon")]
+//! Tests for the parallel consumption of lenders with rayon
+
+use std::panic::{AssertUnwindSafe, catch_unwind};
+use std::rc::Rc;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use ::lender::prelude::*;
+use rayon::prelude::*;
+
+/// Maps windows to owned vectors, as references are not converted by
+/// `ToOwned` into owned values.
+macro_rules! to_vec {
+    ($t:ty) => {
+        covar_mut!(for<'lend> |w: &'lend mut [$t]| -> Vec<$t> { w.to_vec() })
+    };
+}
+
+// ============================================================================
+// par_for_each_owned
+// ============================================================================
+
+#[test]
+fn test_par_for_each_owned() {
+    for batch_size in [1, 3, 7, 100, 1000] {
+        let mut data = (0..100).collect::<Vec<u32>>();
+        let seen = Mutex::new(vec![]);
+        lender::windows_mut(&mut data, 2)
+            .map(to_vec!(u32))
+            .par_for_each_owned(batch_size, |w: Vec<u32>| {
+                seen.lock().unwrap().push(w[0] + w[1]);
+            });
+        let mut seen = seen.into_inner().unwrap();
+        seen.sort_unstable();
+        assert_eq!(seen, (0..99).map(|i| 2 * i + 1).collect::<Vec<_>>());
+    }
+
+    // Empty
+    let count = AtomicUsize::new(0);
+    lender::from_iter(std::iter::empty::<u32>()).par_for_each_owned(4, |_: u32| {
+        count.fetch_add(1, Ordering::Relaxed);
+    });
+    assert_eq!(count.into_inner(), 0);
+}
+
+#[test]
+fn test_par_for_each_owned_non_send_lender() {
+    // The lender is consumed on the calling thread, so it need not be Send
+    let shared = Rc::new((0..50).collect::<Vec<u64>>());
+    let lender =
+        lender::from_iter(shared.iter().copied().collect::<Vec<_>>().into_iter()).inspect(|_| {
+            let _ = Rc::clone(&shared);
+        });
+    let sum = AtomicUsize::new(0);
+    lender.par_for_each_owned(8, |x: u64| {
+        sum.fetch_add(x as usize, Ordering::Relaxed);
+    });
+    assert_eq!(sum.into_inner(), (0..50).sum::<usize>());
+}
+
+#[test]
+fn test_par_for_each_owned_in_pool() {
+    // Calling from a worker of a single-thread pool must not deadlock when
+    // the limit of batches in flight is reached
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(1)
+        .build()
+        .unwrap();
+    let count = AtomicUsize::new(0);
+    pool.install(|| {
+        lender::from_iter(0..1000).par_for_each_owned(3, |_: i32| {
+            count.fetch_add(1, Ordering::Relaxed);
+        });
+    });
+    assert_eq!(count.into_inner(), 1000);
+
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(2)
+        .build()
+        .unwrap();
+    let v: Vec<i32> =
+        pool.install(|| lender::from_iter(0..1000).par_map_collect(1, |x: i32| x * 2));
+    assert_eq!(v, (0..1000).map(|x| x * 2).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_par_for_each_owned_panics() {
+    // A panicking closure must not block the calling thread
+    let processed = AtomicUsize::new(0);
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        lender::from_iter(0..1000).par_for_each_owned(2, |x: i32| {
+            if x == 10 {
+                panic!("boom");
+            }
+            processed.fetch_add(1, Ordering::Relaxed);
+        });
+    }));
+    assert!(result.is_err());
+    assert!(processed.into_inner() < 1000);
+}
+
+#[test]
+#[should_panic(expected = "batch size must be non-zero")]
+fn test_par_for_each_owned_zero_batch_size() {
+    lender::from_iter(0..10).par_for_each_owned(0, |_: i32| {});
+}
+
+// ============================================================================
+// par_map_collect
+// ============================================================================
+
+#[test]
+fn test_par_map_collect() {
+    for batch_size in [1, 2, 5, 64] {
+        let mut data = (0..200).collect::<Vec<u64>>();
+        let sums: Vec<u64> = lender::windows_mut(&mut data, 3)
+            .map(to_vec!(u64))
+            .par_map_collect(batch_size, |w: Vec<u64>| w.iter().sum());
+        assert_eq!(sums, (0..198).map(|i| 3 * i + 3).collect::<Vec<_>>());
+    }
+
+    // Other collections
+    let strings: String = lender::from_iter(["a", "b", "c"].into_iter())
+        .par_map_collect(2, |s: &str| s.to_uppercase());
+    assert_eq!(strings, "ABC");
+
+    // Empty
+    let v: Vec<u8> = lender::from_iter(std::iter::empty::<u8>()).par_map_collect(4, |x: u8| x);
+    assert!(v.is_empty());
+}
+
+// ============================================================================
+// par_chunks_mut
+// ============================================================================
+
+#[test]
+fn test_par_chunks_mut_lender() {
+    let mut data = [0, 1, 2, 3, 4, 5, 6];
+    let mut lender = lender::par_chunks_mut(&mut data, 3);
+    assert_eq!(lender.len(), 3);
+    assert_eq!(lender.next(), Some(&mut [0, 1, 2][..]));
+    assert_eq!(lender.next_back(), Some(&mut [6][..]));
+    assert_eq!(lender.len(), 1);
+    assert_eq!(lender.next_back(), Some(&mut [3, 4, 5][..]));
+    assert_eq!(lender.next(), None);
+    assert_eq!(lender.next_back(), None);
+
+    // Exact division
+    let mut data = [0; 6];
+    let mut lender = lender::par_chunks_mut(&mut data, 2);
+    assert_eq!(lender.next_back().map(|c| c.len()), Some(2));
+    assert_eq!(lender.into_remainder().len(), 4);
+
+    // Empty
+    let mut data: [u8; 0] = [];
+    let mut lender = lender::par_chunks_mut(&mut data, 2);
+    assert_eq!(lender.len(), 0);
+    assert_eq!(lender.next(), None);
+}
+
+#[test]
+fn test_par_chunks_mut_into_par_iter() {
+    let mut data = (0..1000).collect::<Vec<u32>>();
+    let mut lender = lender::par_chunks_mut(&mut data, 7);
+    lender.next().unwrap().fill(0);
+    lender.next_back().unwrap().fill(0);
+    let chunks = lender
+        .into_par_iter()
+        .map(|chunk| chunk.len())
+        .collect::<Vec<_>>();
+    assert_eq!(chunks.len(), 1000 / 7 - 1);
+    assert!(chunks.iter().all(|&len| len == 7));
+
+    let mut data = (0..1000).collect::<Vec<u32>>();
+    lender::par_chunks_mut(&mut data, 10)
+        .into_par_iter()
+        .for_each(|chunk| chunk.reverse());
+    assert_eq!(&data[..3], [9, 8, 7]);
+    assert_eq!(&data[990..993], [999, 998, 997]);
+}
+
+#[test]
+#[should_panic(expected = "chunk size must be non-zero")]
+fn test_par_chunks_mut_zero_chunk_size() {
+    let _ = lender::par_chunks_mut(&mut [1, 2, 3], 0);
+}
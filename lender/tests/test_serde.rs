@@ -0,0 +1,237 @@
+#![cfg(feature = "serde")]
+//! Tests for the serialization and deserialization of lenders with Serde
+
+use std::fmt;
+
+use ::lender::prelude::*;
+use ::lender::serde::{SerializeLender, seq_elements};
+use serde::de::{Deserializer, SeqAccess, Visitor};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+struct Record {
+    id: u32,
+    tags: Vec<String>,
+}
+
+/// Collects some information about the elements of a sequence using
+/// [`seq_elements`], checking that the same value is lent every time.
+struct Stats;
+
+#[derive(Debug, PartialEq)]
+struct Summary {
+    count: usize,
+    ids: u32,
+    size_hint: (usize, Option<usize>),
+}
+
+impl<'de> Visitor<'de> for Stats {
+    type Value = Summary;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a sequence of records")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Summary, A::Error> {
+        let mut lender = seq_elements::<Record, _>(seq);
+        let size_hint = lender.size_hint();
+        let mut summary = Summary {
+            count: 0,
+            ids: 0,
+            size_hint,
+        };
+        let mut last: Option<*const Record> = None;
+        while let Some(record) = lender.next()? {
+            // The same value is lent every time
+            let ptr = &*record as *const Record;
+            assert!(last.is_none_or(|last| last == ptr));
+            last = Some(ptr);
+            summary.count += 1;
+            summary.ids += record.id;
+        }
+        // Fused
+        assert!(lender.next()?.is_none());
+        assert_eq!(lender.size_hint(), (0, Some(0)));
+        Ok(summary)
+    }
+}
+
+// ============================================================================
+// SerializeLender
+// ============================================================================
+
+#[test]
+fn test_serialize_lender() {
+    let mut data = [1, 2, 3, 4];
+    let lender = SerializeLender::new(lender::windows_mut(&mut data, 3));
+    assert_eq!(serde_json::to_string(&lender).unwrap(), "[[1,2,3],[2,3,4]]");
+
+    // Only once
+    let err = serde_json::to_string(&lender).unwrap_err();
+    assert!(err.to_string().contains("only once"));
+    assert!(lender.into_inner().is_none());
+
+    // Not serialized
+    let lender = SerializeLender::from(lender::empty::<lend!(&'lend u8)>());
+    assert!(lender.into_inner().is_some());
+
+    // Empty
+    let lender = SerializeLender::new(lender::empty::<lend!(&'lend u8)>());
+    assert_eq!(serde_json::to_string(&lender).unwrap(), "[]");
+
+    // Structured lends
+    let records = [
+        Record {
+            id: 1,
+            tags: vec!["a".into()],
+        },
+        Record {
+            id: 2,
+            tags: vec![],
+        },
+    ];
+    let lender = SerializeLender::new(lender::from_iter_ref(records.iter()));
+    assert_eq!(
+        serde_json::to_string(&lender).unwrap(),
+        r#"[{"id":1,"tags":["a"]},{"id":2,"tags":[]}]"#
+    );
+}
+
+#[test]
+fn test_serialize_lender_length() {
+    // CBOR writes definite lengths when the size hint is exact, and
+    // indefinite lengths otherwise
+    let data = [1_u8, 2, 3];
+    let mut exact = vec![];
+    ciborium::into_writer(
+        &SerializeLender::new(lender::from_iter_ref(data.into_iter())),
+        &mut exact,
+    )
+    .unwrap();
+    assert_eq!(exact, [0x83, 1, 2, 3]);
+
+    let mut inexact = vec![];
+    ciborium::into_writer(
+        &SerializeLender::new(lender::from_iter_ref(data.into_iter()).filter(|x| **x != 2)),
+        &mut inexact,
+    )
+    .unwrap();
+    assert_eq!(inexact, [0x9f, 1, 3, 0xff]);
+}
+
+// ============================================================================
+// seq_elements
+// ============================================================================
+
+#[test]
+fn test_seq_elements_json() {
+    let json = r#"[
+        {"id": 1, "tags": ["a", "b", "c"]},
+        {"id": 2, "tags": []},
+        {"id": 3, "tags": ["d"]}
+    ]"#;
+    let mut de = serde_json::Deserializer::from_reader(json.as_bytes());
+    let summary = de.deserialize_seq(Stats).unwrap();
+    assert_eq!(summary.count, 3);
+    assert_eq!(summary.ids, 6);
+    assert_eq!(summary.size_hint, (0, None));
+
+    // Empty
+    let mut de = serde_json::Deserializer::from_str("[]");
+    assert_eq!(de.deserialize_seq(Stats).unwrap().count, 0);
+}
+
+#[test]
+fn test_seq_elements_reuse() {
+    // Vec deserializes in place, reusing its allocation
+    struct Capacities;
+
+    impl<'de> Visitor<'de> for Capacities {
+        type Value = Vec<(Vec<u32>, usize)>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a sequence of sequences of integers")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+            let mut lender = seq_elements::<Vec<u32>, _>(seq);
+            let mut result = vec![];
+            while let Some(v) = lender.next()? {
+                result.push((v.clone(), v.capacity()));
+            }
+            Ok(result)
+        }
+    }
+
+    let mut de = serde_json::Deserializer::from_str("[[1, 2, 3, 4], [5], [], [6, 7]]");
+    let result = de.deserialize_seq(Capacities).unwrap();
+    let values = result.iter().map(|(v, _)| v.clone()).collect::<Vec<_>>();
+    assert_eq!(values, [vec![1, 2, 3, 4], vec![5], vec![], vec![6, 7]]);
+    assert!(result.iter().all(|&(_, capacity)| capacity >= 4));
+}
+
+#[test]
+fn test_seq_elements_json_error() {
+    struct FirstError;
+
+    impl<'de> Visitor<'de> for FirstError {
+        type Value = Vec<u32>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a sequence of integers")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Vec<u32>, A::Error> {
+            let mut lender = seq_elements::<u32, _>(seq);
+            let mut seen = vec![];
+            loop {
+                match lender.next() {
+                    Ok(Some(&mut x)) => seen.push(x),
+                    Ok(None) => return Ok(seen),
+                    Err(err) => {
+                        // Fused after an error
+                        assert_eq!(lender.next().ok(), Some(None));
+                        return Err(err);
+                    }
+                }
+            }
+        }
+    }
+
+    let mut de = serde_json::Deserializer::from_str(r#"[1, 2, "x", 4]"#);
+    let err = de.deserialize_seq(FirstError).unwrap_err();
+    assert!(err.is_data());
+    assert_eq!(err.column(), 10);
+}
+
+#[test]
+fn test_seq_elements_cbor() {
+    let records = (0..100)
+        .map(|id| Record {
+            id,
+            tags: vec![id.to_string(); (id % 3) as usize],
+        })
+        .collect::<Vec<_>>();
+    let mut bytes = vec![];
+    ciborium::into_writer(
+        &SerializeLender::new(lender::from_iter_ref(records.iter())),
+        &mut bytes,
+    )
+    .unwrap();
+
+    // ciborium does not expose its deserializer, so we go through a type
+    // whose Deserialize implementation uses the visitor
+    struct Wrapper(Summary);
+
+    impl<'de> Deserialize<'de> for Wrapper {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_seq(Stats).map(Wrapper)
+        }
+    }
+
+    let Wrapper(summary) = ciborium::from_reader(bytes.as_slice()).unwrap();
+    assert_eq!(summary.count, 100);
+    assert_eq!(summary.ids, (0..100).sum::<u32>());
+    // CBOR arrays have a definite length
+    assert_eq!(summary.size_hint, (100, Some(100)));
+}
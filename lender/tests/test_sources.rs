@@ -1,6 +1,7 @@
 mod common;
 use ::lender::prelude::*;
 use common::*;
+use std::collections::VecDeque;
 
 // ============================================================================
 // WindowsMut tests
@@ -81,6 +82,326 @@ fn test_array_windows_mut_zero_panics() {
     let _ = lender::array_windows_mut::<_, 0>(&mut data);
 }
 
+// ============================================================================
+// Permutation source tests
+// ============================================================================
+
+fn collect_slices<L>(mut lender: L) -> Vec<Vec<i32>>
+where
+    L: Lender + ExactSizeLender + for<'all> Lending<'all, Lend = &'all [i32]>,
+{
+    let mut result = Vec::new();
+    let total = lender.len();
+    while let Some(p) = lender.next() {
+        result.push(p.to_vec());
+        assert_eq!(lender.len(), total - result.len());
+    }
+    assert_eq!(result.len(), total);
+    assert!(lender.next().is_none());
+    result
+}
+
+#[test]
+fn test_permutations_heap() {
+    for n in 0..=6 {
+        let perms = collect_slices(lender::permutations((0..n).collect()));
+        let factorial = (1..=n as usize).product::<usize>();
+        assert_eq!(perms.len(), factorial);
+        // All permutations are distinct
+        let mut sorted = perms.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(sorted.len(), factorial);
+        // Consecutive permutations differ by a single swap
+        for w in perms.windows(2) {
+            let diff = w[0].iter().zip(&w[1]).filter(|(a, b)| a != b).count();
+            assert_eq!(diff, 2);
+        }
+    }
+}
+
+#[test]
+fn test_permutations_into_inner() {
+    let mut lender = lender::permutations(vec![1, 2, 3]);
+    lender.next();
+    lender.next();
+    assert_eq!(lender.into_inner(), vec![2, 1, 3]);
+}
+
+#[test]
+fn test_permutations_overflow() {
+    let lender = lender::permutations(vec![0_u8; 21]);
+    assert_eq!(lender.size_hint(), (usize::MAX, None));
+    let lender = lender::lexicographic_permutations((0..21).collect());
+    assert_eq!(lender.size_hint(), (usize::MAX, None));
+    // With many repeated elements the count fits
+    let lender = lender::lexicographic_permutations([vec![0; 30], vec![1; 2]].concat());
+    assert_eq!(lender.len(), 32 * 31 / 2);
+}
+
+#[test]
+fn test_lexicographic_permutations() {
+    for data in [
+        vec![],
+        vec![1],
+        vec![3, 1, 2],
+        vec![2, 2, 1, 1],
+        vec![4, 1, 3, 1, 2],
+        vec![1, 1, 1],
+    ] {
+        let perms = collect_slices(lender::lexicographic_permutations(data.clone()));
+        let mut expected = collect_slices(lender::permutations(data));
+        expected.sort();
+        expected.dedup();
+        assert_eq!(perms, expected);
+    }
+}
+
+// ============================================================================
+// Combination source tests
+// ============================================================================
+
+fn collect_indices<L>(mut lender: L) -> Vec<Vec<usize>>
+where
+    L: Lender + ExactSizeLender + FusedLender + for<'all> Lending<'all, Lend = &'all [usize]>,
+{
+    let mut result = Vec::new();
+    let total = lender.len();
+    while let Some(c) = lender.next() {
+        result.push(c.to_vec());
+        assert_eq!(lender.len(), total - result.len());
+    }
+    assert_eq!(result.len(), total);
+    assert!(lender.next().is_none());
+    result
+}
+
+#[test]
+fn test_combinations() {
+    for n in 0..=6 {
+        for k in 0..=n + 1 {
+            let combs = collect_indices(lender::combinations(n, k));
+            // Brute force: sorted k-subsets of 0..n in lexicographic order
+            let expected: Vec<Vec<usize>> = (0..1_usize << n)
+                .filter(|m| m.count_ones() as usize == k)
+                .map(|m| (0..n).filter(|i| m & (1 << i) != 0).collect::<Vec<_>>())
+                .collect::<std::collections::BTreeSet<_>>()
+                .into_iter()
+                .collect();
+            assert_eq!(combs, expected, "n = {n}, k = {k}");
+        }
+    }
+}
+
+#[test]
+fn test_combinations_overflow() {
+    let lender = lender::combinations(200, 100);
+    assert_eq!(lender.size_hint(), (usize::MAX, None));
+    let lender = lender::combinations(200, 2);
+    assert_eq!(lender.len(), 200 * 199 / 2);
+}
+
+#[test]
+fn test_combinations_of() {
+    let data = [10, 20, 30, 40];
+    let mut lender = lender::combinations_of(&data, 3);
+    assert_eq!(lender.len(), 4);
+    let mut result = Vec::new();
+    while let Some(c) = lender.next() {
+        result.push(c.iter().map(|&&x| x).collect::<Vec<_>>());
+    }
+    assert_eq!(
+        result,
+        vec![
+            vec![10, 20, 30],
+            vec![10, 20, 40],
+            vec![10, 30, 40],
+            vec![20, 30, 40]
+        ]
+    );
+    assert_eq!(lender.len(), 0);
+    assert!(lender.next().is_none());
+
+    let mut lender = lender::combinations_of(&data, 0);
+    assert_eq!(lender.next(), Some(&[][..]));
+    assert!(lender.next().is_none());
+    let mut lender = lender::combinations_of(&data, 5);
+    assert!(lender.next().is_none());
+}
+
+#[test]
+fn test_powerset_gray_code() {
+    for n in 0..=6 {
+        let subsets = collect_indices(lender::powerset(n));
+        assert_eq!(subsets.len(), 1 << n);
+        assert!(subsets[0].is_empty());
+        // All subsets are distinct and sorted
+        let distinct: std::collections::BTreeSet<_> = subsets.iter().cloned().collect();
+        assert_eq!(distinct.len(), 1 << n);
+        assert!(subsets.iter().all(|s| s.windows(2).all(|w| w[0] < w[1])));
+        // Consecutive subsets differ by exactly one element
+        for w in subsets.windows(2) {
+            let (a, b) = (&w[0], &w[1]);
+            let diff = a.iter().filter(|x| !b.contains(x)).count()
+                + b.iter().filter(|x| !a.contains(x)).count();
+            assert_eq!(diff, 1);
+        }
+    }
+    assert_eq!(lender::powerset(200).size_hint(), (usize::MAX, None));
+}
+
+// ============================================================================
+// Cartesian product source tests
+// ============================================================================
+
+#[test]
+fn test_product_indices() {
+    for dims in [&[][..], &[0], &[3], &[2, 3], &[3, 0, 2], &[2, 1, 3, 2]] {
+        let tuples = collect_indices(lender::product_indices(dims));
+        // Brute force: all tuples in lexicographic order
+        let mut expected: Vec<Vec<usize>> = vec![vec![]];
+        for &d in dims {
+            expected = expected
+                .into_iter()
+                .flat_map(|t| {
+                    (0..d).map(move |i| {
+                        let mut t = t.clone();
+                        t.push(i);
+                        t
+                    })
+                })
+                .collect();
+        }
+        assert_eq!(tuples, expected, "dims = {dims:?}");
+
+        // Backwards
+        let mut lender = lender::product_indices(dims);
+        let mut back = Vec::new();
+        while let Some(t) = lender.next_back() {
+            back.push(t.to_vec());
+        }
+        back.reverse();
+        assert_eq!(back, expected, "dims = {dims:?}");
+    }
+}
+
+#[test]
+fn test_product_indices_meet_in_the_middle() {
+    let expected = collect_indices(lender::product_indices(&[3, 2, 2]));
+    for front in 0..=expected.len() {
+        let mut lender = lender::product_indices(&[3, 2, 2]);
+        let mut result = Vec::new();
+        for _ in 0..front {
+            result.push(lender.next().unwrap().to_vec());
+        }
+        let mut back = Vec::new();
+        while let Some(t) = lender.next_back() {
+            back.push(t.to_vec());
+        }
+        assert!(lender.next().is_none());
+        back.reverse();
+        result.extend(back);
+        assert_eq!(result, expected);
+    }
+}
+
+#[test]
+fn test_product_indices_overflow() {
+    let mut lender = lender::product_indices(&[usize::MAX, 2]);
+    assert_eq!(lender.size_hint(), (usize::MAX, None));
+    assert_eq!(lender.next(), Some(&[0, 0][..]));
+    assert_eq!(lender.next(), Some(&[0, 1][..]));
+    assert_eq!(lender.next(), Some(&[1, 0][..]));
+    assert_eq!(lender.next_back(), Some(&[usize::MAX - 1, 1][..]));
+    assert_eq!(lender.next_back(), Some(&[usize::MAX - 1, 0][..]));
+    assert_eq!(lender.size_hint(), (usize::MAX, None));
+    let a = [0_u8; 256];
+    let slices = [&a[..]; 8];
+    let mut lender = lender::multi_cartesian(&slices);
+    assert_eq!(lender.size_hint(), (usize::MAX, None));
+    assert_eq!(lender.next(), Some(&[&0; 8][..]));
+    // With a zero dimension the count fits
+    let lender = lender::product_indices(&[usize::MAX, usize::MAX, 0]);
+    assert_eq!(lender.len(), 0);
+}
+
+#[test]
+fn test_multi_cartesian() {
+    let a = [1, 2];
+    let b = [10];
+    let c = [100, 200, 300];
+    let mut lender = lender::multi_cartesian(&[&a[..], &b[..], &c[..]]);
+    assert_eq!(lender.len(), 6);
+    assert_eq!(lender.next(), Some(&[&1, &10, &100][..]));
+    assert_eq!(lender.next_back(), Some(&[&2, &10, &300][..]));
+    assert_eq!(lender.next_back(), Some(&[&2, &10, &200][..]));
+    assert_eq!(lender.next(), Some(&[&1, &10, &200][..]));
+    assert_eq!(lender.next(), Some(&[&1, &10, &300][..]));
+    assert_eq!(lender.next(), Some(&[&2, &10, &100][..]));
+    assert_eq!(lender.len(), 0);
+    assert_eq!(lender.next(), None);
+    assert_eq!(lender.next_back(), None);
+
+    let mut lender = lender::multi_cartesian::<i32>(&[]);
+    assert_eq!(lender.next(), Some(&[][..]));
+    assert_eq!(lender.next(), None);
+    let mut lender = lender::multi_cartesian(&[&a[..], &[]]);
+    assert_eq!(lender.next(), None);
+}
+
+// ============================================================================
+// PairsMut and NeighborsMut source tests
+// ============================================================================
+
+#[test]
+fn test_pairs_mut() {
+    for n in 0..=6 {
+        let mut data: Vec<(usize, usize)> = (0..n).map(|i| (i, 0)).collect();
+        let mut lender = data.pairs_mut();
+        let total = lender.len();
+        assert_eq!(total, n * n.saturating_sub(1) / 2);
+        let mut pairs = Vec::new();
+        while let Some((a, b)) = lender.next() {
+            pairs.push((a.0, b.0));
+            a.1 += 1;
+            b.1 += 1;
+            assert_eq!(lender.len(), total - pairs.len());
+        }
+        assert!(lender.next().is_none());
+        assert_eq!(lender.len(), 0);
+        let expected: Vec<_> = (0..n)
+            .flat_map(|i| (i + 1..n).map(move |j| (i, j)))
+            .collect();
+        assert_eq!(pairs, expected);
+        // Each element belongs to n - 1 pairs
+        assert!(data.iter().all(|&(_, c)| c == n - 1));
+    }
+}
+
+#[test]
+fn test_neighbors_mut() {
+    let mut data = [1, 2, 3, 4];
+    let mut lender = lender::neighbors_mut(&mut data);
+    assert_eq!(lender.len(), 4);
+    let (prefix, x, suffix) = lender.next().unwrap();
+    assert_eq!((prefix, &*x, suffix), (&[][..], &1, &[2, 3, 4][..]));
+    *x = 10;
+    let (prefix, x, suffix) = lender.next_back().unwrap();
+    assert_eq!((prefix, &*x, suffix), (&[10, 2, 3][..], &4, &[][..]));
+    let (prefix, x, suffix) = lender.next().unwrap();
+    assert_eq!((prefix, &*x, suffix), (&[10][..], &2, &[3, 4][..]));
+    *x = prefix[0] + suffix[1];
+    assert_eq!(lender.len(), 1);
+    let (prefix, x, suffix) = lender.next_back().unwrap();
+    assert_eq!((prefix, &*x, suffix), (&[10, 14][..], &3, &[4][..]));
+    assert!(lender.next().is_none());
+    assert!(lender.next_back().is_none());
+    assert_eq!(data, [10, 14, 3, 4]);
+
+    let mut empty: [i32; 0] = [];
+    assert!(empty.neighbors_mut().next().is_none());
+}
+
 // ============================================================================
 // Sources tests - empty, once, repeat, from_fn, etc.
 // ============================================================================
@@ -235,6 +556,392 @@ fn test_source_once_with_basic() {
     assert_eq!(once_with.next(), None);
 }
 
+// ============================================================================
+// IterateMut and SuccessorsMut source tests
+// ============================================================================
+
+#[test]
+fn test_iterate_mut() {
+    let mut lender = lender::iterate_mut([0_u32; 4], |state: &mut [u32; 4]| {
+        state.rotate_right(1);
+        state[0] += 1;
+        state.iter().sum::<u32>() < 10
+    });
+    assert_eq!(lender.size_hint(), (1, None));
+    assert_eq!(lender.next(), Some(&mut [0, 0, 0, 0]));
+    assert_eq!(lender.size_hint(), (0, None));
+    // Changes through the lend are seen by the next step
+    let state = lender.next().unwrap();
+    assert_eq!(state, &mut [1, 0, 0, 0]);
+    state[3] = 7;
+    assert_eq!(lender.next(), Some(&mut [8, 1, 0, 0]));
+    assert_eq!(lender.next(), None);
+    assert_eq!(lender.size_hint(), (0, Some(0)));
+    // Fused: the step function is not called again
+    assert_eq!(lender.next(), None);
+    assert_eq!(lender.into_inner(), [1, 8, 1, 0]);
+}
+
+#[test]
+fn test_successors_mut() {
+    let mut lender = lender::successors_mut(Some(vec![1]), |v: &mut Vec<i32>| {
+        v.push(v.len() as i32 + 1);
+        v.len() < 3
+    });
+    assert_eq!(lender.next(), Some(&vec![1]));
+    assert_eq!(lender.next(), Some(&vec![1, 2]));
+    assert_eq!(lender.next(), None);
+    assert_eq!(lender.next(), None);
+    assert_eq!(lender.into_inner(), Some(vec![1, 2, 3]));
+
+    let mut lender = lender::successors_mut(None, |_: &mut i32| true);
+    assert_eq!(lender.size_hint(), (0, Some(0)));
+    assert_eq!(lender.next(), None);
+}
+
+// ============================================================================
+// Windows2dMut and ArrayWindows2dMut source tests
+// ============================================================================
+
+#[test]
+fn test_windows2d_mut() {
+    #[rustfmt::skip]
+    let mut m = [
+        1, 2, 3, 4,
+        5, 6, 7, 8,
+        9, 10, 11, 12,
+    ];
+    let mut lender = lender::windows2d_mut(&mut m, 3, 4, 2, 3);
+    assert_eq!(lender.len(), 4);
+    let w = lender.next().unwrap();
+    assert_eq!((w.height(), w.width()), (2, 3));
+    assert_eq!(w.row(0), &[1, 2, 3]);
+    assert_eq!(w.row(1), &[5, 6, 7]);
+    assert_eq!(w.get(1, 2), Some(&7));
+    assert_eq!(w.get(2, 0), None);
+    assert_eq!(w.get(0, 3), None);
+    let mut w = lender.next_back().unwrap();
+    assert_eq!(w.row(0), &[6, 7, 8]);
+    assert_eq!(w.row(1), &[10, 11, 12]);
+    w.row_mut(1).fill(0);
+    let mut w = lender.next().unwrap();
+    assert_eq!(w.row(0), &[2, 3, 4]);
+    *w.get_mut(0, 0).unwrap() = 20;
+    assert_eq!(lender.len(), 1);
+    let w = lender.next().unwrap();
+    assert_eq!(w.row(0), &[5, 6, 7]);
+    assert_eq!(w.row(1), &[9, 0, 0]);
+    assert!(lender.next().is_none());
+    assert!(lender.next_back().is_none());
+    assert_eq!(m, [1, 20, 3, 4, 5, 6, 7, 8, 9, 0, 0, 0]);
+}
+
+#[test]
+fn test_windows2d_mut_sums() {
+    // In-place 2x2 box filter, writing each sum to the top-left corner
+    let mut m: Vec<i32> = (0..9).collect();
+    lender::windows2d_mut(&mut m, 3, 3, 2, 2).for_each(|mut w| {
+        let sum = w.row(0).iter().chain(w.row(1)).sum();
+        *w.get_mut(0, 0).unwrap() = sum;
+    });
+    assert_eq!(m, [8, 12, 2, 20, 24, 5, 6, 7, 8]);
+}
+
+#[test]
+fn test_windows2d_mut_empty() {
+    let mut m = [0; 6];
+    let mut lender = lender::windows2d_mut(&mut m, 2, 3, 3, 1);
+    assert_eq!(lender.len(), 0);
+    assert!(lender.next().is_none());
+    let mut lender = lender::windows2d_mut(&mut m, 2, 3, 1, 4);
+    assert!(lender.next_back().is_none());
+    let mut lender = lender::windows2d_mut(&mut m[..0], 0, 3, 1, 1);
+    assert!(lender.next().is_none());
+}
+
+#[test]
+#[should_panic(expected = "the slice length must be rows * cols")]
+fn test_windows2d_mut_bad_shape() {
+    let _ = lender::windows2d_mut(&mut [0; 5], 2, 3, 1, 1);
+}
+
+#[test]
+#[should_panic(expected = "window size must be non-zero")]
+fn test_windows2d_mut_zero_size() {
+    let _ = lender::windows2d_mut(&mut [0; 6], 2, 3, 0, 1);
+}
+
+#[test]
+fn test_array_windows2d_mut() {
+    #[rustfmt::skip]
+    let mut m = [
+        1, 2, 3,
+        4, 5, 6,
+        7, 8, 9,
+    ];
+    let mut lender = lender::array_windows2d_mut::<_, 2, 2>(&mut m, 3, 3);
+    assert_eq!(lender.len(), 4);
+    assert_eq!(lender.next(), Some([&mut [1, 2], &mut [4, 5]]));
+    assert_eq!(lender.next_back(), Some([&mut [5, 6], &mut [8, 9]]));
+    let [top, bottom] = lender.next().unwrap();
+    top[1] = bottom[0];
+    assert_eq!(lender.next(), Some([&mut [4, 5], &mut [7, 8]]));
+    assert_eq!(lender.next(), None);
+    assert_eq!(m, [1, 2, 5, 4, 5, 6, 7, 8, 9]);
+
+    // A full-size window of a one-row matrix
+    let mut m = [1, 2, 3];
+    let mut lender = lender::array_windows2d_mut::<_, 1, 3>(&mut m, 1, 3);
+    assert_eq!(lender.next(), Some([&mut [1, 2, 3]]));
+    assert_eq!(lender.next(), None);
+}
+
+// ============================================================================
+// VecCursorMut source tests
+// ============================================================================
+
+#[test]
+fn test_vec_cursor_mut() {
+    let mut v = vec![1, 2, 3, 4, 5];
+    let mut lender = lender::vec_cursor_mut(&mut v);
+    assert_eq!(lender.len(), 5);
+    let mut item = lender.next().unwrap();
+    assert_eq!(item.peek_prev(), None);
+    assert_eq!(item.peek_next(), Some(&2));
+    *item.get_mut() *= 10;
+    item.insert_before(0);
+    item.insert_after(11);
+    item.insert_after(12);
+    let item = lender.next().unwrap();
+    assert_eq!(item.get(), &2);
+    assert_eq!(item.peek_prev(), Some(&12));
+    assert_eq!(item.remove(), 2);
+    let item = lender.next().unwrap();
+    assert_eq!(item.peek_prev(), Some(&12));
+    assert_eq!(lender.len(), 2);
+    // Dropping the lender keeps the unvisited elements
+    drop(lender);
+    assert_eq!(v, [0, 10, 11, 12, 3, 4, 5]);
+}
+
+#[test]
+fn test_vec_cursor_mut_retain() {
+    // Keep the elements larger than the previous kept element
+    let mut v = vec![3, 1, 4, 1, 5, 9, 2, 6];
+    lender::vec_cursor_mut(&mut v).for_each(|item| {
+        if item.peek_prev().is_some_and(|p| p >= item.get()) {
+            item.remove();
+        }
+    });
+    assert_eq!(v, [3, 4, 5, 9]);
+
+    let mut v: Vec<String> = vec![];
+    let mut lender = lender::vec_cursor_mut(&mut v);
+    assert!(lender.next().is_none());
+    drop(lender);
+    assert!(v.is_empty());
+}
+
+#[test]
+fn test_vec_cursor_mut_in_place() {
+    // Removals and replacements reuse the allocation
+    let mut v: Vec<i32> = (0..100).collect();
+    let (ptr, cap) = (v.as_ptr(), v.capacity());
+    let mut lender = lender::vec_cursor_mut(&mut v);
+    while let Some(mut item) = lender.next() {
+        if item.get() % 3 == 0 {
+            item.remove();
+        } else if item.get() % 3 == 1 {
+            // Fills the gap left by the previous removal
+            item.insert_before(-1);
+        }
+    }
+    drop(lender);
+    assert_eq!((v.as_ptr(), v.capacity()), (ptr, cap));
+    assert_eq!(v.len(), 99);
+}
+
+#[test]
+fn test_vec_cursor_mut_model() {
+    // Compare with a straightforward rebuild, using strings to check that no
+    // element is dropped twice or leaked
+    for stop in [3, 7, usize::MAX] {
+        let input: Vec<String> = (0..20).map(|i| i.to_string()).collect();
+        let mut expected = Vec::new();
+        for (i, x) in input.iter().enumerate() {
+            if i >= stop {
+                expected.push(x.clone());
+                continue;
+            }
+            for _ in 0..i % 3 {
+                expected.push(format!("b{i}"));
+            }
+            if i % 4 != 0 {
+                expected.push(x.clone());
+            }
+            for j in 0..i % 5 {
+                expected.push(format!("a{i}.{j}"));
+            }
+        }
+        let mut v = input;
+        let mut lender = lender::vec_cursor_mut(&mut v);
+        let mut i = 0;
+        while i < stop {
+            let Some(mut item) = lender.next() else {
+                break;
+            };
+            for _ in 0..i % 3 {
+                item.insert_before(format!("b{i}"));
+            }
+            assert_eq!(item.get(), &i.to_string());
+            for j in 0..i % 5 {
+                item.insert_after(format!("a{i}.{j}"));
+            }
+            if i % 4 == 0 {
+                assert_eq!(item.remove(), i.to_string());
+            }
+            i += 1;
+        }
+        drop(lender);
+        assert_eq!(v, expected);
+    }
+}
+
+#[test]
+fn test_vec_deque_cursor_mut() {
+    let mut d = VecDeque::with_capacity(8);
+    d.extend([3, 4, 5]);
+    d.push_front(2);
+    d.push_front(1);
+    let mut lender = lender::vec_deque_cursor_mut(&mut d);
+    assert_eq!(lender.len(), 5);
+    while let Some(mut item) = lender.next() {
+        if item.get() % 2 == 0 {
+            item.remove();
+        } else {
+            let x = *item.get();
+            item.insert_after(x * 10);
+        }
+    }
+    drop(lender);
+    assert_eq!(d, [1, 10, 3, 30, 5, 50]);
+}
+
+// ============================================================================
+// SplitMut, SplitInclusiveMut, AsciiFieldsMut and StrWindows source tests
+// ============================================================================
+
+fn collect_mut_slices<L>(mut lender: L) -> Vec<Vec<i32>>
+where
+    L: Lender + for<'all> Lending<'all, Lend = &'all mut [i32]>,
+{
+    let mut result = Vec::new();
+    while let Some(s) = lender.next() {
+        result.push(s.to_vec());
+    }
+    result
+}
+
+#[test]
+fn test_split_mut() {
+    let mut s = [0, 1, 2, 0, 0, 3, 0];
+    let expected: Vec<Vec<i32>> = s.split(|&x| x == 0).map(<[i32]>::to_vec).collect();
+    assert_eq!(
+        collect_mut_slices(lender::split_mut(&mut s, |&x| x == 0)),
+        expected
+    );
+    let mut lender = lender::split_mut(&mut s, |&x| x == 0);
+    assert_eq!(lender.next_back(), Some(&mut [][..]));
+    assert_eq!(lender.next(), Some(&mut [][..]));
+    lender.next().unwrap()[1] = 20;
+    assert_eq!(lender.next_back(), Some(&mut [3][..]));
+    assert_eq!(lender.next_back(), Some(&mut [][..]));
+    assert_eq!(lender.next(), None);
+    assert_eq!(lender.next_back(), None);
+    assert_eq!(s, [0, 1, 20, 0, 0, 3, 0]);
+
+    let mut empty: [i32; 0] = [];
+    let mut lender = lender::split_mut(&mut empty, |&x| x == 0);
+    assert_eq!(lender.size_hint(), (1, Some(1)));
+    assert_eq!(lender.next(), Some(&mut [][..]));
+    assert_eq!(lender.next(), None);
+}
+
+#[test]
+fn test_split_inclusive_mut() {
+    for v in [
+        vec![],
+        vec![0],
+        vec![1, 0, 2],
+        vec![0, 0, 1, 2, 0],
+        vec![1, 2],
+    ] {
+        let expected: Vec<Vec<i32>> = v
+            .split_inclusive(|&x| x == 0)
+            .map(<[i32]>::to_vec)
+            .collect();
+        let mut s = v.clone();
+        assert_eq!(
+            collect_mut_slices(lender::split_inclusive_mut(&mut s, |&x| x == 0)),
+            expected
+        );
+        let mut lender = lender::split_inclusive_mut(&mut s, |&x| x == 0);
+        let mut backward = Vec::new();
+        while let Some(s) = lender.next_back() {
+            backward.push(s.to_vec());
+        }
+        backward.reverse();
+        assert_eq!(backward, expected);
+    }
+}
+
+#[test]
+fn test_ascii_fields_mut() {
+    let mut buf = *b" a ,b,, c ";
+    let mut lender = lender::ascii_fields_mut(&mut buf, b',');
+    let mut fields = Vec::new();
+    while let Some(field) = lender.next() {
+        field.make_ascii_uppercase();
+        fields.push(field.trim_ascii().to_vec());
+    }
+    assert_eq!(fields, [&b"A"[..], b"B", b"", b"C"]);
+    assert_eq!(&buf, b" A ,B,, C ");
+
+    let mut buf = *b"x;y";
+    let mut lender = lender::ascii_fields_mut(&mut buf, b';');
+    assert_eq!(lender.next_back().as_deref(), Some(&b"y"[..]));
+    assert_eq!(lender.next_back().as_deref(), Some(&b"x"[..]));
+    assert_eq!(lender.next(), None);
+}
+
+#[test]
+#[should_panic(expected = "the separator must be an ASCII byte")]
+fn test_ascii_fields_mut_non_ascii() {
+    let _ = lender::ascii_fields_mut(&mut [], 0xC3);
+}
+
+#[test]
+fn test_str_windows() {
+    let s = "héllo, wörld";
+    let chars: Vec<char> = s.chars().collect();
+    for size in 1..=chars.len() + 1 {
+        let expected: Vec<String> = chars.windows(size).map(|w| w.iter().collect()).collect();
+        let mut lender = lender::str_windows(s, size);
+        let mut windows = Vec::new();
+        loop {
+            let (lo, hi) = lender.size_hint();
+            let remaining = expected.len() - windows.len();
+            assert!(lo <= remaining && remaining <= hi.unwrap());
+            match lender.next() {
+                Some(w) => windows.push(w.to_string()),
+                None => break,
+            }
+        }
+        assert_eq!(windows, expected);
+    }
+    assert_eq!(lender::str_windows("", 1).next(), None);
+}
+
 // ============================================================================
 // Additional source tests
 // ============================================================================
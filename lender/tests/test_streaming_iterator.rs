@@ -0,0 +1,121 @@
+#![cfg(feature = "streaming-iterator")]
+//! Tests for the interoperability with the `streaming-iterator` and
+//! `fallible-streaming-iterator` crates
+
+use ::lender::prelude::*;
+use fallible_streaming_iterator::{
+    DoubleEndedFallibleStreamingIterator, FallibleStreamingIterator,
+};
+use streaming_iterator::{DoubleEndedStreamingIterator, StreamingIterator};
+
+// ============================================================================
+// StreamingIterator to Lender
+// ============================================================================
+
+#[test]
+fn test_streaming_iter_into_lender() {
+    let mut lender = streaming_iterator::convert([1, 2, 3]).into_lender();
+    assert_eq!(lender.size_hint(), (3, Some(3)));
+    assert_eq!(lender.next(), Some(&1));
+    assert_eq!(lender.next_back(), Some(&3));
+    assert_eq!(lender.next(), Some(&2));
+    assert_eq!(lender.next(), None);
+
+    // Unsized items
+    let words = ["ab", "c"];
+    let mut lender = streaming_iterator::convert_ref(words).into_lender();
+    assert_eq!(lender.next(), Some("ab"));
+    assert_eq!(lender.next(), Some("c"));
+    assert_eq!(lender.next(), None);
+}
+
+#[test]
+fn test_streaming_iter_mut_into_lender() {
+    let mut v = vec![1, 2, 3];
+    let mut lender = streaming_iterator::convert_mut(&mut v).into_mut_lender();
+    *lender.next().unwrap() += 10;
+    *lender.next_back().unwrap() += 30;
+    lender.for_each(|x: &mut i32| *x += 20);
+    assert_eq!(v, [11, 22, 33]);
+
+    // Stateful streaming iterators lend from their own storage
+    let mut lender = streaming_iterator::windows_mut(&mut v, 2).into_mut_lender();
+    while let Some(w) = lender.next() {
+        w[1] += w[0];
+    }
+    assert_eq!(v, [11, 33, 66]);
+}
+
+#[test]
+fn test_fallible_streaming_iter_into_lender() {
+    let data = [Ok(&1), Ok(&2), Err("error")];
+    let mut lender = fallible_streaming_iterator::convert(data.into_iter()).into_fallible_lender();
+    assert_eq!(lender.next(), Ok(Some(&1)));
+    assert_eq!(lender.next(), Ok(Some(&2)));
+    assert_eq!(lender.next(), Err("error"));
+
+    let data = [Ok::<_, ()>(&1), Ok(&2)];
+    let mut lender = fallible_streaming_iterator::convert(data.into_iter()).into_fallible_lender();
+    assert_eq!(lender.next(), Ok(Some(&1)));
+    assert_eq!(lender.next(), Ok(Some(&2)));
+    assert_eq!(lender.next(), Ok(None));
+}
+
+// ============================================================================
+// Lender to StreamingIterator
+// ============================================================================
+
+#[test]
+fn test_lender_into_streaming_iter() {
+    let data = [1, 2, 3, 4];
+    let mut iter = lender::from_iter_ref(data.into_iter()).into_streaming_iter();
+    assert_eq!(iter.get(), None);
+    assert_eq!(iter.size_hint(), (4, Some(4)));
+    iter.advance();
+    assert_eq!(iter.get(), Some(&1));
+    assert_eq!(iter.get(), Some(&1));
+    assert_eq!(iter.next_back(), Some(&4));
+    assert_eq!(iter.next(), Some(&2));
+    assert_eq!(iter.next(), Some(&3));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.get(), None);
+    assert!(iter.into_inner().next().is_none());
+
+    // Round trip through both encodings, with lends borrowing the lender
+    let mut v = vec![1, 2, 3];
+    let iter = lender::windows_mut(&mut v, 2)
+        .map(covar_mut!(
+            for<'lend> |w: &'lend mut [i32]| -> &'lend [i32] { w }
+        ))
+        .into_streaming_iter();
+    let mut lender = iter.into_lender();
+    assert_eq!(lender.next(), Some(&[1, 2][..]));
+    assert_eq!(lender.next(), Some(&[2, 3][..]));
+    assert_eq!(lender.next(), None);
+
+    // Moving the iterator keeps the current item valid
+    let mut iter = lender::from_iter_ref(["a".to_string()].into_iter()).into_streaming_iter();
+    iter.advance();
+    let moved = iter;
+    assert_eq!(moved.get().map(String::as_str), Some("a"));
+}
+
+#[test]
+fn test_fallible_lender_into_fallible_streaming_iter() {
+    let mut iter = lender::from_iter_ref([1, 2, 3].into_iter())
+        .into_fallible()
+        .into_fallible_streaming_iter();
+    assert_eq!(iter.next_back(), Ok(Some(&3)));
+    assert_eq!(iter.next(), Ok(Some(&1)));
+    assert_eq!(iter.get(), Some(&1));
+    assert_eq!(iter.next(), Ok(Some(&2)));
+    assert_eq!(iter.next(), Ok(None));
+
+    let data = [Ok(&1), Err("error")];
+    let mut iter = fallible_streaming_iterator::convert(data.into_iter())
+        .into_fallible_lender()
+        .into_fallible_streaming_iter();
+    assert_eq!(iter.next(), Ok(Some(&1)));
+    assert_eq!(iter.next(), Err("error"));
+    assert_eq!(iter.get(), None);
+}
@@ -0,0 +1,282 @@
+#![cfg(feature = "testing")]
+//! Tests for the lender-compliance test kit
+
+mod common;
+use ::lender::prelude::*;
+use ::lender::testing::*;
+use common::*;
+
+// ============================================================================
+// Compliant lenders
+// ============================================================================
+
+#[test]
+fn test_lender_laws_sources() {
+    for len in 0..5 {
+        assert_exact_size_laws(|| VecLender::new((0..len).collect()));
+        assert_double_ended_laws(|| VecLender::new((0..len).collect()));
+        assert_fused_laws(|| VecLender::new((0..len).collect()));
+    }
+    assert_double_ended_laws(|| lender::from_iter(0..6));
+    assert_lender_laws(lender::empty::<lend!(&'lend i32)>);
+    assert_fused_laws(|| lender::once::<lend!(i32)>(1));
+    assert_lender_laws(|| lender::repeat::<lend!(i32)>(1).take(3));
+}
+
+#[test]
+fn test_lender_laws_adapters() {
+    let data = || VecLender::new(vec![1, 2, 3, 4, 5, 6, 7]);
+    assert_exact_size_laws(|| data().rev());
+    assert_exact_size_laws(|| data().enumerate());
+    assert_exact_size_laws(|| data().skip(2));
+    assert_exact_size_laws(|| data().take(4));
+    assert_exact_size_laws(|| data().step_by(3));
+    assert_exact_size_laws(|| data().zip(data().skip(1)));
+    assert_double_ended_laws(|| data().rev().skip(1).take(4));
+    assert_double_ended_laws(|| data().step_by(2));
+    assert_double_ended_laws(|| data().chain(data()));
+    assert_double_ended_laws(|| data().filter(|x| **x % 2 == 0));
+    assert_double_ended_laws(|| data().inspect(|_| {}));
+    assert_double_ended_laws(|| data().peekable());
+    assert_fused_laws(|| data().fuse());
+    assert_fused_laws(|| data().skip_while(|x| **x < 3));
+    assert_lender_laws(|| data().take_while(|x| **x < 3));
+    assert_lender_laws(|| data().intersperse(&0));
+    assert_lender_laws(|| data().cycle().take(10));
+}
+
+#[test]
+fn test_lender_laws_either() {
+    for left in [false, true] {
+        assert_exact_size_laws(|| {
+            if left {
+                VecLender::new(vec![1, 2, 3]).rev().left_lender()
+            } else {
+                VecLender::new(vec![1, 2, 3]).right_lender()
+            }
+        });
+    }
+}
+
+#[test]
+fn test_fallible_lender_laws() {
+    let data = || VecFallibleLender::new(vec![1, 2, 3, 4, 5]);
+    assert_fallible_exact_size_laws(data);
+    assert_fallible_double_ended_laws(data);
+    assert_fallible_fused_laws(data);
+    assert_fallible_exact_size_laws(|| data().rev().skip(1));
+    assert_fallible_double_ended_laws(|| data().step_by(2));
+    assert_fallible_double_ended_laws(|| data().filter(|x| Ok(**x % 2 == 1)));
+    assert_fallible_fused_laws(|| data().fuse());
+    assert_fallible_lender_laws(lender::fallible_empty::<fallible_lend!(i32), ()>);
+}
+
+#[test]
+fn test_fallible_lender_laws_errors() {
+    for error_at in 0..4 {
+        assert_fallible_lender_laws(|| ErrorAtLender::new(vec![1, 2, 3], error_at));
+    }
+    assert_fallible_fused_laws(|| ErrorAtLender::new(vec![1, 2, 3], 1).fuse());
+    assert_fallible_lender_laws(|| {
+        lender::from_iter(0..3)
+            .into_fallible()
+            .map_err(|e| match e {})
+            .chain(lender::fallible_once_err::<fallible_lend!(i32), _>("error"))
+    });
+}
+
+#[test]
+fn test_lender_laws_into_results() {
+    // The trailing error is counted by the size hint
+    assert_lender_laws(|| lender::fallible_once_err::<fallible_lend!(i32), _>("e").into_results());
+    for error_at in 0..4 {
+        assert_lender_laws(|| ErrorAtLender::new(vec![1, 2, 3], error_at).into_results());
+    }
+    assert_double_ended_laws(|| VecFallibleLender::new(vec![1, 2, 3]).into_results());
+    assert_fused_laws(|| VecFallibleLender::new(vec![1, 2, 3]).into_results());
+
+    // OwnedResults has the same size hint, as an iterator
+    for len in 0..3 {
+        assert_lender_laws(|| {
+            lender::from_iter(0..len)
+                .into_fallible()
+                .map_err(|e| match e {})
+                .chain(lender::fallible_once_err::<fallible_lend!(i32), _>("e"))
+                .owned_results()
+                .into_lender()
+        });
+    }
+
+    // The error arrives before the inner lower bound runs out
+    assert_lender_laws(|| {
+        [Err("e"), Ok(1), Ok(2)]
+            .into_iter()
+            .into_lender()
+            .convert::<&str>()
+            .owned_results()
+            .into_lender()
+    });
+    #[cfg(feature = "proptest")]
+    assert_lender_laws(|| {
+        lender::testing::proptest::ArbitraryFallibleLender::new(vec![Err("e"), Ok(1), Ok(2)])
+            .into_results()
+    });
+}
+
+// ============================================================================
+// Non-compliant lenders
+// ============================================================================
+
+/// A lender lending 0..len, with a configurable flaw.
+struct Flawed {
+    next: usize,
+    len: usize,
+    flaw: Flaw,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Flaw {
+    SizeHint,
+    Inexact,
+    Nth,
+    TryFold,
+    NotFused,
+    NextBack,
+}
+
+impl Flawed {
+    fn new(flaw: Flaw) -> Self {
+        Self {
+            next: 0,
+            len: 3,
+            flaw,
+        }
+    }
+}
+
+impl<'lend> Lending<'lend> for Flawed {
+    type Lend = usize;
+}
+
+impl Lender for Flawed {
+    check_covariance!();
+    fn next(&mut self) -> Option<usize> {
+        if self.next < self.len {
+            self.next += 1;
+            Some(self.next - 1)
+        } else {
+            // Restarts once exhausted
+            self.next = 0;
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len - self.next;
+        match self.flaw {
+            Flaw::SizeHint => (len + 1, Some(len + 1)),
+            Flaw::Inexact => (0, Some(len)),
+            _ => (len, Some(len)),
+        }
+    }
+
+    fn nth(&mut self, n: usize) -> Option<usize> {
+        if self.flaw == Flaw::Nth {
+            // Off by one
+            self.next += n + 1;
+        } else {
+            self.next = self.next.saturating_add(n).min(self.len);
+        }
+        self.next()
+    }
+
+    fn try_fold<B, F, R>(&mut self, init: B, mut f: F) -> R
+    where
+        F: FnMut(B, usize) -> R,
+        R: lender::try_trait_v2::Try<Output = B>,
+    {
+        let mut acc = init;
+        while let Some(x) = self.next() {
+            match f(acc, x).branch() {
+                core::ops::ControlFlow::Continue(next) => acc = next,
+                core::ops::ControlFlow::Break(residual) => {
+                    if self.flaw == Flaw::TryFold {
+                        // Consumes all lends
+                        self.next = self.len;
+                    }
+                    return lender::try_trait_v2::FromResidual::from_residual(residual);
+                }
+            }
+        }
+        R::from_output(acc)
+    }
+}
+
+impl DoubleEndedLender for Flawed {
+    fn next_back(&mut self) -> Option<usize> {
+        if self.next < self.len {
+            self.len -= 1;
+            match self.flaw {
+                Flaw::NextBack => Some(self.next),
+                _ => Some(self.len),
+            }
+        } else {
+            None
+        }
+    }
+}
+
+impl ExactSizeLender for Flawed {
+    fn len(&self) -> usize {
+        self.len - self.next
+    }
+}
+
+// Not really fused, for testing purposes
+impl FusedLender for Flawed {}
+
+#[test]
+fn test_flawed_compliant() {
+    // Without checking fusedness, restarting lenders are compliant
+    assert_exact_size_laws(|| Flawed::new(Flaw::NotFused));
+    assert_double_ended_laws(|| Flawed::new(Flaw::NotFused));
+}
+
+#[test]
+#[should_panic(expected = "size_hint() returned (4, Some(4)) with 3 remaining lends")]
+fn test_flawed_size_hint() {
+    assert_lender_laws(|| Flawed::new(Flaw::SizeHint));
+}
+
+#[test]
+#[should_panic(expected = "nth(")]
+fn test_flawed_nth() {
+    assert_lender_laws(|| Flawed::new(Flaw::Nth));
+}
+
+#[test]
+#[should_panic(
+    expected = "lends after short-circuiting try_fold() differ from lends after position 0"
+)]
+fn test_flawed_try_fold() {
+    assert_lender_laws(|| Flawed::new(Flaw::TryFold));
+}
+
+#[test]
+#[should_panic(expected = "next() returned a lend after returning None")]
+fn test_flawed_not_fused() {
+    assert_fused_laws(|| Flawed::new(Flaw::NotFused));
+}
+
+#[test]
+#[should_panic(expected = "next_back() differs from next() in reverse order")]
+fn test_flawed_next_back() {
+    assert_double_ended_laws(|| Flawed::new(Flaw::NextBack));
+}
+
+#[test]
+#[should_panic(expected = "size_hint() is not exact")]
+fn test_flawed_exact_size() {
+    // The lender laws hold, as the size hint is not exact but correct
+    assert_exact_size_laws(|| Flawed::new(Flaw::Inexact));
+}
@@ -360,6 +360,80 @@ fn test_lender_min_by() {
     );
 }
 
+#[test]
+fn test_lender_minmax() {
+    use lender::MinMaxResult;
+
+    assert_eq!(
+        Vec::<i32>::new().into_iter().into_lender().minmax(),
+        MinMaxResult::NoElements
+    );
+    assert_eq!(
+        vec![7].into_iter().into_lender().minmax(),
+        MinMaxResult::OneElement(7)
+    );
+    assert_eq!(
+        vec![3, 1, 5, 2, 4].into_iter().into_lender().minmax(),
+        MinMaxResult::MinMax(1, 5)
+    );
+    assert_eq!(
+        vec![2, 2].into_iter().into_lender().minmax().into_option(),
+        Some((2, 2))
+    );
+    assert_eq!(
+        vec![7].into_iter().into_lender().minmax().into_option(),
+        Some((7, 7))
+    );
+}
+
+#[test]
+fn test_lender_minmax_by_key_matches_min_max() {
+    use lender::MinMaxResult;
+
+    // Ties on the key must resolve like min_by_key (first) and max_by_key
+    // (last), for both even and odd lengths
+    let data: Vec<(i32, usize)> = [3, -1, 4, 1, -5, 9, 2, -6, 5, 3, 5, -9]
+        .into_iter()
+        .enumerate()
+        .map(|(i, x)| (x, i))
+        .collect();
+    for len in 2..=data.len() {
+        let slice = &data[..len];
+        let expected_min = slice.iter().copied().min_by_key(|(x, _)| x.abs()).unwrap();
+        let expected_max = slice.iter().copied().max_by_key(|(x, _)| x.abs()).unwrap();
+        assert_eq!(
+            slice
+                .iter()
+                .copied()
+                .into_lender()
+                .minmax_by_key(|(x, _): &(i32, usize)| x.abs()),
+            MinMaxResult::MinMax(expected_min, expected_max)
+        );
+        assert_eq!(
+            slice
+                .iter()
+                .copied()
+                .into_lender()
+                .minmax_by(|(x, _): &(i32, usize), (y, _): &(i32, usize)| x.abs().cmp(&y.abs())),
+            MinMaxResult::MinMax(expected_min, expected_max)
+        );
+    }
+}
+
+#[test]
+fn test_lender_minmax_by_key_calls_key_once() {
+    let mut calls = 0;
+    let result = vec![3, 1, 4, 1, 5]
+        .into_iter()
+        .into_lender()
+        .minmax_by_key(|x: &i32| {
+            calls += 1;
+            *x
+        });
+    assert_eq!(result, lender::MinMaxResult::MinMax(1, 5));
+    assert_eq!(calls, 5);
+}
+
 #[test]
 fn test_lender_is_sorted() {
     assert!(vec![1, 2, 3, 4].into_iter().into_lender().is_sorted());
@@ -700,6 +774,62 @@ fn test_lender_try_fold_early_exit() {
     assert_eq!(result, Err("too big"));
 }
 
+#[test]
+fn test_lender_fold_while() {
+    let mut lender = VecLender::new(vec![1, 2, 3, 4, 5]);
+    let result = lender.fold_while(0, |acc, x| {
+        if *x == 3 {
+            lender::FoldWhile::Done(acc)
+        } else {
+            lender::FoldWhile::Continue(acc + *x)
+        }
+    });
+    assert!(result.is_done());
+    assert_eq!(result.into_inner(), 3);
+    // The fold can be resumed after the lend it stopped on
+    let result = lender.fold_while(0, |acc, x| lender::FoldWhile::Continue(acc + *x));
+    assert_eq!(result, lender::FoldWhile::Continue(9));
+}
+
+#[test]
+fn test_lender_try_fold_lend() {
+    use core::ops::ControlFlow;
+
+    let mut data = [1, 2, 3, 4, 5];
+    let mut lender = data.windows_mut(2);
+    let result = lender.try_fold_lend(0, |acc, w| {
+        let acc = acc + w[0];
+        if acc >= 6 {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(acc)
+        }
+    });
+    // The lend on which the fold broke is returned and can be modified
+    let ControlFlow::Break(w) = result else {
+        panic!("expected a break");
+    };
+    assert_eq!(w, &mut [3, 4]);
+    w[0] = 0;
+    assert_eq!(
+        lender.try_fold_lend(0, |acc, w| ControlFlow::Continue(acc + w[0])),
+        ControlFlow::Continue(4)
+    );
+    assert_eq!(data, [1, 2, 0, 4, 5]);
+}
+
+#[test]
+fn test_lender_try_fold_lend_exhausted() {
+    use core::ops::ControlFlow;
+
+    let mut lender = VecLender::new(vec![1, 2, 3]);
+    let result = lender.try_fold_lend(0, |acc, x| ControlFlow::Continue(acc + **x));
+    assert_eq!(result, ControlFlow::Continue(6));
+    let mut lender = VecLender::new(vec![]);
+    let result = lender.try_fold_lend(0, |_, _| ControlFlow::Break(()));
+    assert_eq!(result, ControlFlow::Continue(0));
+}
+
 // ============================================================================
 // try_find tests
 // ============================================================================